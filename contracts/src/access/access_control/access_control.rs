@@ -44,6 +44,8 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub members: Mapping<(RoleType, Option<AccountId>), (), MembersKey>,
+    #[lazy]
+    pub default_admin_role: RoleType,
 }
 
 pub struct MembersKey;
@@ -68,6 +70,23 @@ where
     body(instance)
 }
 
+/// Modifier that checks that `caller` has a specific role or is the owner of the contract.
+/// The contract must implement both `AccessControl` and `Ownable`.
+#[cfg(feature = "ownable")]
+#[modifier_definition]
+pub fn only_role_or_owner<T, F, R, E>(instance: &mut T, body: F, role: RoleType) -> Result<R, E>
+where
+    T: Internal + Storage<crate::ownable::Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<AccessControlError>,
+{
+    let caller = T::env().caller();
+    if instance.data().owner.get_or_default() != Some(caller) {
+        instance._check_role(role, Some(caller))?;
+    }
+    body(instance)
+}
+
 pub trait AccessControlImpl: Internal + MembersManager + Sized {
     fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
         self._has_role(role, &address)
@@ -114,6 +133,10 @@ pub trait MembersManager {
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType>;
 
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType);
+
+    fn _get_default_admin_role(&self) -> Option<RoleType>;
+
+    fn _set_default_admin_role(&mut self, role: RoleType);
 }
 
 pub trait MembersManagerImpl: Storage<Data> {
@@ -136,6 +159,14 @@ pub trait MembersManagerImpl: Storage<Data> {
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
         self.data().admin_roles.insert(role, &new_admin);
     }
+
+    fn _get_default_admin_role(&self) -> Option<RoleType> {
+        self.data().default_admin_role.get()
+    }
+
+    fn _set_default_admin_role(&mut self, role: RoleType) {
+        self.data().default_admin_role.set(&role);
+    }
 }
 
 pub trait Internal {
@@ -148,10 +179,19 @@ pub trait Internal {
 
     fn _default_admin() -> RoleType;
 
+    /// Returns the role that acts as the default admin.
+    ///
+    /// It is the role set during [`Internal::_init_with_admin_role`], or [`Internal::_default_admin`] otherwise.
+    fn _default_admin_role(&self) -> RoleType;
+
     fn _init_with_caller(&mut self);
 
     fn _init_with_admin(&mut self, admin: Option<AccountId>);
 
+    /// Initializes the contract with `admin_role` acting as the default admin role
+    /// and grants it to `admin`.
+    fn _init_with_admin_role(&mut self, admin: Option<AccountId>, admin_role: RoleType);
+
     fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>);
 
     fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>);
@@ -174,12 +214,23 @@ pub trait InternalImpl: Internal + MembersManager + Sized {
         DEFAULT_ADMIN_ROLE
     }
 
+    fn _default_admin_role(&self) -> RoleType {
+        self._get_default_admin_role()
+            .unwrap_or(<Self as Internal>::_default_admin())
+    }
+
     fn _init_with_caller(&mut self) {
         Internal::_init_with_admin(self, Some(Self::env().caller()));
     }
 
     fn _init_with_admin(&mut self, admin: Option<AccountId>) {
-        Internal::_setup_role(self, <Self as Internal>::_default_admin(), admin);
+        let admin_role = Internal::_default_admin_role(self);
+        Internal::_setup_role(self, admin_role, admin);
+    }
+
+    fn _init_with_admin_role(&mut self, admin: Option<AccountId>, admin_role: RoleType) {
+        self._set_default_admin_role(admin_role);
+        Internal::_setup_role(self, admin_role, admin);
     }
 
     fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>) {
@@ -209,6 +260,6 @@ pub trait InternalImpl: Internal + MembersManager + Sized {
     }
 
     fn _get_role_admin(&self, role: RoleType) -> RoleType {
-        MembersManager::_get_role_admin(self, role).unwrap_or(<Self as Internal>::_default_admin())
+        MembersManager::_get_role_admin(self, role).unwrap_or(Internal::_default_admin_role(self))
     }
 }
@@ -0,0 +1,62 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::extensions::bootstrap,
+    ownable,
+    traits::access_control::*,
+};
+pub use access_control::{
+    Internal as _,
+    InternalImpl as _,
+};
+pub use bootstrap::Internal as _;
+use ink::prelude::vec::Vec;
+use openbrush::traits::AccountId;
+pub use ownable::{
+    Internal as _,
+    InternalImpl as _,
+};
+
+pub trait Internal {
+    /// Sets `owner` as the owner of the contract, grants the default admin role to every
+    /// account in `admins` and grants every `(role, account)` pair from `roles`.
+    ///
+    /// Emits `OwnershipTransferred` and `RoleGranted` events via the `Ownable` and
+    /// `AccessControl` event hooks.
+    fn _bootstrap_access(&mut self, owner: AccountId, admins: Vec<AccountId>, roles: Vec<(RoleType, AccountId)>);
+}
+
+pub trait InternalImpl: Internal + ownable::Internal + access_control::Internal {
+    fn _bootstrap_access(&mut self, owner: AccountId, admins: Vec<AccountId>, roles: Vec<(RoleType, AccountId)>) {
+        ownable::Internal::_init_with_owner(self, owner);
+
+        let admin_role = access_control::Internal::_default_admin_role(self);
+        for admin in admins {
+            access_control::Internal::_setup_role(self, admin_role, Some(admin));
+        }
+        for (role, account) in roles {
+            access_control::Internal::_setup_role(self, role, Some(account));
+        }
+    }
+}
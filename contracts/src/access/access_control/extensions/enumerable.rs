@@ -32,6 +32,7 @@ pub use access_control::{
     Internal as _,
     InternalImpl as _,
 };
+pub use enumerable::Internal as _;
 use openbrush::{
     storage::{
         Mapping,
@@ -49,6 +50,8 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub role_members: MultiMapping<RoleType, Option<AccountId>, ValueGuard<RoleType>>,
+    #[lazy]
+    pub default_admin_role: RoleType,
 }
 
 pub trait MembersManagerImpl: Storage<Data> {
@@ -71,6 +74,14 @@ pub trait MembersManagerImpl: Storage<Data> {
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
         self.data().admin_roles.insert(role, &new_admin);
     }
+
+    fn _get_default_admin_role(&self) -> Option<RoleType> {
+        self.data().default_admin_role.get()
+    }
+
+    fn _set_default_admin_role(&mut self, role: RoleType) {
+        self.data().default_admin_role.set(&role);
+    }
 }
 
 pub trait AccessControlEnumerableImpl: Storage<Data> {
@@ -85,3 +96,20 @@ pub trait AccessControlEnumerableImpl: Storage<Data> {
         self.data().role_members.count(role) as u32
     }
 }
+
+pub trait Internal {
+    /// Grants the default admin role to `account` only if the role has no members yet.
+    ///
+    /// Calling it again once an admin exists is a no-op, so deployment scripts can call it safely.
+    fn _init_admin_if_empty(&mut self, account: Option<AccountId>);
+}
+
+pub trait InternalImpl: Storage<Data> + access_control::Internal {
+    fn _init_admin_if_empty(&mut self, account: Option<AccountId>) {
+        let admin_role = access_control::Internal::_default_admin_role(self);
+
+        if self.data().role_members.count(admin_role) == 0 {
+            access_control::Internal::_init_with_admin(self, account);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::extensions::timelocked_grant,
+    traits::access_control::{
+        extensions::timelocked_grant::*,
+        *,
+    },
+};
+pub use access_control::{
+    AccessControlImpl,
+    Internal as _,
+    InternalImpl as _,
+    MembersManager as _,
+    MembersManagerImpl as _,
+};
+use openbrush::{
+    modifiers,
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+    utils::clock::Clock,
+};
+pub use timelocked_grant::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub grant_delay: Timestamp,
+    pub scheduled_grants: Mapping<(RoleType, Option<AccountId>), Timestamp, ScheduledGrantsKey>,
+}
+
+pub struct ScheduledGrantsKey;
+
+impl<'a> TypeGuard<'a> for ScheduledGrantsKey {
+    type Type = &'a (RoleType, &'a Option<AccountId>);
+}
+
+pub trait AccessControlTimelockedGrantImpl:
+    Storage<Data> + Internal + AccessControlImpl + access_control::Internal + access_control::MembersManager + Clock
+{
+    fn grant_delay(&self) -> Timestamp {
+        Internal::_grant_delay(self)
+    }
+
+    fn grant_ready_at(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp> {
+        self.data().scheduled_grants.get(&(role, &account))
+    }
+
+    #[modifiers(access_control::only_role(access_control::Internal::_get_role_admin(self, role)))]
+    fn schedule_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if self._has_role(role, &account) {
+            return Err(AccessControlError::RoleRedundant)
+        }
+        let ready_at = self.now().saturating_add(Internal::_grant_delay(self));
+        self.data().scheduled_grants.insert(&(role, &account), &ready_at);
+        Internal::_emit_grant_scheduled(self, role, account, ready_at);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(access_control::Internal::_get_role_admin(self, role)))]
+    fn execute_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        let ready_at = self
+            .data()
+            .scheduled_grants
+            .get(&(role, &account))
+            .ok_or(AccessControlError::GrantNotScheduled)?;
+        if self.now() < ready_at {
+            return Err(AccessControlError::GrantNotReady)
+        }
+        if self._has_role(role, &account) {
+            return Err(AccessControlError::RoleRedundant)
+        }
+        self.data().scheduled_grants.remove(&(role, &account));
+        self._add(role, &account);
+        access_control::Internal::_emit_role_granted(self, role, account, Some(Self::env().caller()));
+        Ok(())
+    }
+
+    /// Replaces `AccessControl::grant_role`, so a role admin can't skip the delay.
+    /// While `grant_delay` is set, it grants the role only like `execute_grant`.
+    fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if Internal::_grant_delay(self) > 0 {
+            return AccessControlTimelockedGrantImpl::execute_grant(self, role, account)
+        }
+        AccessControlImpl::grant_role(self, role, account)
+    }
+
+    #[modifiers(access_control::only_role(access_control::Internal::_get_role_admin(self, role)))]
+    fn cancel_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if !self.data().scheduled_grants.contains(&(role, &account)) {
+            return Err(AccessControlError::GrantNotScheduled)
+        }
+        self.data().scheduled_grants.remove(&(role, &account));
+        Internal::_emit_grant_cancelled(self, role, account);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// The user must override those methods using their event definition.
+    fn _emit_grant_scheduled(&self, role: RoleType, account: Option<AccountId>, ready_at: Timestamp);
+
+    fn _emit_grant_cancelled(&self, role: RoleType, account: Option<AccountId>);
+
+    fn _grant_delay(&self) -> Timestamp;
+
+    fn _set_grant_delay(&mut self, delay: Timestamp);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_grant_scheduled(&self, _role: RoleType, _account: Option<AccountId>, _ready_at: Timestamp) {}
+
+    fn _emit_grant_cancelled(&self, _role: RoleType, _account: Option<AccountId>) {}
+
+    fn _grant_delay(&self) -> Timestamp {
+        self.data().grant_delay.get_or_default()
+    }
+
+    fn _set_grant_delay(&mut self, delay: Timestamp) {
+        self.data().grant_delay.set(&delay);
+    }
+}
@@ -20,7 +20,10 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub mod extensions {
+    #[cfg(feature = "ownable")]
+    pub mod bootstrap;
     pub mod enumerable;
+    pub mod timelocked_grant;
 }
 
 pub mod access_control;
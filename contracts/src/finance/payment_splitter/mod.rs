@@ -30,6 +30,11 @@ use openbrush::{
         AccountId,
         Balance,
         Storage,
+        String,
+    },
+    utils::math::{
+        mul_div,
+        RoundingMode,
     },
 };
 pub use payment_splitter::Internal as _;
@@ -164,7 +169,10 @@ pub trait InternalImpl: Storage<Data> + Internal {
         let shares = self.data().shares.get(&account).unwrap();
         let total_shares = self.data().total_shares.get_or_default();
         let released = self.data().released.get(&account).unwrap_or_default();
-        let payment = total_received * shares / total_shares - released;
+        // The share is rounded down, so the sum of the payments never exceeds the received amount
+        let payment = mul_div(total_received, shares, total_shares, RoundingMode::Down)
+            .ok_or(PaymentSplitterError::Custom(String::from("Overflow")))?
+            - released;
 
         if payment == 0 {
             return Err(PaymentSplitterError::AccountIsNotDuePayment)
@@ -38,4 +38,8 @@ pub struct Data {
 
     /// Stores if the account has voted for the proposal
     pub has_votes: Mapping<(ProposalId, AccountId), ()>,
+
+    /// Stores if the abstain votes are excluded from the quorum, they are counted by default
+    #[lazy]
+    pub abstain_excluded_from_quorum: bool,
 }
@@ -46,4 +46,9 @@ pub trait GovernorCountingImpl: Storage<Data> + CountingInternal {
         let proposal_vote = self.data::<Data>().proposal_votes.get(&proposal_id).unwrap_or_default();
         Ok(ProposalVote { ..proposal_vote })
     }
+
+    /// Returns `true` if the abstain votes count toward the quorum, `false` otherwise
+    fn abstain_counts_toward_quorum(&self) -> bool {
+        self._abstain_counts_toward_quorum()
+    }
 }
@@ -44,16 +44,34 @@ use openbrush::traits::{
 
 pub trait CountingInternal: Storage<Data> + QuorumImpl + GovernorStorageGetters {
     /// Returns true if the quorum is reached for the given proposal, false otherwise
+    ///
+    /// The quorum is reached by `for` and `abstain` votes, or by `for` and `against` votes
+    /// if the abstain votes are excluded from the quorum.
     fn _quorum_reached(&self, proposal_id: ProposalId) -> Result<bool, GovernanceError> {
         let proposal_vote = self.data::<Data>().proposal_votes.get(&proposal_id).unwrap_or_default();
+        let counted_votes = if self._abstain_counts_toward_quorum() {
+            proposal_vote.abstain_votes
+        } else {
+            proposal_vote.against_votes
+        };
         let num_votes = proposal_vote
             .for_votes
-            .checked_add(proposal_vote.abstain_votes)
+            .checked_add(counted_votes)
             .ok_or(GovernanceError::Overflow)?;
 
         Ok(self.quorum(self._proposal_snapshot(proposal_id)?)? <= num_votes)
     }
 
+    /// Returns true if the abstain votes count toward the quorum, false otherwise
+    fn _abstain_counts_toward_quorum(&self) -> bool {
+        !self.data::<Data>().abstain_excluded_from_quorum.get_or_default()
+    }
+
+    /// Sets whether the abstain votes count toward the quorum
+    fn _set_abstain_counts_toward_quorum(&mut self, counts: bool) {
+        self.data::<Data>().abstain_excluded_from_quorum.set(&!counts);
+    }
+
     /// Returns true if the proposal has succeeded, false otherwise
     fn _vote_succeeded(&self, proposal_id: ProposalId) -> bool {
         self.data::<Data>()
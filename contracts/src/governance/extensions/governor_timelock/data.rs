@@ -0,0 +1,39 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::traits::governance::{
+    OperationId,
+    ProposalId,
+};
+pub use openbrush::{
+    storage::Mapping,
+    traits::AccountId,
+};
+
+#[derive(Debug, Default)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// Stores the `TimelockController` contract that executes the proposals
+    #[lazy]
+    pub timelock: AccountId,
+    /// Stores the ids of the timelock operations of the queued proposals
+    pub timelock_ids: Mapping<ProposalId, OperationId>,
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::{
+    governance::extensions::governor_timelock,
+    traits::governance::extensions::governor_timelock::*,
+};
+
+use crate::{
+    governance::{
+        extensions::governor_timelock::{
+            GovernorTimelockEvents,
+            GovernorTimelockInternal,
+        },
+        governor::GovernorInternal,
+    },
+    traits::governance::ProposalState,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+/// Extension of `Governor` that binds the execution process to an instance of `TimelockController`.
+///
+/// Successful proposals are queued in the timelock, and `execute` succeeds only after the timelock delay.
+/// The governor must have the proposer and executor roles of the timelock.
+pub trait GovernorTimelockImpl: GovernorTimelockInternal + GovernorTimelockEvents + GovernorInternal {
+    /// Returns the address of the timelock, `None` if the governor executes proposals itself
+    fn timelock(&self) -> Option<AccountId> {
+        self._timelock()
+    }
+
+    /// Returns the timestamp at which a queued proposal can be executed, 0 if the proposal isn't queued
+    fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+        self._proposal_eta(proposal_id)
+    }
+
+    /// Queues a proposal in the `Succeeded` state to the timelock.
+    /// Returns the id of the queued proposal
+    fn queue(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<ProposalId, GovernanceError> {
+        let proposal_id = self._hash_proposal(transactions.clone(), description_hash.clone())?;
+
+        if self._state(proposal_id.clone())? != ProposalState::Succeeded {
+            return Err(GovernanceError::UnexpectedProposalState)
+        }
+
+        let eta = self._queue(proposal_id.clone(), transactions, description_hash)?;
+
+        self.emit_proposal_queued(proposal_id.clone(), eta);
+
+        Ok(proposal_id)
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+use crate::{
+    governance::extensions::{
+        governor_timelock::Data,
+        timelock_controller::DONE_TIMESTAMP,
+    },
+    traits::{
+        errors::GovernanceError,
+        governance::{
+            extensions::timelock_controller::TimelockControllerRef,
+            HashType,
+            ProposalId,
+            ProposalState,
+            Transaction,
+        },
+    },
+};
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Storage,
+    Timestamp,
+};
+
+/// Extension of `Governor` that executes the proposals through a `TimelockController`
+///
+/// `#[openbrush::implementation(GovernorTimelock)]` overrides `GovernorInternal::_succeeded_state`,
+/// `GovernorInternal::_execute` and `GovernorInternal::_cancel_queued` to use `_timelock_state`,
+/// `_timelock_execute` and `_timelock_cancel`.
+pub trait GovernorTimelockInternal: Storage<Data> {
+    /// Initializes the governor timelock extension
+    fn _init_governor_timelock(&mut self, timelock: AccountId) -> Result<(), GovernanceError> {
+        self.data().timelock.set(&timelock);
+        Ok(())
+    }
+
+    /// Returns the address of the timelock, `None` if the governor executes proposals itself
+    fn _timelock(&self) -> Option<AccountId> {
+        self.data().timelock.get()
+    }
+
+    /// Returns the timestamp at which a queued proposal can be executed, 0 if the proposal isn't queued
+    fn _proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+        match (self._timelock(), self.data().timelock_ids.get(&proposal_id)) {
+            (Some(timelock), Some(id)) => {
+                let eta = TimelockControllerRef::get_timestamp(&timelock, id);
+                // The timelock marks the executed operations with `DONE_TIMESTAMP`
+                if eta == DONE_TIMESTAMP {
+                    0
+                } else {
+                    eta
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns the state of a succeeded proposal according to its timelock operation
+    fn _timelock_state(&self, proposal_id: ProposalId) -> ProposalState {
+        match (self._timelock(), self.data().timelock_ids.get(&proposal_id)) {
+            (Some(timelock), Some(id)) => {
+                if TimelockControllerRef::is_operation_done(&timelock, id) {
+                    ProposalState::Executed
+                } else if TimelockControllerRef::is_operation_pending(&timelock, id) {
+                    ProposalState::Queued
+                } else {
+                    // The operation was canceled in the timelock
+                    ProposalState::Canceled
+                }
+            }
+            _ => ProposalState::Succeeded,
+        }
+    }
+
+    /// Schedules the transactions of the proposal in the timelock with its minimal delay.
+    /// Returns the timestamp at which the proposal can be executed
+    fn _queue(
+        &mut self,
+        proposal_id: ProposalId,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<Timestamp, GovernanceError> {
+        let timelock = self._timelock().ok_or(GovernanceError::TimelockNotSet)?;
+
+        let delay = TimelockControllerRef::get_min_delay(&timelock);
+        let id = TimelockControllerRef::hash_operation_batch(&timelock, transactions.clone(), None, description_hash);
+
+        self.data().timelock_ids.insert(&proposal_id, &id);
+
+        TimelockControllerRef::schedule_batch(&timelock, transactions, None, description_hash, delay)?;
+
+        Ok(TimelockControllerRef::get_timestamp(&timelock, id))
+    }
+
+    /// Executes the queued proposal through the timelock.
+    ///
+    /// Fails with `TimelockControllerError::OperationIsNotReady` until the timelock delay has passed.
+    fn _timelock_execute(
+        &mut self,
+        proposal_id: ProposalId,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<(), GovernanceError> {
+        let timelock = self._timelock().ok_or(GovernanceError::TimelockNotSet)?;
+
+        if !self.data().timelock_ids.contains(&proposal_id) {
+            return Err(GovernanceError::UnexpectedProposalState)
+        }
+
+        // Transactions of the proposal can call the governor back through the timelock
+        TimelockControllerRef::execute_batch_builder(&timelock, transactions, None, description_hash)
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+            .map_err(|_| GovernanceError::ExecutionFailed)?
+            .map_err(|_| GovernanceError::ExecutionFailed)??;
+
+        self.data().timelock_ids.remove(&proposal_id);
+
+        Ok(())
+    }
+
+    /// Cancels the timelock operation of a queued proposal, so it can't be executed in the timelock.
+    fn _timelock_cancel(&mut self, proposal_id: ProposalId) -> Result<(), GovernanceError> {
+        if let (Some(timelock), Some(id)) = (self._timelock(), self.data().timelock_ids.get(&proposal_id)) {
+            if TimelockControllerRef::is_operation_pending(&timelock, id) {
+                TimelockControllerRef::cancel(&timelock, id)?;
+            }
+
+            self.data().timelock_ids.remove(&proposal_id);
+        }
+
+        Ok(())
+    }
+}
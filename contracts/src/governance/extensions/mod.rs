@@ -23,5 +23,6 @@
 pub mod governor_counting;
 pub mod governor_quorum;
 pub mod governor_settings;
+pub mod governor_timelock;
 pub mod governor_votes;
 pub mod timelock_controller;
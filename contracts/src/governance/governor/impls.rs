@@ -151,6 +151,9 @@ pub trait GovernorImpl:
 
     /// Makes a proposal for a list of transactions to be executed.
     /// Returns the id of the proposal
+    ///
+    /// The proposer must have at least `proposal_threshold` votes at the moment of the proposal,
+    /// otherwise `ProposalThresholdNotMet` error is returned.
     fn propose(&mut self, transactions: Vec<Transaction>, description: String) -> Result<ProposalId, GovernanceError> {
         if transactions.is_empty() {
             return Err(GovernanceError::ZeroProposalLength)
@@ -167,7 +170,7 @@ pub trait GovernorImpl:
         let votes_threshold = self.proposal_threshold();
 
         if proposer_votes < votes_threshold {
-            return Err(GovernanceError::InsufficientProposerVotes)
+            return Err(GovernanceError::ProposalThresholdNotMet)
         }
 
         let description_hash = self._hash_description(description.clone())?;
@@ -208,6 +211,8 @@ pub trait GovernorImpl:
 
     /// Executes a proposal if it is in the `Succeeded` state.
     /// Returns the id of the executed proposal
+    ///
+    /// If the governor has a timelock, the proposal must be queued and executed after the timelock delay.
     fn execute(
         &mut self,
         transactions: Vec<Transaction>,
@@ -246,28 +251,48 @@ pub trait GovernorImpl:
         Ok(proposal_id)
     }
 
-    /// Cancels a proposal if it is in the `Pending` state.
+    /// Cancels a proposal.
     /// Returns the id of the canceled proposal
+    ///
+    /// The proposer can cancel the proposal while it is in the `Pending` state, or until it is executed
+    /// if their votes fell below `proposal_threshold`. The guardian can cancel the proposal in any state
+    /// until it is executed.
     fn cancel(
         &mut self,
         transactions: Vec<Transaction>,
         description_hash: HashType,
     ) -> Result<ProposalId, GovernanceError> {
         let proposal_id = self.hash_proposal(transactions.clone(), description_hash.clone())?;
+        let caller = Self::env().caller();
+
+        // The guardian isn't limited to the `Pending` state, `_cancel` rejects only the final states
+        if self._guardian() == Some(caller) {
+            return self._cancel(transactions, description_hash)
+        }
+
+        if caller != self.proposal_proposer(proposal_id.clone())? {
+            return Err(GovernanceError::OnlyProposer)
+        }
 
         let current_state = self.state(proposal_id.clone())?;
 
         if current_state != ProposalState::Pending {
-            return Err(GovernanceError::UnexpectedProposalState)
-        }
+            let current_timestamp = TimestampProvider::block_timestamp(self);
+            let proposer_votes = self.get_votes_with_params(caller, current_timestamp, Vec::new())?;
 
-        if Self::env().caller() != self.proposal_proposer(proposal_id.clone())? {
-            return Err(GovernanceError::OnlyProposer)
+            if proposer_votes >= self.proposal_threshold() {
+                return Err(GovernanceError::UnexpectedProposalState)
+            }
         }
 
         self._cancel(transactions, description_hash)
     }
 
+    /// Returns the guardian that can cancel any proposal before it is executed, if any.
+    fn guardian(&self) -> Option<AccountId> {
+        self._guardian()
+    }
+
     /// Casts a vote for a proposal from a message sender.
     /// Returns the number of votes already casted for the proposal by the sender
     fn cast_vote(
@@ -285,6 +310,37 @@ pub trait GovernorImpl:
             params.unwrap_or_default(),
         )
     }
+
+    /// Casts a vote with a reason for a proposal from a message sender. The reason is emitted in the `VoteCast` event.
+    /// Returns the number of votes already casted for the proposal by the sender
+    fn cast_vote_with_reason(
+        &mut self,
+        proposal_id: ProposalId,
+        support: VoteType,
+        reason: String,
+    ) -> Result<Balance, GovernanceError> {
+        self._cast_vote_with_params(proposal_id, Self::env().caller(), support, reason, vec![])
+    }
+
+    /// Casts a vote for a proposal on behalf of the account that signed
+    /// `(governor_account_id, proposal_id, support)`.
+    /// Returns the number of votes already casted for the proposal by the signer
+    fn cast_vote_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: VoteType,
+        signature: Signature,
+    ) -> Result<Balance, GovernanceError> {
+        // The governor and the proposal id are a part of the signed message, so the signature can't be
+        // replayed on another governor or for another proposal, and a second vote on the same proposal
+        // is rejected by `_count_vote`.
+        let message = (Self::env().account_id(), proposal_id.clone(), support.clone()).encode();
+
+        let voter = signature.recover(&message).ok_or(GovernanceError::InvalidSignature)?;
+
+        self._cast_vote_with_params(proposal_id, voter, support, String::new(), vec![])
+    }
+
     /// Casts a vote with signature for a proposal from a message sender. Returns the number of votes already casted for the proposal by the sender
     fn cast_vote_with_signature(
         &mut self,
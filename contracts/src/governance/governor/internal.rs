@@ -79,6 +79,9 @@ pub trait GovernorInternal:
     Storage<Data> + GovernorEvents + CountingInternal + GovernorVotesInternal + TimestampProvider
 {
     /// Hashing function used to (re)build the proposal id from the proposal details.
+    ///
+    /// The id is the Keccak-256 hash of the SCALE encoded transactions and the description hash,
+    /// so `queue`, `execute` and `cancel` find the proposal only if they get the same details.
     fn _hash_proposal(
         &self,
         transactions: Vec<Transaction>,
@@ -86,7 +89,7 @@ pub trait GovernorInternal:
     ) -> Result<HashType, GovernanceError> {
         let message = (transactions, description_hash).encode();
 
-        Ok(crypto::hash_blake2b256(message.as_slice()))
+        Ok(crypto::hash::keccak256(message.as_slice()))
     }
 
     /// Current state of a proposal, following Compound's convention
@@ -120,14 +123,30 @@ pub trait GovernorInternal:
         }
 
         if self._vote_succeeded(proposal_id.clone()) && self._quorum_reached(proposal_id.clone())? {
-            Ok(ProposalState::Succeeded)
+            Ok(self._succeeded_state(proposal_id))
         } else {
             Ok(ProposalState::Defeated)
         }
     }
 
+    /// State of a proposal that passed the vote, `Succeeded` by default.
+    ///
+    /// Extensions that execute the proposals elsewhere override it, for example `GovernorTimelock`
+    /// reports whether the proposal is queued or executed in the timelock.
+    fn _succeeded_state(&self, _proposal_id: ProposalId) -> ProposalState {
+        ProposalState::Succeeded
+    }
+
     /// Executes a proposal if it is in the `Succeeded` state.
+    ///
+    /// By default the governor calls the transactions itself. `GovernorTimelock` overrides it
+    /// to execute the proposal through the timelock.
     fn _execute(&mut self, transactions: Vec<Transaction>, _description_hash: HashType) -> Result<(), GovernanceError> {
+        self._execute_calls(transactions)
+    }
+
+    /// Calls the transactions of a proposal from the governor.
+    fn _execute_calls(&mut self, transactions: Vec<Transaction>) -> Result<(), GovernanceError> {
         for tx in transactions.iter() {
             if let Some(callee) = tx.callee {
                 build_call::<DefaultEnvironment>()
@@ -203,6 +222,8 @@ pub trait GovernorInternal:
             return Err(GovernanceError::UnexpectedProposalState)
         }
 
+        self._cancel_queued(proposal_id.clone())?;
+
         let proposal = self
             .data::<Data>()
             .proposals
@@ -222,6 +243,13 @@ pub trait GovernorInternal:
         Ok(proposal_id)
     }
 
+    /// Cancels the queued execution of a proposal that is being canceled, nothing by default.
+    ///
+    /// `GovernorTimelock` overrides it to cancel the operation scheduled in the timelock.
+    fn _cancel_queued(&mut self, _proposal_id: ProposalId) -> Result<(), GovernanceError> {
+        Ok(())
+    }
+
     /// Returns the AccountId of the proposer of a proposal
     fn _proposal_proposer(&self, proposal_id: ProposalId) -> Result<AccountId, GovernanceError> {
         Ok(self
@@ -268,6 +296,17 @@ pub trait GovernorInternal:
         Ok(weight)
     }
 
+    /// Returns the guardian of the governor, if any.
+    fn _guardian(&self) -> Option<AccountId> {
+        self.data::<Data>().guardian.get_or_default()
+    }
+
+    /// Sets the `guardian` that can cancel any proposal before it is executed.
+    /// `None` removes the guardian.
+    fn _set_guardian(&mut self, guardian: Option<AccountId>) {
+        self.data::<Data>().guardian.set(&guardian);
+    }
+
     /// Returns the AccountId of the executor.
     fn _executor(&self) -> AccountId {
         Self::env().account_id()
@@ -301,9 +340,9 @@ pub trait GovernorInternal:
         0
     }
 
-    /// Return the hash of the description.
+    /// Return the Keccak-256 hash of the description.
     fn _hash_description(&self, description: String) -> Result<HashType, GovernanceError> {
-        Ok(crypto::hash_blake2b256(description.as_bytes()))
+        Ok(crypto::hash::keccak256(description.as_bytes()))
     }
 }
 
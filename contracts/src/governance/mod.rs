@@ -31,6 +31,8 @@ pub mod extensions {
     #[cfg(feature = "governance")]
     pub mod governor_settings;
     #[cfg(feature = "governance")]
+    pub mod governor_timelock;
+    #[cfg(feature = "governance")]
     pub mod governor_votes;
     #[cfg(feature = "timelock_controller")]
     pub mod timelock_controller;
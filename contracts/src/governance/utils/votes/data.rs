@@ -38,4 +38,7 @@ pub struct Data {
     /// Stores the checkpoints of the total delegations of the governor
     #[lazy]
     pub total_checkpoints: Checkpoints,
+    /// Stores the checkpoints of the balances of the token holders.
+    /// The key is the holder and the value is the checkpoints of the balance keyed by block number
+    pub balance_checkpoints: Mapping<AccountId, Checkpoints>,
 }
@@ -61,6 +61,8 @@ pub use upgradeability::diamond;
 pub use upgradeability::proxy;
 #[cfg(feature = "upgradeable")]
 pub use upgradeability::upgradeable;
+#[cfg(feature = "multicall")]
+pub use utils::multicall;
 #[cfg(feature = "nonces")]
 pub use utils::nonces;
 #[cfg(feature = "psp61")]
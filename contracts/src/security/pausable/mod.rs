@@ -26,6 +26,7 @@ pub use crate::{
 use openbrush::{
     modifier_definition,
     modifiers,
+    storage::Mapping,
     traits::{
         AccountId,
         Storage,
@@ -42,9 +43,14 @@ pub use pausable::{
 pub struct Data {
     #[lazy]
     pub paused: bool,
+    pub paused_scopes: Mapping<PauseScope, ()>,
 }
 
 /// Modifier to make a function callable only when the contract is paused.
+///
+/// It is the inverse of [`when_not_paused`] and can be used for an emergency exit,
+/// like an `emergency_withdraw` method that lets users pull their own funds
+/// while the rest of the contract is stopped.
 #[modifier_definition]
 pub fn when_paused<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
 where
@@ -98,6 +104,27 @@ pub trait Internal {
 
     /// Function which changes state to unpaused if paused and vice versa
     fn _switch_pause(&mut self) -> Result<(), PausableError>;
+
+    /// User must override those methods in their contract.
+    fn _emit_scope_paused_event(&self, _account: AccountId, _scope: PauseScope);
+
+    fn _emit_scope_unpaused_event(&self, _account: AccountId, _scope: PauseScope);
+
+    /// Returns true if the `scope` is paused, regardless of the state of the whole contract.
+    fn _scope_paused(&self, scope: PauseScope) -> bool;
+
+    /// Triggers stopped state only for the `scope`.
+    ///
+    /// On success a `ScopePaused` event is emitted.
+    fn _pause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError>;
+
+    /// Returns the `scope` to normal state.
+    ///
+    /// On success a `ScopeUnpaused` event is emitted.
+    fn _unpause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError>;
+
+    /// Returns `PausableError::Paused` if the whole contract or the `scope` is paused.
+    fn _ensure_not_paused(&self, scope: PauseScope) -> Result<(), PausableError>;
 }
 
 pub trait InternalImpl: Storage<Data> + Internal {
@@ -130,4 +157,37 @@ pub trait InternalImpl: Storage<Data> + Internal {
             Internal::_pause(self)
         }
     }
+
+    fn _emit_scope_paused_event(&self, _account: AccountId, _scope: PauseScope) {}
+
+    fn _emit_scope_unpaused_event(&self, _account: AccountId, _scope: PauseScope) {}
+
+    fn _scope_paused(&self, scope: PauseScope) -> bool {
+        self.data().paused_scopes.get(&scope).is_some()
+    }
+
+    fn _pause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError> {
+        if Internal::_scope_paused(self, scope) {
+            return Err(PausableError::Paused)
+        }
+        self.data().paused_scopes.insert(&scope, &());
+        Internal::_emit_scope_paused_event(self, Self::env().caller(), scope);
+        Ok(())
+    }
+
+    fn _unpause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError> {
+        if !Internal::_scope_paused(self, scope) {
+            return Err(PausableError::NotPaused)
+        }
+        self.data().paused_scopes.remove(&scope);
+        Internal::_emit_scope_unpaused_event(self, Self::env().caller(), scope);
+        Ok(())
+    }
+
+    fn _ensure_not_paused(&self, scope: PauseScope) -> Result<(), PausableError> {
+        if Internal::_paused(self) || Internal::_scope_paused(self, scope) {
+            return Err(PausableError::Paused)
+        }
+        Ok(())
+    }
 }
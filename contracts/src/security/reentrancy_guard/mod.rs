@@ -34,6 +34,9 @@ use openbrush::{
 pub struct Data {
     #[lazy]
     pub status: u8,
+    /// The number of entered `non_reentrant_read_lock` methods.
+    #[lazy]
+    pub readers: u32,
 }
 
 const NOT_ENTERED: u8 = 0;
@@ -45,6 +48,9 @@ const ENTERED: u8 = 1;
 /// by making the `non_reentrant` function external, and make it call a
 /// `private` function that does the actual work.
 ///
+/// Calling a `non_reentrant` function from a `non_reentrant_read_lock` function
+/// is not supported either.
+///
 /// This modifier flushes the struct into storage with `ENTERED`
 /// status before calling the original method.
 #[modifier_definition]
@@ -54,7 +60,7 @@ where
     F: FnOnce(&mut T) -> Result<R, E>,
     E: From<ReentrancyGuardError>,
 {
-    if instance.data().status.get_or_default() == ENTERED {
+    if instance.data().status.get_or_default() == ENTERED || instance.data().readers.get_or_default() > 0 {
         return Err(From::from(ReentrancyGuardError::ReentrantCall))
     }
     // Any calls to nonReentrant after this point will fail
@@ -65,3 +71,50 @@ where
 
     result
 }
+
+/// Prevents a contract from reading its state while a `non_reentrant` function is executed.
+/// Calling a `non_reentrant_read` function from another `non_reentrant_read` function is allowed.
+///
+/// The modifier only checks the status and doesn't write anything, so it can be used by `&self` methods.
+/// It doesn't lock anything either, so a `non_reentrant` function called from
+/// a `non_reentrant_read` function isn't rejected, use `non_reentrant_read_lock` for that.
+#[modifier_definition]
+pub fn non_reentrant_read<T, F, R, E>(instance: &T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&T) -> Result<R, E>,
+    E: From<ReentrancyGuardError>,
+{
+    if instance.data().status.get_or_default() == ENTERED {
+        return Err(From::from(ReentrancyGuardError::ReentrantCall))
+    }
+
+    body(instance)
+}
+
+/// Prevents a contract from reading its state while a `non_reentrant` function is executed,
+/// and a `non_reentrant` function from being executed while the read is in progress.
+/// Calling a `non_reentrant_read_lock` or `non_reentrant_read` function
+/// from a `non_reentrant_read_lock` function is allowed.
+///
+/// The read lock is stored, so the modifier can be used only by `&mut self` methods.
+/// This modifier flushes the incremented number of readers into storage
+/// before calling the original method.
+#[modifier_definition]
+pub fn non_reentrant_read_lock<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data> + Storable,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<ReentrancyGuardError>,
+{
+    if instance.data().status.get_or_default() == ENTERED {
+        return Err(From::from(ReentrancyGuardError::ReentrantCall))
+    }
+    let readers = instance.data().readers.get_or_default();
+    instance.data().readers.set(&(readers + 1));
+
+    let result = body(instance);
+    instance.data().readers.set(&readers);
+
+    result
+}
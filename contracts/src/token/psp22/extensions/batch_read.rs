@@ -0,0 +1,61 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    traits::psp22::{
+        extensions::batch_read::*,
+        *,
+    },
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    String,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+pub trait PSP22BatchReadImpl: psp22::Internal {
+    fn balances_of(&self, accounts: Vec<AccountId>) -> Result<Vec<Balance>, PSP22Error> {
+        if accounts.len() > MAX_BATCH_READ_LEN {
+            return Err(PSP22Error::Custom(String::from("BatchTooLarge")))
+        }
+
+        Ok(accounts.iter().map(|account| self._balance_of(account)).collect())
+    }
+
+    fn allowances_of(&self, pairs: Vec<(AccountId, AccountId)>) -> Result<Vec<Balance>, PSP22Error> {
+        if pairs.len() > MAX_BATCH_READ_LEN {
+            return Err(PSP22Error::Custom(String::from("BatchTooLarge")))
+        }
+
+        Ok(pairs
+            .iter()
+            .map(|(owner, spender)| self._allowance(owner, spender))
+            .collect())
+    }
+}
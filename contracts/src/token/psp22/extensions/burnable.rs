@@ -21,14 +21,25 @@
 
 pub use crate::{
     psp22,
+    psp22::extensions::burnable,
     traits::psp22::{
         extensions::burnable::*,
         *,
     },
 };
-use openbrush::traits::{
-    AccountId,
-    Balance,
+pub use burnable::Internal as _;
+pub use openbrush::traits::String;
+use openbrush::{
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+    },
 };
 pub use psp22::{
     Internal as _,
@@ -36,8 +47,103 @@ pub use psp22::{
     PSP22Impl,
 };
 
-pub trait PSP22BurnableImpl: psp22::Internal {
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub burn_allowances: Mapping<(AccountId, AccountId), Balance, BurnAllowancesKey>,
+    /// The total supply can't be burned below it, `0` means no floor.
+    #[lazy]
+    pub min_supply: Balance,
+}
+
+pub struct BurnAllowancesKey;
+
+impl<'a> TypeGuard<'a> for BurnAllowancesKey {
+    type Type = &'a (&'a AccountId, &'a AccountId);
+}
+
+pub trait PSP22BurnableImpl: Internal + psp22::Internal {
     fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        Internal::_check_min_supply(self, amount)?;
         self._burn_from(account, amount)
     }
+
+    fn burn_with_memo(&mut self, amount: Balance, memo: String) -> Result<(), PSP22Error> {
+        let account = Self::env().caller();
+        Internal::_check_min_supply(self, amount)?;
+        self._burn_from(account, amount)?;
+        Internal::_emit_burn_with_memo_event(self, account, amount, memo);
+        Ok(())
+    }
+
+    fn approve_burn(&mut self, burner: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        Internal::_approve_burn(self, Self::env().caller(), burner, amount);
+        Ok(())
+    }
+
+    fn burn_allowance(&self, owner: AccountId, burner: AccountId) -> Balance {
+        Internal::_burn_allowance(self, &owner, &burner)
+    }
+
+    fn burn_from_approved(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        let allowance = Internal::_burn_allowance(self, &account, &caller);
+
+        if allowance < amount {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
+
+        Internal::_check_min_supply(self, amount)?;
+        Internal::_approve_burn(self, account, caller, allowance - amount);
+        self._burn_from(account, amount)
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_burn_with_memo_event(&self, _account: AccountId, _amount: Balance, _memo: String);
+
+    fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance;
+
+    /// Sets the amount of `owner`'s tokens that `burner` is allowed to burn.
+    fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance);
+
+    fn _min_supply(&self) -> Balance;
+
+    fn _set_min_supply(&mut self, min_supply: Balance);
+
+    /// Returns `PSP22Error::Custom("BelowMinSupply")` error if burning `amount`
+    /// would drop the total supply below the minimum supply.
+    ///
+    /// The messages of [`PSP22Burnable`] check it, user must call it if they burn via `psp22::Internal::_burn_from`.
+    fn _check_min_supply(&self, amount: Balance) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + psp22::Internal {
+    fn _emit_burn_with_memo_event(&self, _account: AccountId, _amount: Balance, _memo: String) {}
+
+    fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance {
+        self.data().burn_allowances.get(&(owner, burner)).unwrap_or(0)
+    }
+
+    fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance) {
+        self.data().burn_allowances.insert(&(&owner, &burner), &amount);
+    }
+
+    fn _min_supply(&self) -> Balance {
+        self.data().min_supply.get_or_default()
+    }
+
+    fn _set_min_supply(&mut self, min_supply: Balance) {
+        self.data().min_supply.set(&min_supply);
+    }
+
+    fn _check_min_supply(&self, amount: Balance) -> Result<(), PSP22Error> {
+        let total_supply = psp22::Internal::_total_supply(self);
+
+        if total_supply.saturating_sub(amount) < Internal::_min_supply(self) {
+            return Err(PSP22Error::Custom(String::from("BelowMinSupply")))
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,98 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::circulating,
+    traits::psp22::{
+        extensions::circulating::*,
+        *,
+    },
+};
+pub use circulating::Internal as _;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub excluded: Vec<AccountId>,
+}
+
+pub trait PSP22CirculatingImpl: Internal {
+    fn circulating_supply(&self) -> Balance {
+        self._circulating_supply()
+    }
+
+    fn is_excluded(&self, account: AccountId) -> bool {
+        self._is_excluded(&account)
+    }
+}
+
+pub trait Internal {
+    fn _circulating_supply(&self) -> Balance;
+
+    fn _is_excluded(&self, account: &AccountId) -> bool;
+
+    /// Excludes the balance of `account` from the circulating supply or includes it back.
+    fn _set_excluded(&mut self, account: AccountId, excluded: bool);
+}
+
+pub trait InternalImpl: Internal + psp22::Internal + Storage<Data> {
+    fn _circulating_supply(&self) -> Balance {
+        let excluded: Balance = self
+            .data()
+            .excluded
+            .get_or_default()
+            .iter()
+            .map(|account| psp22::Internal::_balance_of(self, account))
+            .fold(0, |sum, balance| sum.saturating_add(balance));
+
+        psp22::Internal::_total_supply(self).saturating_sub(excluded)
+    }
+
+    fn _is_excluded(&self, account: &AccountId) -> bool {
+        self.data().excluded.get_or_default().contains(account)
+    }
+
+    fn _set_excluded(&mut self, account: AccountId, excluded: bool) {
+        let mut accounts = self.data().excluded.get_or_default();
+
+        if excluded && !accounts.contains(&account) {
+            accounts.push(account);
+        } else if !excluded {
+            accounts.retain(|excluded_account| excluded_account != &account);
+        }
+
+        self.data().excluded.set(&accounts);
+    }
+}
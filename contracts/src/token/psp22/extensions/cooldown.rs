@@ -0,0 +1,111 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::cooldown,
+    traits::psp22::{
+        extensions::cooldown::*,
+        *,
+    },
+};
+pub use cooldown::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        String,
+        Timestamp,
+    },
+    utils::clock::Clock,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub cooldown_secs: u64,
+    pub last_transfer_time: Mapping<AccountId, Timestamp>,
+}
+
+pub trait PSP22CooldownImpl: Internal {
+    fn cooldown_secs(&self) -> u64 {
+        self._cooldown_secs()
+    }
+
+    fn last_transfer_time(&self, account: AccountId) -> Option<Timestamp> {
+        self._last_transfer_time(&account)
+    }
+}
+
+pub trait Internal {
+    fn _cooldown_secs(&self) -> u64;
+
+    fn _set_cooldown_secs(&mut self, cooldown_secs: u64);
+
+    fn _last_transfer_time(&self, account: &AccountId) -> Option<Timestamp>;
+
+    /// Returns `PSP22Error::Custom("Cooldown")` error if `from` transfers tokens
+    /// before the cooldown since its last transfer has passed. Otherwise, remembers the time of the transfer.
+    /// Minting and burning are allowed.
+    ///
+    /// User must call it in `psp22::Internal::_before_token_transfer` of their contract.
+    fn _check_cooldown(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + Clock {
+    fn _cooldown_secs(&self) -> u64 {
+        self.data().cooldown_secs.get_or_default()
+    }
+
+    fn _set_cooldown_secs(&mut self, cooldown_secs: u64) {
+        self.data().cooldown_secs.set(&cooldown_secs);
+    }
+
+    fn _last_transfer_time(&self, account: &AccountId) -> Option<Timestamp> {
+        self.data().last_transfer_time.get(account)
+    }
+
+    fn _check_cooldown(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+        if let (Some(from), Some(_)) = (from, to) {
+            let now = self.now();
+
+            if let Some(last) = Internal::_last_transfer_time(self, from) {
+                // Timestamps are in milliseconds
+                let cooldown = Internal::_cooldown_secs(self).saturating_mul(1000);
+
+                if now < last.saturating_add(cooldown) {
+                    return Err(PSP22Error::Custom(String::from("Cooldown")))
+                }
+            }
+
+            self.data().last_transfer_time.insert(from, &now);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::deflationary,
+    traits::psp22::{
+        extensions::deflationary::*,
+        *,
+    },
+};
+pub use deflationary::Internal as _;
+use ink::prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+    utils::math::{
+        mul_div,
+        RoundingMode,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+/// The denominator of `burn_bps`, `buy_fee_bps` and `sell_fee_bps`.
+pub const MAX_BPS: u16 = 10_000;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub burn_bps: u16,
+    #[lazy]
+    pub buy_fee_bps: u16,
+    #[lazy]
+    pub sell_fee_bps: u16,
+    pub pairs: Mapping<AccountId, ()>,
+    #[lazy]
+    pub pair_count: u32,
+}
+
+pub trait PSP22DeflationaryImpl: Internal {
+    fn burn_bps(&self) -> u16 {
+        self._burn_bps()
+    }
+
+    fn buy_fee_bps(&self) -> u16 {
+        self._buy_fee_bps()
+    }
+
+    fn sell_fee_bps(&self) -> u16 {
+        self._sell_fee_bps()
+    }
+
+    fn is_pair(&self, account: AccountId) -> bool {
+        self._is_pair(&account)
+    }
+}
+
+fn share_of(amount: Balance, bps: u16) -> Balance {
+    // The share is rounded down, so the fee never exceeds the configured share of `amount`.
+    // The result can't overflow while `bps <= MAX_BPS`.
+    mul_div(amount, bps as Balance, MAX_BPS as Balance, RoundingMode::Down).unwrap_or_default()
+}
+
+pub trait Internal {
+    fn _burn_bps(&self) -> u16;
+
+    /// Sets the share of each transfer that is burned, in basis points.
+    ///
+    /// Returns `PSP22Error::Custom("InvalidBurnBps")` error if `burn_bps` is above `MAX_BPS`.
+    fn _set_burn_bps(&mut self, burn_bps: u16) -> Result<(), PSP22Error>;
+
+    fn _buy_fee_bps(&self) -> u16;
+
+    /// Sets the share burned on transfers from a pair, in basis points.
+    ///
+    /// Returns `PSP22Error::Custom("InvalidFeeBps")` error if `buy_fee_bps` is above `MAX_BPS`.
+    fn _set_buy_fee_bps(&mut self, buy_fee_bps: u16) -> Result<(), PSP22Error>;
+
+    fn _sell_fee_bps(&self) -> u16;
+
+    /// Sets the share burned on transfers to a pair, in basis points.
+    ///
+    /// Returns `PSP22Error::Custom("InvalidFeeBps")` error if `sell_fee_bps` is above `MAX_BPS`.
+    fn _set_sell_fee_bps(&mut self, sell_fee_bps: u16) -> Result<(), PSP22Error>;
+
+    fn _is_pair(&self, account: &AccountId) -> bool;
+
+    /// Marks `account` as a pair (e.g. a DEX pool) or removes the mark.
+    fn _set_pair(&mut self, account: AccountId, is_pair: bool);
+
+    /// Returns the share of a transfer from `from` to `to` that is burned, in basis points.
+    ///
+    /// Transfers from a pair are charged `buy_fee_bps` and transfers to a pair `sell_fee_bps`.
+    /// Other transfers are untaxed once a pair is set, and are charged `burn_bps` while there are no pairs.
+    fn _fee_bps(&self, from: &AccountId, to: &AccountId) -> u16;
+
+    /// Returns the part of `amount` that is burned on transfer.
+    fn _burn_amount(&self, amount: Balance) -> Balance;
+
+    /// Transfers `amount` minus the part burned according to `_fee_bps` from `from` to `to`
+    /// and burns the rest.
+    ///
+    /// User must call it in `psp22::Internal::_transfer_from_to` of their contract.
+    /// `_mint_to` and `_burn_from` don't go through `_transfer_from_to`, so they are never charged.
+    fn _transfer_with_burn(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp22::InternalImpl {
+    fn _burn_bps(&self) -> u16 {
+        self.data::<Data>().burn_bps.get_or_default()
+    }
+
+    fn _set_burn_bps(&mut self, burn_bps: u16) -> Result<(), PSP22Error> {
+        if burn_bps > MAX_BPS {
+            return Err(PSP22Error::Custom(String::from("InvalidBurnBps")))
+        }
+        self.data::<Data>().burn_bps.set(&burn_bps);
+        Ok(())
+    }
+
+    fn _buy_fee_bps(&self) -> u16 {
+        self.data::<Data>().buy_fee_bps.get_or_default()
+    }
+
+    fn _set_buy_fee_bps(&mut self, buy_fee_bps: u16) -> Result<(), PSP22Error> {
+        if buy_fee_bps > MAX_BPS {
+            return Err(PSP22Error::Custom(String::from("InvalidFeeBps")))
+        }
+        self.data::<Data>().buy_fee_bps.set(&buy_fee_bps);
+        Ok(())
+    }
+
+    fn _sell_fee_bps(&self) -> u16 {
+        self.data::<Data>().sell_fee_bps.get_or_default()
+    }
+
+    fn _set_sell_fee_bps(&mut self, sell_fee_bps: u16) -> Result<(), PSP22Error> {
+        if sell_fee_bps > MAX_BPS {
+            return Err(PSP22Error::Custom(String::from("InvalidFeeBps")))
+        }
+        self.data::<Data>().sell_fee_bps.set(&sell_fee_bps);
+        Ok(())
+    }
+
+    fn _is_pair(&self, account: &AccountId) -> bool {
+        self.data::<Data>().pairs.contains(account)
+    }
+
+    fn _set_pair(&mut self, account: AccountId, is_pair: bool) {
+        if is_pair == Internal::_is_pair(self, &account) {
+            return
+        }
+
+        let pair_count = self.data::<Data>().pair_count.get_or_default();
+
+        if is_pair {
+            self.data::<Data>().pairs.insert(&account, &());
+            self.data::<Data>().pair_count.set(&(pair_count + 1));
+        } else {
+            self.data::<Data>().pairs.remove(&account);
+            self.data::<Data>().pair_count.set(&(pair_count - 1));
+        }
+    }
+
+    fn _fee_bps(&self, from: &AccountId, to: &AccountId) -> u16 {
+        if Internal::_is_pair(self, from) {
+            Internal::_buy_fee_bps(self)
+        } else if Internal::_is_pair(self, to) {
+            Internal::_sell_fee_bps(self)
+        } else if self.data::<Data>().pair_count.get_or_default() > 0 {
+            0
+        } else {
+            Internal::_burn_bps(self)
+        }
+    }
+
+    fn _burn_amount(&self, amount: Balance) -> Balance {
+        share_of(amount, Internal::_burn_bps(self))
+    }
+
+    fn _transfer_with_burn(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        if psp22::Internal::_balance_of(self, &from) < amount {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+
+        let burned = share_of(amount, Internal::_fee_bps(self, &from, &to));
+
+        psp22::InternalImpl::_transfer_from_to(self, from, to, amount - burned, data)?;
+        if burned > 0 {
+            psp22::Internal::_burn_from(self, from, burned)?;
+        }
+
+        Ok(())
+    }
+}
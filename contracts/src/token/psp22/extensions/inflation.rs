@@ -0,0 +1,102 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::inflation,
+    traits::psp22::{
+        extensions::inflation::*,
+        *,
+    },
+};
+pub use inflation::Internal as _;
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+        Timestamp,
+    },
+    utils::clock::Clock,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+/// Number of timestamp units (milliseconds) in one second.
+const MILLIS_PER_SECOND: Timestamp = 1000;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub rate_per_second: Balance,
+    #[lazy]
+    pub last_mint_time: Timestamp,
+}
+
+pub trait PSP22InflationImpl: Internal + Storage<Data> + psp22::Internal + Clock {
+    fn rate_per_second(&self) -> Balance {
+        self.data().rate_per_second.get_or_default()
+    }
+
+    fn last_mint_time(&self) -> Timestamp {
+        self.data().last_mint_time.get_or_default()
+    }
+
+    fn mint_inflation(&mut self, to: AccountId) -> Result<(), PSP22Error> {
+        let last_mint_time = self.data().last_mint_time.get_or_default();
+        let elapsed = self.now().saturating_sub(last_mint_time) / MILLIS_PER_SECOND;
+
+        if elapsed == 0 {
+            return Err(PSP22Error::Custom(String::from("NoTimeElapsed")))
+        }
+
+        let amount = self
+            .data()
+            .rate_per_second
+            .get_or_default()
+            .checked_mul(elapsed as Balance)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+
+        // The remainder of the second is kept for the next mint.
+        self.data()
+            .last_mint_time
+            .set(&(last_mint_time + elapsed * MILLIS_PER_SECOND));
+        psp22::Internal::_mint_to(self, to, amount)
+    }
+}
+
+pub trait Internal {
+    /// Initializes the rate of the inflation and starts it from the current block timestamp.
+    fn _init_inflation(&mut self, rate_per_second: Balance);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + Clock {
+    fn _init_inflation(&mut self, rate_per_second: Balance) {
+        self.data().rate_per_second.set(&rate_per_second);
+        self.data().last_mint_time.set(&self.now());
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::max_tx,
+    traits::psp22::{
+        extensions::max_tx::*,
+        *,
+    },
+};
+pub use max_tx::Internal as _;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+    String,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub max_tx_amount: Balance,
+}
+
+pub trait PSP22MaxTxImpl: Internal {
+    fn max_tx_amount(&self) -> Balance {
+        self._max_tx_amount()
+    }
+}
+
+pub trait Internal {
+    fn _max_tx_amount(&self) -> Balance;
+
+    /// Sets the maximal amount of tokens per transfer. Zero disables the limit.
+    fn _set_max_tx(&mut self, max_tx_amount: Balance);
+
+    /// Returns `PSP22Error::Custom("MaxTxExceeded")` error if more than `max_tx_amount` tokens
+    /// are transferred at once. Minting and burning are allowed.
+    ///
+    /// User must call it in `psp22::Internal::_before_token_transfer` of their contract.
+    fn _check_max_tx(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _max_tx_amount(&self) -> Balance {
+        self.data().max_tx_amount.get_or_default()
+    }
+
+    fn _set_max_tx(&mut self, max_tx_amount: Balance) {
+        self.data().max_tx_amount.set(&max_tx_amount);
+    }
+
+    fn _check_max_tx(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if let (Some(_), Some(_)) = (from, to) {
+            let max_tx_amount = Internal::_max_tx_amount(self);
+
+            if max_tx_amount > 0 && *amount > max_tx_amount {
+                return Err(PSP22Error::Custom(String::from("MaxTxExceeded")))
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::merkle_airdrop,
+    traits::psp22::{
+        extensions::merkle_airdrop::*,
+        *,
+    },
+};
+use ink::prelude::vec::Vec;
+pub use merkle_airdrop::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+        String,
+    },
+    utils::crypto::hash::keccak256,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+use scale::Encode;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub merkle_root: [u8; 32],
+    /// Bitmap of claimed indexes, each word stores 128 indexes.
+    pub claimed: Mapping<u32, u128>,
+}
+
+pub trait PSP22MerkleAirdropImpl: Internal + psp22::Internal {
+    fn merkle_root(&self) -> [u8; 32] {
+        self._merkle_root()
+    }
+
+    fn is_claimed(&self, index: u32) -> bool {
+        self._is_claimed(index)
+    }
+
+    fn claim(&mut self, index: u32, amount: Balance, proof: Vec<[u8; 32]>) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+
+        if Internal::_is_claimed(self, index) {
+            return Err(PSP22Error::Custom(String::from("AlreadyClaimed")))
+        }
+
+        if !Internal::_verify_claim(self, index, &caller, amount, &proof) {
+            return Err(PSP22Error::Custom(String::from("InvalidProof")))
+        }
+
+        Internal::_set_claimed(self, index);
+        psp22::Internal::_mint_to(self, caller, amount)
+    }
+}
+
+pub trait Internal {
+    fn _merkle_root(&self) -> [u8; 32];
+
+    fn _set_merkle_root(&mut self, merkle_root: [u8; 32]);
+
+    fn _is_claimed(&self, index: u32) -> bool;
+
+    fn _set_claimed(&mut self, index: u32);
+
+    /// Returns `true` if `(index, account, amount)` leaf belongs to the merkle tree.
+    fn _verify_claim(&self, index: u32, account: &AccountId, amount: Balance, proof: &[[u8; 32]]) -> bool;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _merkle_root(&self) -> [u8; 32] {
+        self.data().merkle_root.get_or_default()
+    }
+
+    fn _set_merkle_root(&mut self, merkle_root: [u8; 32]) {
+        self.data().merkle_root.set(&merkle_root);
+    }
+
+    fn _is_claimed(&self, index: u32) -> bool {
+        let word = self.data().claimed.get(&(index / 128)).unwrap_or_default();
+        word & (1 << (index % 128)) != 0
+    }
+
+    fn _set_claimed(&mut self, index: u32) {
+        let word = self.data().claimed.get(&(index / 128)).unwrap_or_default();
+        self.data()
+            .claimed
+            .insert(&(index / 128), &(word | (1 << (index % 128))));
+    }
+
+    fn _verify_claim(&self, index: u32, account: &AccountId, amount: Balance, proof: &[[u8; 32]]) -> bool {
+        let leaf = keccak256(&(index, account, amount).encode());
+
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            // Pairs are sorted, so the proof doesn't need to know the position of the node
+            if node <= *sibling {
+                keccak256(&[node, *sibling].concat())
+            } else {
+                keccak256(&[*sibling, node].concat())
+            }
+        });
+
+        root == Internal::_merkle_root(self)
+    }
+}
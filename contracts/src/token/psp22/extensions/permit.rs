@@ -28,16 +28,24 @@ pub use crate::{
         *,
     },
 };
+use ink::prelude::vec::Vec;
 use openbrush::{
     traits::{
         AccountId,
         Balance,
+        DefaultEnv,
         Storage,
     },
-    utils::crypto::hash_blake2b256,
+    utils::{
+        clock::Clock,
+        crypto::hash_blake2b256,
+    },
 };
 
-pub use openbrush::utils::crypto::Signature;
+pub use openbrush::utils::crypto::{
+    Signature,
+    SigningScheme,
+};
 
 pub use psp22::{
     Internal as _,
@@ -63,7 +71,7 @@ pub struct PermitMessage {
     pub nonce: u64,
 }
 
-pub trait PSP22PermitImpl: Internal {
+pub trait PSP22PermitImpl: Internal + PSP22 + Sized {
     fn permit(
         &mut self,
         owner: AccountId,
@@ -78,6 +86,24 @@ pub trait PSP22PermitImpl: Internal {
     fn domain_separator(&mut self) -> [u8; 32] {
         self._domain_separator()
     }
+
+    fn permit_transfer_from(
+        &mut self,
+        owner: AccountId,
+        permitted: Balance,
+        deadline: u64,
+        signature: Signature,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<(), PSP22Error> {
+        let spender = Self::env().caller();
+        self._permit(owner, spender, permitted, deadline, signature)?;
+        PSP22::transfer_from(self, owner, to, value, Vec::new())
+    }
+
+    fn permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error> {
+        self._permit_batch(permits)
+    }
 }
 
 pub trait Internal {
@@ -90,10 +116,44 @@ pub trait Internal {
         signature: Signature,
     ) -> Result<(), PSP22Error>;
 
+    /// Applies all `permits` or none of them.
+    ///
+    /// All permits are verified before any nonce is consumed or allowance is changed.
+    fn _permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error>;
+
+    /// Verifies that `signature` is a signature of `owner` for the permit with `nonce`.
+    fn _verify_permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        amount: Balance,
+        deadline: u64,
+        nonce: u64,
+        signature: &Signature,
+    ) -> Result<(), PSP22Error>;
+
     fn _domain_separator(&mut self) -> [u8; 32];
+
+    /// Returns the scheme that builds the digest of the permit signed by the owner.
+    ///
+    /// By default it is `SigningScheme::Eip712`, override it to accept permits
+    /// signed under another scheme, e.g. `SigningScheme::Raw` for permits signed as
+    /// `blake2_256(scale(PermitMessage))` before the scheme was configurable.
+    fn _signing_scheme(&self) -> SigningScheme;
+
+    /// Returns the current point of time that the permit `deadline` is compared against.
+    ///
+    /// By default it is [`Clock::now`], override it to return the block number
+    /// if the deadlines are block-based.
+    fn _clock(&self) -> u64;
+
+    /// Emitted in addition to `Approval` when a permit is consumed, `nonce` is the consumed nonce.
+    ///
+    /// User must override this method in their contract.
+    fn _emit_permit_used_event(&self, _owner: AccountId, _spender: AccountId, _value: Balance, _nonce: u64);
 }
 
-pub trait InternalImpl: Storage<Data> + psp22::Internal + NoncesImpl {
+pub trait InternalImpl: Internal + Storage<Data> + psp22::Internal + NoncesImpl + Clock {
     fn _permit(
         &mut self,
         owner: AccountId,
@@ -102,13 +162,61 @@ pub trait InternalImpl: Storage<Data> + psp22::Internal + NoncesImpl {
         deadline: u64,
         signature: Signature,
     ) -> Result<(), PSP22Error> {
-        let block_time = Self::env().block_timestamp();
-        if deadline < block_time {
+        if deadline < Internal::_clock(self) {
             return Err(PSP22Error::PermitExpired)
         }
 
         let nonce = self._use_nonce(&owner)?;
-        let domain_separator = self._domain_separator();
+        Internal::_verify_permit(self, owner, spender, amount, deadline, nonce, &signature)?;
+        self._approve_from_to(owner, spender, amount)?;
+        Internal::_emit_permit_used_event(self, owner, spender, amount, nonce);
+        Ok(())
+    }
+
+    fn _permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error> {
+        let clock = Internal::_clock(self);
+
+        for (i, permit) in permits.iter().enumerate() {
+            if permit.deadline < clock {
+                return Err(PSP22Error::PermitExpired)
+            }
+
+            // Earlier permits of the same owner in the batch consume the preceding nonces
+            let previous = permits[..i].iter().filter(|p| p.owner == permit.owner).count() as u64;
+            let nonce = NoncesImpl::nonces(self, &permit.owner)
+                .checked_add(previous)
+                .ok_or(NoncesError::NonceOverflow)?;
+
+            Internal::_verify_permit(
+                self,
+                permit.owner,
+                permit.spender,
+                permit.value,
+                permit.deadline,
+                nonce,
+                &permit.signature,
+            )?;
+        }
+
+        for permit in permits {
+            let nonce = self._use_nonce(&permit.owner)?;
+            self._approve_from_to(permit.owner, permit.spender, permit.value)?;
+            Internal::_emit_permit_used_event(self, permit.owner, permit.spender, permit.value, nonce);
+        }
+
+        Ok(())
+    }
+
+    fn _verify_permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        amount: Balance,
+        deadline: u64,
+        nonce: u64,
+        signature: &Signature,
+    ) -> Result<(), PSP22Error> {
+        let domain_separator = Internal::_domain_separator(self);
 
         let message = &scale::Encode::encode(&PermitMessage {
             domain_separator,
@@ -119,8 +227,9 @@ pub trait InternalImpl: Storage<Data> + psp22::Internal + NoncesImpl {
             nonce,
         });
 
-        if signature.verify(message, &owner) {
-            self._approve_from_to(owner, spender, amount)?;
+        let scheme = Internal::_signing_scheme(self);
+
+        if signature.verify_with_scheme(scheme, &domain_separator, message, &owner) {
             Ok(())
         } else {
             Err(PSP22Error::PermitInvalidSignature)
@@ -140,4 +249,14 @@ pub trait InternalImpl: Storage<Data> + psp22::Internal + NoncesImpl {
             cached
         }
     }
+
+    fn _signing_scheme(&self) -> SigningScheme {
+        SigningScheme::default()
+    }
+
+    fn _clock(&self) -> u64 {
+        self.now()
+    }
+
+    fn _emit_permit_used_event(&self, _owner: AccountId, _spender: AccountId, _value: Balance, _nonce: u64) {}
 }
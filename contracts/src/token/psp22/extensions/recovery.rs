@@ -0,0 +1,92 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    psp22,
+    psp22::extensions::recovery,
+    traits::psp22::{
+        extensions::recovery::*,
+        *,
+    },
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+        String,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use recovery::Internal as _;
+
+pub trait PSP22RecoveryImpl: Storage<ownable::Data> + psp22::Internal + Internal {
+    #[modifiers(ownable::only_owner)]
+    fn recover_self(&mut self, to: AccountId) -> Result<(), PSP22Error> {
+        let this = Self::env().account_id();
+        let amount = self
+            ._balance_of(&this)
+            .saturating_sub(Internal::_protected_balance(self, &this));
+        self._transfer_from_to(this, to, amount, Vec::new())
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn rescue_token(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let this = Self::env().account_id();
+        let held = if token == this {
+            self._balance_of(&this)
+        } else {
+            PSP22Ref::balance_of(&token, this)
+        };
+
+        if held.saturating_sub(Internal::_protected_balance(self, &token)) < amount {
+            return Err(PSP22Error::Custom(String::from("ProtectedBalance")))
+        }
+
+        if token == this {
+            self._transfer_from_to(this, to, amount, Vec::new())
+        } else {
+            PSP22Ref::transfer(&token, to, amount, Vec::new())
+        }
+    }
+}
+
+pub trait Internal {
+    /// Returns the part of the `token` balance held by the contract that can't be recovered,
+    /// for example tokens staked by users. It is `0` by default,
+    /// override it to protect the balances your contract accounts for.
+    fn _protected_balance(&self, token: &AccountId) -> Balance;
+}
+
+pub trait InternalImpl: Internal {
+    fn _protected_balance(&self, _token: &AccountId) -> Balance {
+        0
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::restricted,
+    traits::psp22::{
+        extensions::restricted::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use restricted::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub allowed: Mapping<AccountId, ()>,
+}
+
+pub trait PSP22RestrictedImpl: Internal {
+    fn is_allowed(&self, account: AccountId) -> bool {
+        self._is_allowed(&account)
+    }
+}
+
+pub trait Internal {
+    fn _is_allowed(&self, account: &AccountId) -> bool;
+
+    fn _set_allowed(&mut self, account: AccountId, allowed: bool);
+
+    /// Returns `PSP22Error::Custom("ReceiverNotAllowed")` error if tokens are transferred
+    /// to an account that is not allowed. Minting and burning are allowed.
+    ///
+    /// User must call it in `psp22::Internal::_before_token_transfer` of their contract.
+    fn _check_restricted(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _is_allowed(&self, account: &AccountId) -> bool {
+        self.data().allowed.contains(account)
+    }
+
+    fn _set_allowed(&mut self, account: AccountId, allowed: bool) {
+        if allowed {
+            self.data().allowed.insert(&account, &());
+        } else {
+            self.data().allowed.remove(&account);
+        }
+    }
+
+    fn _check_restricted(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+        if let (Some(_), Some(to)) = (from, to) {
+            if !Internal::_is_allowed(self, to) {
+                return Err(PSP22Error::Custom(String::from("ReceiverNotAllowed")))
+            }
+        }
+        Ok(())
+    }
+}
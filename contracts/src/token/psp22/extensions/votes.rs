@@ -22,13 +22,23 @@
 
 pub use crate::traits::psp22::extensions::votes::*;
 use crate::{
-    governance::utils::votes::VotesInternal,
+    governance::utils::votes::{
+        Data,
+        VotesInternal,
+    },
     psp22,
     psp22::PSP22Error,
     traits::errors::GovernanceError,
 };
 use ink::prelude::vec;
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    BlockNumber,
+    DefaultEnv,
+    Storage,
+    String,
+};
 pub use openbrush::utils::checkpoints::Checkpoint;
 
 /// Extension of ERC20 to support Compound-like voting and delegation.
@@ -49,6 +59,16 @@ pub trait PSP22VotesImpl: VotesInternal {
     fn checkpoints(&self, account: AccountId, pos: u32) -> Result<Checkpoint, GovernanceError> {
         VotesInternal::_checkpoints(self, &account, pos)
     }
+
+    /// Get the balance of `account` at the end of `block`.
+    fn balance_of_at_block(&self, account: AccountId, block: BlockNumber) -> Balance {
+        self.data::<Data>()
+            .balance_checkpoints
+            .get(&account)
+            .unwrap_or_default()
+            .upper_lookup_recent(block as u64)
+            .unwrap_or(0)
+    }
 }
 
 pub trait PSP22VotesInternal: VotesInternal + psp22::Internal {
@@ -68,4 +88,21 @@ pub trait PSP22VotesInternal: VotesInternal + psp22::Internal {
     fn _get_voting_units(&self, account: &AccountId) -> u128 {
         psp22::Internal::_balance_of(self, account)
     }
+
+    /// Records the current balances of `from` and `to` at the current block.
+    ///
+    /// User must call it in `psp22::Internal::_after_token_transfer` of their contract
+    /// to keep `balance_of_at_block` up to date.
+    fn _checkpoint_balances(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+        let block = Self::env().block_number() as u64;
+        for account in [from, to].into_iter().flatten() {
+            let balance = psp22::Internal::_balance_of(self, account);
+            let mut store = self.data::<Data>().balance_checkpoints.get(account).unwrap_or_default();
+            store
+                .push(block, balance)
+                .map_err(|_| PSP22Error::Custom(String::from("UnorderedInsertion")))?;
+            self.data::<Data>().balance_checkpoints.insert(account, &store);
+        }
+        Ok(())
+    }
 }
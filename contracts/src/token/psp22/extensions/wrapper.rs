@@ -27,10 +27,7 @@ pub use crate::{
         *,
     },
 };
-use ink::{
-    env::CallFlags,
-    prelude::vec::Vec,
-};
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
@@ -69,9 +66,13 @@ pub trait Internal {
     fn _recover(&mut self, account: AccountId) -> Result<Balance, PSP22Error>;
 
     /// helper function to transfer the underlying token from caller to the contract
+    ///
+    /// The underlying token is called without allowing reentry, so it can't call the wrapper back.
     fn _deposit(&mut self, amount: Balance) -> Result<(), PSP22Error>;
 
     /// helper function to transfer the underlying token
+    ///
+    /// The underlying token is called without allowing reentry, so it can't call the wrapper back.
     fn _withdraw(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
     /// helper function to get balance of underlying tokens in the contract
@@ -102,7 +103,6 @@ pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal + PSP22 {
                 amount,
                 Vec::<u8>::new(),
             )
-            .call_flags(CallFlags::default().set_allow_reentry(true))
             .try_invoke()
             .unwrap()
             .unwrap()
@@ -114,7 +114,6 @@ pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal + PSP22 {
     fn _withdraw(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         if let Some(underlying) = Internal::_underlying(self) {
             PSP22Ref::transfer_builder(&underlying, account, amount, Vec::<u8>::new())
-                .call_flags(CallFlags::default().set_allow_reentry(true))
                 .try_invoke()
                 .unwrap()
                 .unwrap()
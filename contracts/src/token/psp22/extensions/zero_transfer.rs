@@ -0,0 +1,94 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::{
+    psp22,
+    psp22::extensions::zero_transfer,
+    traits::psp22::{
+        extensions::zero_transfer::*,
+        *,
+    },
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+    String,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use zero_transfer::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub reject_zero_transfers: bool,
+}
+
+pub trait PSP22ZeroTransferImpl: Internal {
+    fn rejects_zero_transfers(&self) -> bool {
+        self._rejects_zero_transfers()
+    }
+}
+
+pub trait Internal {
+    fn _rejects_zero_transfers(&self) -> bool;
+
+    /// Enables or disables rejecting of transfers of zero tokens.
+    fn _set_reject_zero_transfers(&mut self, reject: bool);
+
+    /// Returns `PSP22Error::Custom("ZeroTransfer")` error if zero tokens are transferred,
+    /// minted or burned while rejecting is enabled, so no zero-value `Transfer` event is emitted.
+    ///
+    /// User must call it in `psp22::Internal::_before_token_transfer` of their contract.
+    fn _check_zero_transfer(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _rejects_zero_transfers(&self) -> bool {
+        self.data().reject_zero_transfers.get_or_default()
+    }
+
+    fn _set_reject_zero_transfers(&mut self, reject: bool) {
+        self.data().reject_zero_transfers.set(&reject);
+    }
+
+    fn _check_zero_transfer(
+        &self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if *amount == 0 && Internal::_rejects_zero_transfers(self) {
+            return Err(PSP22Error::Custom(String::from("ZeroTransfer")))
+        }
+        Ok(())
+    }
+}
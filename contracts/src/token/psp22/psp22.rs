@@ -33,6 +33,7 @@ use openbrush::{
         AccountId,
         Balance,
         Storage,
+        String,
     },
 };
 pub use psp22::{
@@ -48,6 +49,10 @@ pub struct Data {
     pub supply: Balance,
     pub balances: Mapping<AccountId, Balance>,
     pub allowances: Mapping<(AccountId, AccountId), Balance, AllowancesKey>,
+    #[lazy]
+    pub strict_approvals: bool,
+    #[lazy]
+    pub reject_transfers_to_contract: bool,
 }
 
 pub struct AllowancesKey;
@@ -75,6 +80,18 @@ pub trait PSP22Impl: Storage<Data> + Internal {
         Ok(())
     }
 
+    fn transfer_all(&mut self, to: AccountId, data: Vec<u8>) -> Result<Balance, PSP22Error> {
+        let from = Self::env().caller();
+        let value = self._balance_of(&from);
+
+        if value == 0 {
+            return Ok(0)
+        }
+
+        self._transfer_from_to(from, to, value, data)?;
+        Ok(value)
+    }
+
     fn transfer_from(
         &mut self,
         from: AccountId,
@@ -89,13 +106,22 @@ pub trait PSP22Impl: Storage<Data> + Internal {
             return Err(PSP22Error::InsufficientAllowance)
         }
 
-        self._approve_from_to(from, caller, allowance - value)?;
+        // `Balance::MAX` allowance is infinite, so we don't spend it.
+        // Otherwise, the spending emits `Approval` event with the remaining allowance.
+        if allowance != Balance::MAX {
+            self._approve_from_to(from, caller, allowance - value)?;
+        }
         self._transfer_from_to(from, to, value, data)?;
         Ok(())
     }
 
     fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
         let owner = Self::env().caller();
+
+        if value != 0 && self._strict_approvals() && self._allowance(&owner, &spender) != 0 {
+            return Err(PSP22Error::Custom(String::from("NonZeroToNonZero")))
+        }
+
         self._approve_from_to(owner, spender, value)?;
         Ok(())
     }
@@ -139,6 +165,24 @@ pub trait Internal {
 
     fn _approve_from_to(&mut self, owner: AccountId, spender: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
+    /// Returns `true` if `approve` requires the allowance to be set to zero before changing it.
+    fn _strict_approvals(&self) -> bool;
+
+    /// Enables or disables the strict approvals mode.
+    ///
+    /// In the strict mode `approve` fails with `NonZeroToNonZero` error
+    /// if both the current allowance and the new value are non-zero.
+    fn _set_strict_approvals(&mut self, strict: bool);
+
+    /// Returns `true` if transfers to the address of the token contract are rejected.
+    fn _reject_transfers_to_contract(&self) -> bool;
+
+    /// Enables or disables the guard against transfers to the address of the token contract.
+    ///
+    /// When enabled, `_transfer_from_to` fails with `TransferToContract` error if `to` is
+    /// the contract itself. Minting, burning and transfers from the contract are not affected.
+    fn _set_reject_transfers_to_contract(&mut self, reject: bool);
+
     fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 
     fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
@@ -182,14 +226,21 @@ pub trait InternalImpl: Storage<Data> + Internal {
         amount: Balance,
         _data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
-        let from_balance = Internal::_balance_of(self, &from);
+        if Internal::_reject_transfers_to_contract(self) && to == Self::env().account_id() {
+            return Err(PSP22Error::Custom(String::from("TransferToContract")))
+        }
 
-        if from_balance < amount {
+        if Internal::_balance_of(self, &from) < amount {
             return Err(PSP22Error::InsufficientBalance)
         }
 
         Internal::_before_token_transfer(self, Some(&from), Some(&to), &amount)?;
 
+        // The hook can mint or burn, so the balance is read again after it
+        let from_balance = Internal::_balance_of(self, &from);
+        if from_balance < amount {
+            return Err(PSP22Error::InsufficientBalance)
+        }
         self.data().balances.insert(&from, &(from_balance - amount));
 
         let to_balance = Internal::_balance_of(self, &to);
@@ -202,11 +253,35 @@ pub trait InternalImpl: Storage<Data> + Internal {
     }
 
     fn _approve_from_to(&mut self, owner: AccountId, spender: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let zero_address = AccountId::from([0x0; 32]);
+        if owner == zero_address {
+            return Err(PSP22Error::ZeroSenderAddress)
+        }
+        if spender == zero_address {
+            return Err(PSP22Error::ZeroRecipientAddress)
+        }
+
         self.data().allowances.insert(&(&owner, &spender), &amount);
         Internal::_emit_approval_event(self, owner, spender, amount);
         Ok(())
     }
 
+    fn _strict_approvals(&self) -> bool {
+        self.data().strict_approvals.get_or_default()
+    }
+
+    fn _set_strict_approvals(&mut self, strict: bool) {
+        self.data().strict_approvals.set(&strict);
+    }
+
+    fn _reject_transfers_to_contract(&self) -> bool {
+        self.data().reject_transfers_to_contract.get_or_default()
+    }
+
+    fn _set_reject_transfers_to_contract(&mut self, reject: bool) {
+        self.data().reject_transfers_to_contract.set(&reject);
+    }
+
     fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         Internal::_before_token_transfer(self, None, Some(&account), &amount)?;
         let mut new_balance = Internal::_balance_of(self, &account);
@@ -223,16 +298,18 @@ pub trait InternalImpl: Storage<Data> + Internal {
     }
 
     fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-        let mut from_balance = Internal::_balance_of(self, &account);
-
-        if from_balance < amount {
+        if Internal::_balance_of(self, &account) < amount {
             return Err(PSP22Error::InsufficientBalance)
         }
 
         Internal::_before_token_transfer(self, Some(&account), None, &amount)?;
 
-        from_balance -= amount;
-        self.data().balances.insert(&account, &from_balance);
+        // The hook can mint or burn, so the balance is read again after it
+        let from_balance = Internal::_balance_of(self, &account);
+        if from_balance < amount {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+        self.data().balances.insert(&account, &(from_balance - amount));
 
         let new_supply = self.data().supply.get_or_default() - amount;
         self.data().supply.set(&new_supply);
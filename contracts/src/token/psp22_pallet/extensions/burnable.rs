@@ -21,16 +21,26 @@
 
 pub use crate::{
     psp22_pallet,
+    psp22_pallet::extensions::burnable,
     traits::psp22::{
         extensions::burnable::*,
         *,
     },
 };
+pub use burnable::Internal as _;
 pub use ink::env::DefaultEnvironment;
-use openbrush::traits::{
-    AccountId,
-    Balance,
-    Storage,
+pub use openbrush::traits::String;
+use openbrush::{
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+    },
 };
 pub use pallet_assets_chain_extension::traits::{
     Error,
@@ -42,8 +52,70 @@ pub use psp22_pallet::{
     PSP22PalletImpl,
 };
 
-pub trait PSP22PalletBurnableImpl: Storage<psp22_pallet::Data> + psp22_pallet::Internal {
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub burn_allowances: Mapping<(AccountId, AccountId), Balance, BurnAllowancesKey>,
+}
+
+pub struct BurnAllowancesKey;
+
+impl<'a> TypeGuard<'a> for BurnAllowancesKey {
+    type Type = &'a (&'a AccountId, &'a AccountId);
+}
+
+pub trait PSP22PalletBurnableImpl: Storage<psp22_pallet::Data> + Internal + psp22_pallet::Internal {
     fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         self._burn_from(account, amount)
     }
+
+    fn burn_with_memo(&mut self, amount: Balance, memo: String) -> Result<(), PSP22Error> {
+        let account = Self::env().caller();
+        self._burn_from(account, amount)?;
+        Internal::_emit_burn_with_memo_event(self, account, amount, memo);
+        Ok(())
+    }
+
+    fn approve_burn(&mut self, burner: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        Internal::_approve_burn(self, Self::env().caller(), burner, amount);
+        Ok(())
+    }
+
+    fn burn_allowance(&self, owner: AccountId, burner: AccountId) -> Balance {
+        Internal::_burn_allowance(self, &owner, &burner)
+    }
+
+    fn burn_from_approved(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        let allowance = Internal::_burn_allowance(self, &account, &caller);
+
+        if allowance < amount {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
+
+        Internal::_approve_burn(self, account, caller, allowance - amount);
+        self._burn_from(account, amount)
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_burn_with_memo_event(&self, _account: AccountId, _amount: Balance, _memo: String);
+
+    fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance;
+
+    /// Sets the amount of `owner`'s tokens that `burner` is allowed to burn.
+    fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_burn_with_memo_event(&self, _account: AccountId, _amount: Balance, _memo: String) {}
+
+    fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance {
+        self.data().burn_allowances.get(&(owner, burner)).unwrap_or(0)
+    }
+
+    fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance) {
+        self.data().burn_allowances.insert(&(&owner, &burner), &amount);
+    }
 }
@@ -21,16 +21,21 @@
 
 pub use crate::{
     psp22_pallet,
+    psp22_pallet::extensions::mintable,
     traits::psp22::{
         extensions::mintable::*,
         *,
     },
 };
 pub use ink::env::DefaultEnvironment;
+use ink::prelude::vec::Vec;
+pub use mintable::Internal as _;
 use openbrush::traits::{
     AccountId,
     Balance,
+    DefaultEnv,
     Storage,
+    String,
 };
 pub use pallet_assets_chain_extension::traits::{
     Error,
@@ -42,8 +47,80 @@ pub use psp22_pallet::{
     PSP22PalletImpl,
 };
 
-pub trait PSP22PalletMintableImpl: Storage<psp22_pallet::Data> + psp22_pallet::Internal {
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub minting_finished: bool,
+}
+
+pub trait PSP22PalletMintableImpl: Storage<psp22_pallet::Data> + Internal + psp22_pallet::Internal {
     fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        Internal::_check_minting_not_finished(self)?;
         self._mint_to(account, amount)
     }
+
+    fn mint_to_many(&mut self, recipients: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error> {
+        Internal::_check_minter(self, &Self::env().caller())?;
+        Internal::_check_minting_not_finished(self)?;
+
+        let mut total: Balance = 0;
+        for (_, amount) in recipients.iter() {
+            total = total
+                .checked_add(*amount)
+                .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+        }
+
+        for (account, amount) in recipients {
+            self._mint_to(account, amount)?;
+        }
+        Ok(())
+    }
+
+    fn finish_minting(&mut self) -> Result<(), PSP22Error> {
+        Internal::_check_minter(self, &Self::env().caller())?;
+        Internal::_finish_minting(self);
+        Ok(())
+    }
+
+    fn minting_finished(&self) -> bool {
+        Internal::_minting_finished(self)
+    }
+}
+
+pub trait Internal {
+    fn _minting_finished(&self) -> bool;
+
+    /// Disables `mint` and `mint_to_many` permanently.
+    fn _finish_minting(&mut self);
+
+    /// Returns `PSP22Error::Custom("MintingFinished")` error if minting is finished.
+    fn _check_minting_not_finished(&self) -> Result<(), PSP22Error>;
+
+    /// Returns `PSP22Error::Custom("NotMinter")` error if `caller` isn't allowed to use `mint_to_many` and `finish_minting`.
+    ///
+    /// Nobody is allowed by default, user should override it to check the [`MINTER`] role
+    /// with `access_control::Internal::_check_role`, the owner or another rule of the contract.
+    fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _minting_finished(&self) -> bool {
+        self.data().minting_finished.get_or_default()
+    }
+
+    fn _finish_minting(&mut self) {
+        self.data().minting_finished.set(&true);
+    }
+
+    fn _check_minting_not_finished(&self) -> Result<(), PSP22Error> {
+        if Internal::_minting_finished(self) {
+            return Err(PSP22Error::Custom(String::from("MintingFinished")))
+        }
+        Ok(())
+    }
+
+    fn _check_minter(&self, _caller: &AccountId) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom(String::from("NotMinter")))
+    }
 }
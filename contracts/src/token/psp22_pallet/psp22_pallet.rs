@@ -98,6 +98,17 @@ pub trait PSP22PalletImpl: Storage<Data> + Internal {
         Ok(())
     }
 
+    fn transfer_all(&mut self, to: AccountId, data: Vec<u8>) -> Result<Balance, PSP22Error> {
+        let value = PSP22PalletImpl::balance_of(self, self._sender());
+
+        if value == 0 {
+            return Ok(0)
+        }
+
+        PSP22PalletImpl::transfer(self, to, value, data)?;
+        Ok(value)
+    }
+
     fn transfer_from(
         &mut self,
         from: AccountId,
@@ -0,0 +1,99 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::{
+        attribute_index,
+        metadata,
+    },
+    traits::psp34::{
+        extensions::attribute_index::*,
+        *,
+    },
+};
+use ink::prelude::vec::Vec;
+pub use metadata::{
+    Internal as _,
+    InternalImpl as _,
+    PSP34MetadataImpl,
+};
+use openbrush::{
+    storage::{
+        MultiMapping,
+        TypeGuard,
+    },
+    traits::{
+        Storage,
+        String,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// Token ids by the `(key, value)` of their attributes.
+    pub tokens: MultiMapping<(String, String), Id, AttributeIndexKey>,
+}
+
+pub struct AttributeIndexKey;
+
+impl<'a> TypeGuard<'a> for AttributeIndexKey {
+    type Type = &'a (&'a String, &'a String);
+}
+
+pub trait PSP34AttributeIndexImpl: Storage<Data> {
+    fn tokens_with_attribute(&self, key: String, value: String, start: u128, count: u128) -> Vec<Id> {
+        let bucket = (&key, &value);
+        let end = start.saturating_add(count).min(self.data().tokens.count(&bucket));
+
+        (start..end)
+            .filter_map(|index| self.data().tokens.get_value(&bucket, &index))
+            .collect()
+    }
+}
+
+pub trait Internal {
+    /// Sets the attribute and moves the token from the bucket of the previous value
+    /// of the attribute to the bucket of the new one.
+    fn _set_attribute(&mut self, id: Id, key: String, value: String);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + Storage<metadata::Data> + metadata::InternalImpl {
+    fn _set_attribute(&mut self, id: Id, key: String, value: String) {
+        let previous = self.data::<metadata::Data>().attributes.get(&(&id, &key));
+
+        if let Some(previous) = previous {
+            self.data::<Data>().tokens.remove_value(&(&key, &previous), &id);
+        }
+        self.data::<Data>().tokens.insert(&(&key, &value), &id);
+
+        metadata::InternalImpl::_set_attribute(self, id, key, value)
+    }
+}
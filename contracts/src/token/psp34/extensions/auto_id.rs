@@ -0,0 +1,92 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::auto_id,
+    traits::psp34::{
+        extensions::auto_id::*,
+        *,
+    },
+};
+pub use auto_id::Internal as _;
+use openbrush::traits::{
+    AccountId,
+    Storage,
+    String,
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub next_id: u128,
+}
+
+pub trait PSP34AutoIdImpl: Internal {
+    fn next_id(&self) -> u128 {
+        self._next_id()
+    }
+
+    fn mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error> {
+        self._mint_next(to)
+    }
+}
+
+pub trait Internal {
+    fn _next_id(&self) -> u128;
+
+    fn _set_next_id(&mut self, next_id: u128);
+
+    fn _mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + psp34::Internal + Storage<Data> {
+    fn _next_id(&self) -> u128 {
+        self.data().next_id.get_or_default()
+    }
+
+    fn _set_next_id(&mut self, next_id: u128) {
+        self.data().next_id.set(&next_id);
+    }
+
+    fn _mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error> {
+        let current = Internal::_next_id(self);
+        let next = current
+            .checked_add(1)
+            .ok_or(PSP34Error::Custom(String::from("Overflow")))?;
+
+        let id = Id::U128(current);
+        psp34::Internal::_mint_to(self, to, id.clone())?;
+        Internal::_set_next_id(self, next);
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::capped,
+    traits::psp34::{
+        extensions::capped::*,
+        *,
+    },
+};
+pub use capped::Internal as _;
+use openbrush::traits::{
+    AccountId,
+    Storage,
+    String,
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub cap: u128,
+    #[lazy]
+    pub reserved: u128,
+    /// The number of tokens minted with `_mint_reserved`.
+    #[lazy]
+    pub reserved_minted: u128,
+}
+
+pub trait PSP34CappedImpl: Internal {
+    fn cap(&self) -> u128 {
+        self._cap()
+    }
+
+    fn reserved(&self) -> u128 {
+        self._reserved()
+    }
+}
+
+pub trait Internal {
+    /// Initializes the collection's cap
+    fn _init_cap(&mut self, cap: u128) -> Result<(), PSP34Error>;
+
+    fn _cap(&self) -> u128;
+
+    fn _reserved(&self) -> u128;
+
+    /// Reserves `reserved` tokens of the cap for `_mint_reserved`.
+    ///
+    /// Returns `PSP34Error::Custom("ReservedExceedsCap")` error if `reserved` is above the cap.
+    fn _set_reserved(&mut self, reserved: u128) -> Result<(), PSP34Error>;
+
+    /// Mints a token on the public path, which is limited to `cap - reserved` tokens.
+    ///
+    /// Returns `PSP34Error::Custom("CapExceeded")` error if the public part of the cap is minted out.
+    fn _mint_public(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error>;
+
+    /// Mints a token into the reserve. It isn't restricted by default,
+    /// user should expose it only to the owner, e.g. with the `only_owner` modifier.
+    ///
+    /// Returns `PSP34Error::Custom("CapExceeded")` error if the whole cap is minted out.
+    fn _mint_reserved(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp34::Internal {
+    fn _init_cap(&mut self, cap: u128) -> Result<(), PSP34Error> {
+        if cap == 0 {
+            return Err(PSP34Error::Custom(String::from("Cap must be above 0")))
+        }
+        self.data().cap.set(&cap);
+        Ok(())
+    }
+
+    fn _cap(&self) -> u128 {
+        self.data().cap.get_or_default()
+    }
+
+    fn _reserved(&self) -> u128 {
+        self.data().reserved.get_or_default()
+    }
+
+    fn _set_reserved(&mut self, reserved: u128) -> Result<(), PSP34Error> {
+        if reserved > Internal::_cap(self) {
+            return Err(PSP34Error::Custom(String::from("ReservedExceedsCap")))
+        }
+        self.data().reserved.set(&reserved);
+        Ok(())
+    }
+
+    fn _mint_public(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+        let total_supply = psp34::Internal::_total_supply(self);
+        let public_minted = total_supply.saturating_sub(self.data().reserved_minted.get_or_default());
+        let public_cap = Internal::_cap(self).saturating_sub(Internal::_reserved(self));
+
+        if public_minted >= public_cap || total_supply >= Internal::_cap(self) {
+            return Err(PSP34Error::Custom(String::from("CapExceeded")))
+        }
+        psp34::Internal::_mint_to(self, to, id)
+    }
+
+    fn _mint_reserved(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+        if psp34::Internal::_total_supply(self) >= Internal::_cap(self) {
+            return Err(PSP34Error::Custom(String::from("CapExceeded")))
+        }
+        psp34::Internal::_mint_to(self, to, id)?;
+
+        let reserved_minted = self.data().reserved_minted.get_or_default();
+        self.data().reserved_minted.set(&(reserved_minted + 1));
+        Ok(())
+    }
+}
@@ -28,6 +28,8 @@ pub use crate::{
         *,
     },
 };
+pub use enumerable::Internal as _;
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::{
         Mapping,
@@ -38,6 +40,7 @@ use openbrush::{
         AccountId,
         Balance,
         Storage,
+        String,
     },
 };
 pub use psp34::{
@@ -54,7 +57,40 @@ pub use psp34::{
 pub struct Data {
     pub token_owner: Mapping<Id, Owner>,
     pub operator_approvals: Mapping<(Owner, Operator, Option<Id>), (), ApprovalsKey>,
+    /// Operators approved for a single token, used to clear approvals when the token moves.
+    pub token_approvals: Mapping<Id, Vec<Operator>>,
     pub balances: MultiMapping<Option<AccountId>, Id, EnumerableKey>,
+    /// If `true`, the list of all tokens isn't stored, it is derived from the range
+    /// of sequentially minted ids `first_id..next_id` instead.
+    #[lazy]
+    pub sequential: bool,
+    #[lazy]
+    pub first_id: u128,
+    #[lazy]
+    pub next_id: u128,
+}
+
+fn sequential_id(id: &Id) -> Option<u128> {
+    match id {
+        Id::U128(id) => Some(*id),
+        _ => None,
+    }
+}
+
+/// Returns the minted id if it is the next id of the range `first_id..next_id`.
+fn check_sequential_mint(id: &Id, next_id: u128) -> Result<u128, PSP34Error> {
+    match sequential_id(id) {
+        Some(id) if id == next_id => Ok(id),
+        _ => Err(PSP34Error::Custom(String::from("NonSequentialId"))),
+    }
+}
+
+/// Returns the burned id if it is the first or the last id of the range `first_id..next_id`.
+fn check_sequential_burn(id: &Id, first_id: u128, next_id: u128) -> Result<u128, PSP34Error> {
+    match sequential_id(id) {
+        Some(id) if first_id < next_id && (id == first_id || id == next_id - 1) => Ok(id),
+        _ => Err(PSP34Error::Custom(String::from("NonContiguousBurn"))),
+    }
 }
 
 pub struct EnumerableKey;
@@ -68,22 +104,43 @@ pub trait BalancesManagerImpl: Storage<Data> {
         self.data().balances.count(&Some(owner)) as u32
     }
 
-    fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) {
-        self.data().balances.insert(&Some(owner), id);
+    fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) -> Result<(), PSP34Error> {
         if increase_supply {
-            self.data().balances.insert(&None, id);
+            if self.data().sequential.get_or_default() {
+                let id = check_sequential_mint(id, self.data().next_id.get_or_default())?;
+                self.data().next_id.set(&(id + 1));
+            } else {
+                self.data().balances.insert(&None, id);
+            }
         }
+        self.data().balances.insert(&Some(owner), id);
+        Ok(())
     }
 
-    fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) {
-        self.data().balances.remove_value(&Some(owner), id);
+    fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) -> Result<(), PSP34Error> {
         if decrease_supply {
-            self.data().balances.remove_value(&None, id);
+            if self.data().sequential.get_or_default() {
+                let first_id = self.data().first_id.get_or_default();
+                let id = check_sequential_burn(id, first_id, self.data().next_id.get_or_default())?;
+                if id == first_id {
+                    self.data().first_id.set(&(id + 1));
+                } else {
+                    self.data().next_id.set(&id);
+                }
+            } else {
+                self.data().balances.remove_value(&None, id);
+            }
         }
+        self.data().balances.remove_value(&Some(owner), id);
+        Ok(())
     }
 
     fn _total_supply(&self) -> Balance {
-        self.data().balances.count(&None)
+        if self.data().sequential.get_or_default() {
+            self.data().next_id.get_or_default() - self.data().first_id.get_or_default()
+        } else {
+            self.data().balances.count(&None)
+        }
     }
 
     fn _owner_of(&self, id: &Id) -> Option<AccountId> {
@@ -96,10 +153,33 @@ pub trait BalancesManagerImpl: Storage<Data> {
 
     fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
         self.data().operator_approvals.insert(&(owner, operator, id), &());
+        if let Some(id) = id {
+            let mut operators = self.data().token_approvals.get(*id).unwrap_or_default();
+            if !operators.contains(operator) {
+                operators.push(*operator);
+                self.data().token_approvals.insert(*id, &operators);
+            }
+        }
     }
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
         self.data().operator_approvals.remove(&(owner, operator, id));
+        if let Some(id) = id {
+            let mut operators = self.data().token_approvals.get(*id).unwrap_or_default();
+            operators.retain(|approved| approved != operator);
+            if operators.is_empty() {
+                self.data().token_approvals.remove(*id);
+            } else {
+                self.data().token_approvals.insert(*id, &operators);
+            }
+        }
+    }
+
+    fn _clear_token_approvals(&mut self, owner: &Owner, id: &Id) {
+        for operator in self.data().token_approvals.get(id).unwrap_or_default() {
+            self.data().operator_approvals.remove(&(owner, &operator, &Some(id)));
+        }
+        self.data().token_approvals.remove(id);
     }
 
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
@@ -120,9 +200,77 @@ pub trait PSP34EnumerableImpl: Storage<Data> {
     }
 
     fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error> {
+        if self.data().sequential.get_or_default() {
+            let first_id = self.data().first_id.get_or_default();
+            if index >= self.data().next_id.get_or_default() - first_id {
+                return Err(PSP34Error::TokenNotExists)
+            }
+            return Ok(Id::U128(first_id + index))
+        }
+
         self.data()
             .balances
             .get_value(&None, &index)
             .ok_or(PSP34Error::TokenNotExists)
     }
 }
+
+pub trait Internal {
+    /// Derives the total supply and `token_by_index` from the range of sequentially minted ids,
+    /// which saves a storage write per mint. Tokens must be minted as `Id::U128`
+    /// starting from `first_id`, and only the first or the last token of the range can be burned.
+    ///
+    /// Returns `PSP34Error::Custom("TokensExist")` error if some tokens were already minted.
+    fn _init_sequential_supply(&mut self, first_id: u128) -> Result<(), PSP34Error>;
+
+    fn _is_sequential_supply(&self) -> bool;
+
+    /// Returns `PSP34Error::Custom("NonSequentialId")` error if the minted id isn't the next id of the range,
+    /// or `PSP34Error::Custom("NonContiguousBurn")` error if the burned id isn't at an end of the range.
+    /// Does nothing if the sequential supply isn't initialized.
+    ///
+    /// The balances manager always performs the same check when minting or burning. User can call it
+    /// in `psp34::Internal::_before_token_transfer` of their contract to fail before the other hooks.
+    fn _check_sequential_supply(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _init_sequential_supply(&mut self, first_id: u128) -> Result<(), PSP34Error> {
+        if self.data().balances.count(&None) > 0 || Internal::_is_sequential_supply(self) {
+            return Err(PSP34Error::Custom(String::from("TokensExist")))
+        }
+        self.data().sequential.set(&true);
+        self.data().first_id.set(&first_id);
+        self.data().next_id.set(&first_id);
+        Ok(())
+    }
+
+    fn _is_sequential_supply(&self) -> bool {
+        self.data().sequential.get_or_default()
+    }
+
+    fn _check_sequential_supply(
+        &self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        if !Internal::_is_sequential_supply(self) {
+            return Ok(())
+        }
+
+        let first_id = self.data().first_id.get_or_default();
+        let next_id = self.data().next_id.get_or_default();
+
+        match (from, to) {
+            (None, Some(_)) => check_sequential_mint(id, next_id).map(|_| ()),
+            (Some(_), None) => check_sequential_burn(id, first_id, next_id).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    traits::psp34::{
+        extensions::evolve::*,
+        *,
+    },
+};
+use openbrush::traits::DefaultEnv;
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+pub trait PSP34EvolveImpl: psp34::Internal {
+    fn evolve(&mut self, from_id: Id, to_id: Id) -> Result<(), PSP34Error> {
+        let owner = self._check_token_exists(&from_id)?;
+        let caller = Self::env().caller();
+
+        if owner != caller && !self._allowance(&owner, &caller, &Some(&from_id)) {
+            return Err(PSP34Error::NotApproved)
+        }
+        if self._owner_of(&to_id).is_some() {
+            return Err(PSP34Error::TokenExists)
+        }
+
+        self._burn_from(owner, from_id)?;
+        self._mint_to(owner, to_id)
+    }
+}
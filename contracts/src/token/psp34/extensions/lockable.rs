@@ -0,0 +1,110 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::{
+    access_control,
+    psp34,
+    psp34::extensions::lockable,
+    traits::psp34::{
+        extensions::lockable::*,
+        *,
+    },
+};
+pub use lockable::Internal as _;
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub locked: Mapping<Id, ()>,
+}
+
+pub trait PSP34LockableImpl: Internal + access_control::Internal {
+    fn is_locked(&self, id: Id) -> bool {
+        self._is_locked(&id)
+    }
+
+    #[modifiers(access_control::only_role(LOCKER))]
+    fn lock(&mut self, id: Id) -> Result<(), PSP34Error> {
+        self._lock(id)
+    }
+
+    #[modifiers(access_control::only_role(LOCKER))]
+    fn unlock(&mut self, id: Id) -> Result<(), PSP34Error> {
+        self._unlock(id);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    fn _is_locked(&self, id: &Id) -> bool;
+
+    /// Returns `PSP34Error::TokenNotExists` error if the token doesn't exist.
+    fn _lock(&mut self, id: Id) -> Result<(), PSP34Error>;
+
+    fn _unlock(&mut self, id: Id);
+
+    /// Returns `PSP34Error::Custom("TokenLocked")` error if a locked token is transferred or burned.
+    ///
+    /// User must call it in `psp34::Internal::_before_token_transfer` of their contract.
+    fn _check_lock(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + psp34::Internal {
+    fn _is_locked(&self, id: &Id) -> bool {
+        self.data().locked.contains(id)
+    }
+
+    fn _lock(&mut self, id: Id) -> Result<(), PSP34Error> {
+        if psp34::Internal::_owner_of(self, &id).is_none() {
+            return Err(PSP34Error::TokenNotExists)
+        }
+        self.data().locked.insert(&id, &());
+        Ok(())
+    }
+
+    fn _unlock(&mut self, id: Id) {
+        self.data().locked.remove(&id);
+    }
+
+    fn _check_lock(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error> {
+        if from.is_some() && Internal::_is_locked(self, id) {
+            return Err(PSP34Error::Custom(String::from("TokenLocked")))
+        }
+        Ok(())
+    }
+}
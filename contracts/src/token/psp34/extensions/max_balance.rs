@@ -0,0 +1,92 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::max_balance,
+    traits::psp34::{
+        extensions::max_balance::*,
+        *,
+    },
+};
+pub use max_balance::Internal as _;
+use openbrush::traits::{
+    AccountId,
+    Storage,
+    String,
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub max_balance: u32,
+}
+
+pub trait PSP34MaxBalanceImpl: Internal {
+    fn max_balance(&self) -> u32 {
+        self._max_balance()
+    }
+}
+
+pub trait Internal {
+    fn _max_balance(&self) -> u32;
+
+    /// Sets the maximal number of tokens per account. Zero disables the limit.
+    fn _set_max_balance(&mut self, max_balance: u32);
+
+    /// Returns `PSP34Error::Custom("MaxBalanceExceeded")` error if the token is minted or transferred
+    /// to an account that already owns `max_balance` tokens. Burning and transferring out are allowed.
+    ///
+    /// User must call it in `psp34::Internal::_before_token_transfer` of their contract.
+    fn _check_max_balance(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + psp34::BalancesManager + Storage<Data> {
+    fn _max_balance(&self) -> u32 {
+        self.data().max_balance.get_or_default()
+    }
+
+    fn _set_max_balance(&mut self, max_balance: u32) {
+        self.data().max_balance.set(&max_balance);
+    }
+
+    fn _check_max_balance(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error> {
+        if let Some(to) = to {
+            let max_balance = Internal::_max_balance(self);
+
+            // Transfer to itself doesn't change the balance
+            if max_balance > 0 && from != Some(to) && psp34::BalancesManager::_balance_of(self, to) >= max_balance {
+                return Err(PSP34Error::Custom(String::from("MaxBalanceExceeded")))
+            }
+        }
+        Ok(())
+    }
+}
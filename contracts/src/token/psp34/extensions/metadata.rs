@@ -49,6 +49,9 @@ pub use psp34::{
 #[openbrush::storage_item]
 pub struct Data {
     pub attributes: Mapping<(Id, String), String, AttributesKey>,
+    /// Keys of the token attributes in the order of their creation.
+    pub attribute_names: Mapping<(Id, u32), String, AttributeNamesKey>,
+    pub attribute_count: Mapping<Id, u32>,
 }
 
 pub struct AttributesKey;
@@ -57,10 +60,24 @@ impl<'a> TypeGuard<'a> for AttributesKey {
     type Type = &'a (&'a Id, &'a String);
 }
 
+pub struct AttributeNamesKey;
+
+impl<'a> TypeGuard<'a> for AttributeNamesKey {
+    type Type = &'a (&'a Id, &'a u32);
+}
+
 pub trait PSP34MetadataImpl: Storage<Data> {
     fn get_attribute(&self, id: Id, key: String) -> Option<String> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    fn get_attribute_count(&self, id: Id) -> u32 {
+        self.data().attribute_count.get(&id).unwrap_or(0)
+    }
+
+    fn get_attribute_name(&self, id: Id, index: u32) -> Option<String> {
+        self.data().attribute_names.get(&(&id, &index))
+    }
 }
 
 pub trait Internal {
@@ -74,6 +91,11 @@ pub trait InternalImpl: Internal + Storage<Data> {
     fn _emit_attribute_set_event(&self, _id: Id, _key: String, _data: String) {}
 
     fn _set_attribute(&mut self, id: Id, key: String, value: String) {
+        if !self.data().attributes.contains(&(&id, &key)) {
+            let count = self.data().attribute_count.get(&id).unwrap_or(0);
+            self.data().attribute_names.insert(&(&id, &count), &key);
+            self.data().attribute_count.insert(&id, &(count + 1));
+        }
         self.data().attributes.insert(&(&id, &key), &value);
         Internal::_emit_attribute_set_event(self, id, key, value);
     }
@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    psp34,
+    psp34::extensions::metadata,
+    traits::psp34::{
+        extensions::metadata_admin::*,
+        *,
+    },
+};
+pub use metadata::Internal as _;
+use openbrush::{
+    modifiers,
+    traits::String,
+};
+
+pub trait PSP34MetadataAdminImpl: access_control::Internal + metadata::Internal {
+    #[modifiers(access_control::only_role(METADATA_ADMIN))]
+    fn set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), PSP34Error> {
+        metadata::Internal::_set_attribute(self, id, key, value);
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::metadata,
+    traits::psp34::{
+        extensions::mintable_metadata::*,
+        *,
+    },
+};
+use ink::prelude::vec::Vec;
+pub use metadata::Internal as _;
+use openbrush::traits::AccountId;
+pub use openbrush::traits::String;
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+pub trait PSP34MintableMetadataImpl: psp34::Internal + metadata::Internal {
+    fn mint_with_attributes(
+        &mut self,
+        account: AccountId,
+        id: Id,
+        attributes: Vec<(String, String)>,
+    ) -> Result<(), PSP34Error> {
+        // Everything that can fail is checked before the first write
+        for (i, (key, _)) in attributes.iter().enumerate() {
+            if attributes[..i].iter().any(|(previous, _)| previous == key) {
+                return Err(PSP34Error::Custom(String::from("DuplicateAttribute")))
+            }
+        }
+
+        psp34::Internal::_mint_to(self, account, id.clone())?;
+
+        for (key, value) in attributes {
+            metadata::Internal::_set_attribute(self, id.clone(), key, value);
+        }
+
+        Ok(())
+    }
+}
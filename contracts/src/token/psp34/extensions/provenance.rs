@@ -0,0 +1,83 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::provenance,
+    traits::psp34::{
+        extensions::provenance::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+    utils::clock::Clock,
+};
+pub use provenance::Internal as _;
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub last_transferred: Mapping<Id, u64>,
+}
+
+pub trait PSP34ProvenanceImpl: Internal {
+    fn last_transferred(&self, id: Id) -> Option<u64> {
+        self._last_transferred(&id)
+    }
+}
+
+pub trait Internal {
+    fn _last_transferred(&self, id: &Id) -> Option<u64>;
+
+    /// Sets the last transfer time of the token to the current block timestamp on mint and transfer,
+    /// and clears it on burn.
+    ///
+    /// User must call it in `psp34::Internal::_before_token_transfer` of their contract.
+    fn _record_transfer(&mut self, to: Option<&AccountId>, id: &Id);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + Clock {
+    fn _last_transferred(&self, id: &Id) -> Option<u64> {
+        self.data().last_transferred.get(id)
+    }
+
+    fn _record_transfer(&mut self, to: Option<&AccountId>, id: &Id) {
+        if to.is_some() {
+            self.data().last_transferred.insert(id, &self.now());
+        } else {
+            self.data().last_transferred.remove(id);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::{
+    ownable,
+    psp34,
+    psp34::extensions::reveal,
+    traits::psp34::{
+        extensions::reveal::*,
+        *,
+    },
+};
+use ink::prelude::string::ToString;
+use openbrush::{
+    modifiers,
+    traits::{
+        Storage,
+        String,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use reveal::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub revealed: bool,
+    #[lazy]
+    pub placeholder_uri: String,
+    #[lazy]
+    pub base_uri: String,
+}
+
+pub trait PSP34RevealImpl: Internal + Storage<ownable::Data> + psp34::Internal {
+    fn is_revealed(&self) -> bool {
+        self._is_revealed()
+    }
+
+    fn token_uri(&self, id: Id) -> Result<String, PSP34Error> {
+        if self._owner_of(&id).is_none() {
+            return Err(PSP34Error::TokenNotExists)
+        }
+
+        Ok(self._token_uri(&id))
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn reveal(&mut self) -> Result<(), PSP34Error> {
+        self._reveal()
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when the collection is revealed.
+    fn _emit_revealed_event(&self);
+
+    fn _is_revealed(&self) -> bool;
+
+    fn _set_placeholder_uri(&mut self, placeholder_uri: String);
+
+    fn _set_base_uri(&mut self, base_uri: String);
+
+    fn _token_uri(&self, id: &Id) -> String;
+
+    fn _reveal(&mut self) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_revealed_event(&self) {}
+
+    fn _is_revealed(&self) -> bool {
+        self.data().revealed.get_or_default()
+    }
+
+    fn _set_placeholder_uri(&mut self, placeholder_uri: String) {
+        self.data().placeholder_uri.set(&placeholder_uri);
+    }
+
+    fn _set_base_uri(&mut self, base_uri: String) {
+        self.data().base_uri.set(&base_uri);
+    }
+
+    fn _token_uri(&self, id: &Id) -> String {
+        if !Internal::_is_revealed(self) {
+            return self.data().placeholder_uri.get_or_default()
+        }
+
+        let mut uri = self.data().base_uri.get_or_default();
+        uri.push_str(&id_to_string(id));
+        uri
+    }
+
+    fn _reveal(&mut self) -> Result<(), PSP34Error> {
+        if Internal::_is_revealed(self) {
+            return Err(PSP34Error::Custom(String::from("AlreadyRevealed")))
+        }
+
+        self.data().revealed.set(&true);
+        Internal::_emit_revealed_event(self);
+        Ok(())
+    }
+}
+
+/// Formats numeric ids in decimal and `Id::Bytes` in lowercase hex.
+fn id_to_string(id: &Id) -> String {
+    match id {
+        Id::U8(value) => value.to_string(),
+        Id::U16(value) => value.to_string(),
+        Id::U32(value) => value.to_string(),
+        Id::U64(value) => value.to_string(),
+        Id::U128(value) => value.to_string(),
+        Id::Bytes(bytes) => {
+            const HEX: &[u8; 16] = b"0123456789abcdef";
+            let mut hex = String::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                hex.push(HEX[(byte >> 4) as usize] as char);
+                hex.push(HEX[(byte & 0x0f) as usize] as char);
+            }
+            hex
+        }
+    }
+}
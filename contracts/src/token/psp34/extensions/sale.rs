@@ -0,0 +1,119 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::sale,
+    traits::psp34::{
+        extensions::sale::*,
+        *,
+    },
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+    String,
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use sale::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub price: Balance,
+    #[lazy]
+    pub recipient: Option<AccountId>,
+    #[lazy]
+    pub refund_overpayment: bool,
+}
+
+pub trait PSP34SaleImpl: Internal + psp34::Internal + Storage<Data> {
+    fn price(&self) -> Balance {
+        self.data().price.get_or_default()
+    }
+
+    fn mint_sale(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+        let price = self.data().price.get_or_default();
+        let paid = Self::env().transferred_value();
+
+        if paid < price {
+            return Err(PSP34Error::Custom(String::from("InsufficientPayment")))
+        }
+        let overpayment = paid - price;
+        if overpayment > 0 && !self.data().refund_overpayment.get_or_default() {
+            return Err(PSP34Error::Custom(String::from("Overpayment")))
+        }
+
+        psp34::Internal::_mint_to(self, to, id)?;
+
+        if overpayment > 0 && Self::env().transfer(Self::env().caller(), overpayment).is_err() {
+            return Err(PSP34Error::Custom(String::from("TransferFailed")))
+        }
+        if let Some(recipient) = Internal::_sale_recipient(self) {
+            if price > 0 && Self::env().transfer(recipient, price).is_err() {
+                return Err(PSP34Error::Custom(String::from("TransferFailed")))
+            }
+        }
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    fn _set_price(&mut self, price: Balance);
+
+    /// Returns the account that receives the proceeds of the sale.
+    ///
+    /// If there is no recipient, the proceeds stay on the contract.
+    fn _sale_recipient(&self) -> Option<AccountId>;
+
+    fn _set_sale_recipient(&mut self, recipient: Option<AccountId>);
+
+    /// Refunds the overpayment to the caller if `true`, otherwise rejects it.
+    fn _set_refund_overpayment(&mut self, refund: bool);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _set_price(&mut self, price: Balance) {
+        self.data().price.set(&price);
+    }
+
+    fn _sale_recipient(&self) -> Option<AccountId> {
+        self.data().recipient.get_or_default()
+    }
+
+    fn _set_sale_recipient(&mut self, recipient: Option<AccountId>) {
+        self.data().recipient.set(&recipient);
+    }
+
+    fn _set_refund_overpayment(&mut self, refund: bool) {
+        self.data().refund_overpayment.set(&refund);
+    }
+}
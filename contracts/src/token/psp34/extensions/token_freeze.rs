@@ -0,0 +1,94 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::token_freeze,
+    traits::psp34::{
+        extensions::token_freeze::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use token_freeze::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub frozen: Mapping<Id, ()>,
+}
+
+pub trait PSP34TokenFreezeImpl: Internal {
+    fn is_token_frozen(&self, id: Id) -> bool {
+        self._is_token_frozen(&id)
+    }
+}
+
+pub trait Internal {
+    fn _is_token_frozen(&self, id: &Id) -> bool;
+
+    fn _freeze_token(&mut self, id: Id);
+
+    fn _unfreeze_token(&mut self, id: Id);
+
+    /// Returns `PSP34Error::Custom("TokenFrozen")` error if a frozen token is transferred or burned.
+    /// Minting is allowed.
+    ///
+    /// User must call it in `psp34::Internal::_before_token_transfer` of their contract.
+    fn _check_token_freeze(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _is_token_frozen(&self, id: &Id) -> bool {
+        self.data().frozen.contains(id)
+    }
+
+    fn _freeze_token(&mut self, id: Id) {
+        self.data().frozen.insert(&id, &());
+    }
+
+    fn _unfreeze_token(&mut self, id: Id) {
+        self.data().frozen.remove(&id);
+    }
+
+    fn _check_token_freeze(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error> {
+        if from.is_some() && Internal::_is_token_frozen(self, id) {
+            return Err(PSP34Error::Custom(String::from("TokenFrozen")))
+        }
+        Ok(())
+    }
+}
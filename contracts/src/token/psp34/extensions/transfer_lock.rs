@@ -0,0 +1,89 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::transfer_lock,
+    traits::psp34::{
+        extensions::transfer_lock::*,
+        *,
+    },
+};
+use openbrush::{
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+    utils::clock::Clock,
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use transfer_lock::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub unlock_time: u64,
+}
+
+pub trait PSP34TransferLockImpl: Internal {
+    fn unlock_time(&self) -> u64 {
+        self._unlock_time()
+    }
+}
+
+pub trait Internal {
+    fn _unlock_time(&self) -> u64;
+
+    fn _set_unlock_time(&mut self, unlock_time: u64);
+
+    /// Returns `PSP34Error::Custom("TransfersLocked")` error if tokens are transferred
+    /// between accounts before the unlock time. Minting and burning are allowed.
+    ///
+    /// User must call it in `psp34::Internal::_before_token_transfer` of their contract.
+    fn _check_transfer_lock(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + Clock {
+    fn _unlock_time(&self) -> u64 {
+        self.data().unlock_time.get_or_default()
+    }
+
+    fn _set_unlock_time(&mut self, unlock_time: u64) {
+        self.data().unlock_time.set(&unlock_time);
+    }
+
+    fn _check_transfer_lock(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error> {
+        if from.is_some() && to.is_some() && self.now() < Internal::_unlock_time(self) {
+            return Err(PSP34Error::Custom(String::from("TransfersLocked")))
+        }
+        Ok(())
+    }
+}
@@ -25,10 +25,26 @@ pub mod psp34;
 pub use psp34::*;
 
 pub mod extensions {
+    pub mod attribute_index;
+    pub mod auto_id;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
+    pub mod evolve;
+    #[cfg(feature = "access_control")]
+    pub mod lockable;
+    pub mod max_balance;
     pub mod metadata;
+    #[cfg(feature = "access_control")]
+    pub mod metadata_admin;
     pub mod mintable;
+    pub mod mintable_metadata;
+    pub mod provenance;
+    #[cfg(feature = "ownable")]
+    pub mod reveal;
+    pub mod sale;
+    pub mod token_freeze;
+    pub mod transfer_lock;
 }
 
 pub type Owner = AccountId;
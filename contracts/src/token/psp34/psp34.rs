@@ -27,7 +27,10 @@ pub use crate::{
     },
     traits::psp34::*,
 };
-use ink::prelude::vec::Vec;
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
 use openbrush::{
     storage::{
         Mapping,
@@ -38,6 +41,7 @@ use openbrush::{
         Balance,
         DefaultEnv,
         Storage,
+        String,
     },
 };
 pub use psp34::{
@@ -52,6 +56,8 @@ pub use psp34::{
 pub struct Data {
     pub token_owner: Mapping<Id, Owner>,
     pub operator_approvals: Mapping<(Owner, Operator, Option<Id>), (), ApprovalsKey>,
+    /// Operators approved for a single token, used to clear approvals when the token moves.
+    pub token_approvals: Mapping<Id, Vec<Operator>>,
     pub owned_tokens_count: Mapping<Owner, u32>,
     #[lazy]
     pub total_supply: Balance,
@@ -77,6 +83,18 @@ pub trait PSP34Impl: Internal + PSP34 + BalancesManager + Sized {
         Internal::_owner_of(self, &id)
     }
 
+    fn owners_of(&self, ids: Vec<Id>) -> Result<Vec<Option<AccountId>>, PSP34Error> {
+        if ids.len() > MAX_OWNERS_OF_IDS {
+            return Err(PSP34Error::Custom(String::from("BatchTooLarge")))
+        }
+
+        Ok(ids.iter().map(|id| Internal::_owner_of(self, id)).collect())
+    }
+
+    fn exists(&self, id: Id) -> bool {
+        Internal::_owner_of(self, &id).is_some()
+    }
+
     fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
         self._allowance(&owner, &operator, &id.as_ref())
     }
@@ -85,7 +103,41 @@ pub trait PSP34Impl: Internal + PSP34 + BalancesManager + Sized {
         self._approve_for(operator, id, approved)
     }
 
+    fn approve_batch(&mut self, operator: AccountId, ids: Vec<Id>, approved: bool) -> Result<(), PSP34Error> {
+        let caller = Self::env().caller();
+
+        // Validate the whole batch before approving any of the tokens
+        for id in ids.iter() {
+            let owner = self._check_token_exists(id)?;
+
+            if approved && owner == operator {
+                return Err(PSP34Error::SelfApprove)
+            }
+            if owner != caller && !self._allowance(&owner, &caller, &None) {
+                return Err(PSP34Error::NotApproved)
+            }
+        }
+
+        for id in ids {
+            self._approve_for(operator, Some(id), approved)?;
+        }
+
+        Ok(())
+    }
+
     fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+        if self._check_token_exists(&id)? != Self::env().caller() {
+            return Err(PSP34Error::NotOwner)
+        }
+
+        self._transfer_token(to, id, data)
+    }
+
+    fn transfer_from(&mut self, from: AccountId, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+        if self._check_token_exists(&id)? != from {
+            return Err(PSP34Error::NotOwner)
+        }
+
         self._transfer_token(to, id, data)
     }
 
@@ -117,6 +169,17 @@ pub trait Internal {
 
     fn _check_token_exists(&self, id: &Id) -> Result<AccountId, PSP34Error>;
 
+    /// Calls `PSP34Receiver::on_received` on `to` if it is a contract.
+    /// Fails if the receiver doesn't acknowledge the transfer.
+    fn _do_safe_transfer_check(
+        &mut self,
+        operator: &AccountId,
+        from: &AccountId,
+        to: &AccountId,
+        id: &Id,
+        data: &[u8],
+    ) -> Result<(), PSP34Error>;
+
     fn _before_token_transfer(
         &mut self,
         from: Option<&AccountId>,
@@ -167,7 +230,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         BalancesManager::_owner_of(self, id)
     }
 
-    fn _transfer_token(&mut self, to: AccountId, id: Id, _data: Vec<u8>) -> Result<(), PSP34Error> {
+    fn _transfer_token(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
         let owner = Internal::_check_token_exists(self, &id)?;
         let caller = Self::env().caller();
 
@@ -177,12 +240,13 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
 
         Internal::_before_token_transfer(self, Some(&owner), Some(&to), &id)?;
 
-        self._remove_operator_approvals(&owner, &caller, &Some(&id));
-        BalancesManager::_decrease_balance(self, &owner, &id, false);
+        self._clear_token_approvals(&owner, &id);
+        BalancesManager::_decrease_balance(self, &owner, &id, false)?;
         self._remove_token_owner(&id);
 
-        BalancesManager::_increase_balance(self, &to, &id, false);
+        BalancesManager::_increase_balance(self, &to, &id, false)?;
         self._insert_token_owner(&id, &to);
+        Internal::_do_safe_transfer_check(self, &caller, &owner, &to, &id, &data)?;
         Internal::_after_token_transfer(self, Some(&owner), Some(&to), &id)?;
         Internal::_emit_transfer_event(self, Some(owner), Some(to), id);
 
@@ -195,7 +259,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         }
         Internal::_before_token_transfer(self, None, Some(&to), &id)?;
 
-        BalancesManager::_increase_balance(self, &to, &id, true);
+        BalancesManager::_increase_balance(self, &to, &id, true)?;
         self._insert_token_owner(&id, &to);
         Internal::_after_token_transfer(self, None, Some(&to), &id)?;
         Internal::_emit_transfer_event(self, None, Some(to), id);
@@ -208,8 +272,9 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
 
         Internal::_before_token_transfer(self, Some(&from), None, &id)?;
 
+        BalancesManager::_decrease_balance(self, &from, &id, true)?;
+        self._clear_token_approvals(&from, &id);
         self._remove_token_owner(&id);
-        BalancesManager::_decrease_balance(self, &from, &id, true);
         Internal::_after_token_transfer(self, Some(&from), None, &id)?;
         Internal::_emit_transfer_event(self, Some(from), None, id);
         Ok(())
@@ -224,6 +289,31 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         Internal::_owner_of(self, id).ok_or(PSP34Error::TokenNotExists)
     }
 
+    fn _do_safe_transfer_check(
+        &mut self,
+        operator: &AccountId,
+        from: &AccountId,
+        to: &AccountId,
+        id: &Id,
+        data: &[u8],
+    ) -> Result<(), PSP34Error> {
+        if !Self::env().is_contract(to) {
+            return Ok(())
+        }
+
+        let builder = PSP34ReceiverRef::on_received_builder(to, *operator, *from, id.clone(), data.to_vec())
+            .call_flags(CallFlags::default().set_allow_reentry(true));
+        match builder.try_invoke() {
+            Ok(Ok(Ok(selector))) if selector == ON_PSP34_RECEIVED => Ok(()),
+            Ok(Ok(Err(error))) => Err(error.into()),
+            _ => {
+                Err(PSP34Error::SafeTransferCheckFailed(String::from(
+                    "Receiver didn't acknowledge the transfer",
+                )))
+            }
+        }
+    }
+
     fn _before_token_transfer(
         &mut self,
         _from: Option<&AccountId>,
@@ -246,9 +336,11 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
 pub trait BalancesManager {
     fn _balance_of(&self, owner: &Owner) -> u32;
 
-    fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool);
+    /// Returns an error if the balances manager doesn't accept `id`, nothing is changed in that case.
+    fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) -> Result<(), PSP34Error>;
 
-    fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool);
+    /// Returns an error if the balances manager doesn't accept `id`, nothing is changed in that case.
+    fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) -> Result<(), PSP34Error>;
 
     fn _total_supply(&self) -> u128;
 
@@ -260,6 +352,10 @@ pub trait BalancesManager {
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>);
 
+    /// Removes the approvals of all operators for the single token `id` of `owner`.
+    /// The approvals for all tokens of `owner` are kept.
+    fn _clear_token_approvals(&mut self, owner: &Owner, id: &Id);
+
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId);
 
     fn _remove_token_owner(&mut self, id: &Id);
@@ -270,16 +366,17 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
         self.data().owned_tokens_count.get(owner).unwrap_or(0)
     }
 
-    fn _increase_balance(&mut self, owner: &Owner, _id: &Id, increase_supply: bool) {
+    fn _increase_balance(&mut self, owner: &Owner, _id: &Id, increase_supply: bool) -> Result<(), PSP34Error> {
         let to_balance = self.data().owned_tokens_count.get(owner).unwrap_or(0);
         self.data().owned_tokens_count.insert(owner, &(to_balance + 1));
         if increase_supply {
             let new_supply = self.data().total_supply.get_or_default() + 1;
             self.data().total_supply.set(&new_supply);
         }
+        Ok(())
     }
 
-    fn _decrease_balance(&mut self, owner: &Owner, _id: &Id, decrease_supply: bool) {
+    fn _decrease_balance(&mut self, owner: &Owner, _id: &Id, decrease_supply: bool) -> Result<(), PSP34Error> {
         let from_balance = self.data().owned_tokens_count.get(owner).unwrap_or(0);
         self.data()
             .owned_tokens_count
@@ -289,6 +386,7 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
             let new_supply = self.data().total_supply.get_or_default() - 1;
             self.data().total_supply.set(&new_supply);
         }
+        Ok(())
     }
 
     fn _total_supply(&self) -> u128 {
@@ -305,10 +403,33 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
 
     fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
         self.data().operator_approvals.insert(&(owner, operator, id), &());
+        if let Some(id) = id {
+            let mut operators = self.data().token_approvals.get(*id).unwrap_or_default();
+            if !operators.contains(operator) {
+                operators.push(*operator);
+                self.data().token_approvals.insert(*id, &operators);
+            }
+        }
     }
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
         self.data().operator_approvals.remove(&(owner, operator, id));
+        if let Some(id) = id {
+            let mut operators = self.data().token_approvals.get(*id).unwrap_or_default();
+            operators.retain(|approved| approved != operator);
+            if operators.is_empty() {
+                self.data().token_approvals.remove(*id);
+            } else {
+                self.data().token_approvals.insert(*id, &operators);
+            }
+        }
+    }
+
+    fn _clear_token_approvals(&mut self, owner: &Owner, id: &Id) {
+        for operator in self.data().token_approvals.get(id).unwrap_or_default() {
+            self.data().operator_approvals.remove(&(owner, &operator, &Some(id)));
+        }
+        self.data().token_approvals.remove(id);
     }
 
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
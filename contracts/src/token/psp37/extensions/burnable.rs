@@ -30,6 +30,7 @@ use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
+    DefaultEnv,
 };
 pub use psp37::{
     BalancesManager as _,
@@ -39,8 +40,23 @@ pub use psp37::{
     PSP37Impl,
 };
 
-pub trait PSP37BurnableImpl: psp37::Internal {
+pub trait PSP37BurnableImpl: psp37::Internal + psp37::BalancesManager + Sized {
     fn burn(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
-        self._burn_from(from, ids_amounts)
+        let operator = Self::env().caller();
+
+        for (id, amount) in &ids_amounts {
+            if from != operator && psp37::Internal::_get_allowance(self, &from, &operator, &Some(id)) < *amount {
+                return Err(PSP37Error::NotAllowed)
+            }
+            if self._balance_of(&from, &Some(id)) < *amount {
+                return Err(PSP37Error::InsufficientBalance)
+            }
+        }
+
+        for (id, amount) in &ids_amounts {
+            psp37::Internal::_decrease_allowance(self, &from, &operator, id, *amount)?;
+        }
+
+        psp37::Internal::_burn_from(self, from, ids_amounts)
     }
 }
@@ -0,0 +1,78 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp37,
+    psp37::extensions::metadata_named,
+    traits::psp37::{
+        extensions::metadata_named::*,
+        *,
+    },
+};
+pub use metadata_named::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        Storage,
+        String,
+    },
+};
+pub use psp37::{
+    BalancesManager as _,
+    BalancesManagerImpl as _,
+    Internal as _,
+    InternalImpl as _,
+    PSP37Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub names: Mapping<Id, String>,
+    pub symbols: Mapping<Id, String>,
+}
+
+pub trait PSP37MetadataNamedImpl: Storage<Data> {
+    fn token_name(&self, id: Id) -> Option<String> {
+        self.data().names.get(&id)
+    }
+
+    fn token_symbol(&self, id: Id) -> Option<String> {
+        self.data().symbols.get(&id)
+    }
+}
+
+pub trait Internal {
+    fn _set_token_name(&mut self, id: &Id, name: &String);
+
+    fn _set_token_symbol(&mut self, id: &Id, symbol: &String);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _set_token_name(&mut self, id: &Id, name: &String) {
+        self.data().names.insert(id, name);
+    }
+
+    fn _set_token_symbol(&mut self, id: &Id, symbol: &String) {
+        self.data().symbols.insert(id, symbol);
+    }
+}
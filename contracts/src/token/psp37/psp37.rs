@@ -31,6 +31,7 @@ use ink::prelude::{
 use openbrush::{
     storage::{
         Mapping,
+        MultiMapping,
         TypeGuard,
     },
     traits::{
@@ -38,6 +39,7 @@ use openbrush::{
         Balance,
         DefaultEnv,
         Storage,
+        String,
     },
 };
 pub use psp37::{
@@ -53,6 +55,7 @@ pub struct Data {
     pub balances: Mapping<(AccountId, Option<Id>), Balance, BalancesKey>,
     pub supply: Mapping<Option<Id>, Balance, SupplyKey>,
     pub operator_approvals: Mapping<(AccountId, AccountId, Option<Id>), Balance, ApprovalsKey>,
+    pub operators: MultiMapping<AccountId, AccountId>,
 }
 
 pub struct BalancesKey;
@@ -78,6 +81,17 @@ pub trait PSP37Impl: Internal + BalancesManager + Sized {
         self._balance_of(&owner, &id.as_ref())
     }
 
+    fn balance_of_batch(&self, accounts_ids: Vec<(AccountId, Id)>) -> Result<Vec<Balance>, PSP37Error> {
+        if accounts_ids.len() > MAX_BALANCE_OF_BATCH_LEN {
+            return Err(PSP37Error::Custom(String::from("BatchTooLarge")))
+        }
+
+        Ok(accounts_ids
+            .iter()
+            .map(|(owner, id)| self._balance_of(owner, &Some(id)))
+            .collect())
+    }
+
     fn total_supply(&self, id: Option<Id>) -> Balance {
         self._total_supply(&id.as_ref())
     }
@@ -93,6 +107,10 @@ pub trait PSP37Impl: Internal + BalancesManager + Sized {
         self._approve_for(operator, id, value)
     }
 
+    fn revoke_all_operators(&mut self) -> Result<(), PSP37Error> {
+        self._revoke_all_operators()
+    }
+
     fn transfer(&mut self, to: AccountId, id: Id, value: Balance, data: Vec<u8>) -> Result<(), PSP37Error> {
         self._transfer_from(Self::env().caller(), to, id, value, data)
     }
@@ -122,6 +140,10 @@ pub trait Internal {
 
     fn _emit_approval_event(&self, _owner: AccountId, _operator: AccountId, _id: Option<Id>, value: Balance);
 
+    /// Emitted along with the approval event when `owner` approves or revokes
+    /// `operator` for all of its tokens.
+    fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool);
+
     /// Creates `amount` tokens of token type `id` to `to`.
     ///
     /// On success a `TransferSingle` event is emitted if length of `ids_amounts` is 1, otherwise `TransferBatch` event.
@@ -155,6 +177,9 @@ pub trait Internal {
 
     fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error>;
 
+    /// Revokes every operator approved by the caller for all of its tokens.
+    fn _revoke_all_operators(&mut self) -> Result<(), PSP37Error>;
+
     fn _decrease_allowance(
         &mut self,
         owner: &AccountId,
@@ -187,7 +212,7 @@ pub trait Internal {
     ) -> Result<(), PSP37Error>;
 }
 
-pub trait InternalImpl: Internal + BalancesManager + Sized {
+pub trait InternalImpl: Internal + BalancesManager + Storage<Data> + Sized {
     fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _id: Id, _amount: Balance) {}
 
     fn _emit_transfer_batch_event(
@@ -200,6 +225,8 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
 
     fn _emit_approval_event(&self, _owner: AccountId, _operator: AccountId, _id: Option<Id>, _value: Balance) {}
 
+    fn _emit_approval_for_all_event(&self, _owner: AccountId, _operator: AccountId, _approved: bool) {}
+
     fn _mint_to(&mut self, to: AccountId, mut ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
         if ids_amounts.is_empty() {
             return Ok(())
@@ -291,11 +318,30 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             }
         } else if value == 0 {
             self._remove_operator_approvals(&caller, &operator, &None);
+            self.data().operators.remove_value(&caller, &operator);
         } else {
             self._insert_operator_approvals(&caller, &operator, &None, &Balance::MAX);
+            self.data().operators.insert(&caller, &operator);
         }
 
+        let for_all = id.is_none();
         Internal::_emit_approval_event(self, caller, operator, id, value);
+        if for_all {
+            Internal::_emit_approval_for_all_event(self, caller, operator, value != 0);
+        }
+
+        Ok(())
+    }
+
+    fn _revoke_all_operators(&mut self) -> Result<(), PSP37Error> {
+        let caller = Self::env().caller();
+        let operators: Vec<AccountId> = (0..self.data().operators.count(&caller))
+            .filter_map(|index| self.data().operators.get_value(&caller, &index))
+            .collect();
+
+        for operator in operators {
+            Internal::_approve_for(self, operator, None, 0)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,83 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::access_control::*;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type AccessControlTimelockedGrantRef = dyn AccessControlTimelockedGrant;
+
+/// Extension of [`AccessControl`] that allows granting a role only after a delay,
+/// giving everyone time to react to the upcoming grant.
+///
+/// While the delay is set, `AccessControl::grant_role` can only execute a scheduled grant that is ready,
+/// like `execute_grant`.
+#[openbrush::trait_definition]
+pub trait AccessControlTimelockedGrant {
+    /// Returns the delay between scheduling a grant and being able to execute it.
+    #[ink(message)]
+    fn grant_delay(&self) -> Timestamp;
+
+    /// Returns the timestamp from which the grant of `role` to `account` can be executed,
+    /// or `None` if the grant is not scheduled.
+    #[ink(message)]
+    fn grant_ready_at(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp>;
+
+    /// Schedules the grant of `role` to `account`.
+    /// Scheduling it again restarts the delay.
+    ///
+    /// On success a `GrantScheduled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant the role.
+    /// Returns with `RoleRedundant` error if `account` already has `role`.
+    #[ink(message)]
+    fn schedule_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError>;
+
+    /// Grants `role` to `account` once the scheduled delay has passed.
+    ///
+    /// On success a `RoleGranted` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant the role.
+    /// Returns with `GrantNotScheduled` error if the grant was not scheduled.
+    /// Returns with `GrantNotReady` error if the delay has not passed yet.
+    /// Returns with `RoleRedundant` error if `account` already has `role`.
+    #[ink(message)]
+    fn execute_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError>;
+
+    /// Cancels the scheduled grant of `role` to `account`.
+    ///
+    /// On success a `GrantCancelled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant the role.
+    /// Returns with `GrantNotScheduled` error if the grant was not scheduled.
+    #[ink(message)]
+    fn cancel_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError>;
+}
@@ -25,4 +25,5 @@ pub use access_control::*;
 
 pub mod extensions {
     pub mod enumerable;
+    pub mod timelocked_grant;
 }
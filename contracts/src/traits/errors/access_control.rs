@@ -27,4 +27,8 @@ pub enum AccessControlError {
     InvalidCaller,
     MissingRole,
     RoleRedundant,
+    /// Returned if the grant was not scheduled.
+    GrantNotScheduled,
+    /// Returned if the grant delay has not passed yet.
+    GrantNotReady,
 }
@@ -56,6 +56,12 @@ impl From<AccessControlError> for FlashBorrowerError {
             AccessControlError::InvalidCaller => {
                 FlashBorrowerError::FlashloanRejected(String::from("AC::InvalidCaller"))
             }
+            AccessControlError::GrantNotScheduled => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::GrantNotScheduled"))
+            }
+            AccessControlError::GrantNotReady => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::GrantNotReady"))
+            }
         }
     }
 }
@@ -142,6 +148,8 @@ impl From<AccessControlError> for FlashLenderError {
             AccessControlError::MissingRole => FlashLenderError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => FlashLenderError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => FlashLenderError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::GrantNotScheduled => FlashLenderError::Custom(String::from("AC::GrantNotScheduled")),
+            AccessControlError::GrantNotReady => FlashLenderError::Custom(String::from("AC::GrantNotReady")),
         }
     }
 }
@@ -20,7 +20,10 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use crate::traits::errors::NoncesError;
+use crate::traits::errors::{
+    NoncesError,
+    TimelockControllerError,
+};
 use openbrush::utils::{
     checkpoints::CheckpointsError,
     crypto::CryptoError,
@@ -39,7 +42,7 @@ pub enum GovernanceError {
     NonexistentProposal,
     UnexpectedProposalState,
     InvalidVotingPeriod,
-    InsufficientProposerVotes,
+    ProposalThresholdNotMet,
     InvalidVoteType,
     InvalidSignature,
     ProposerRestricted,
@@ -60,6 +63,8 @@ pub enum GovernanceError {
     CheckpointsError(CheckpointsError),
     IndexOutOfRange,
     Overflow,
+    TimelockNotSet,
+    TimelockControllerError(TimelockControllerError),
 }
 
 impl From<CryptoError> for GovernanceError {
@@ -79,3 +84,9 @@ impl From<CheckpointsError> for GovernanceError {
         GovernanceError::CheckpointsError(err)
     }
 }
+
+impl From<TimelockControllerError> for GovernanceError {
+    fn from(err: TimelockControllerError) -> Self {
+        GovernanceError::TimelockControllerError(err)
+    }
+}
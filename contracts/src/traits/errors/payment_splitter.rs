@@ -47,6 +47,10 @@ impl From<AccessControlError> for PaymentSplitterError {
             AccessControlError::MissingRole => PaymentSplitterError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PaymentSplitterError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PaymentSplitterError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::GrantNotScheduled => {
+                PaymentSplitterError::Custom(String::from("AC::GrantNotScheduled"))
+            }
+            AccessControlError::GrantNotReady => PaymentSplitterError::Custom(String::from("AC::GrantNotReady")),
         }
     }
 }
@@ -67,6 +67,8 @@ impl From<AccessControlError> for PSP22Error {
             AccessControlError::MissingRole => PSP22Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP22Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP22Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::GrantNotScheduled => PSP22Error::Custom(String::from("AC::GrantNotScheduled")),
+            AccessControlError::GrantNotReady => PSP22Error::Custom(String::from("AC::GrantNotReady")),
         }
     }
 }
@@ -88,6 +90,15 @@ impl From<ReentrancyGuardError> for PSP22Error {
     }
 }
 
+impl From<ink::LangError> for PSP22Error {
+    fn from(error: ink::LangError) -> Self {
+        match error {
+            ink::LangError::CouldNotReadInput => PSP22Error::Custom(String::from("LE::CouldNotReadInput")),
+            _ => PSP22Error::Custom(String::from("LE::Unknown")),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PSP22ReceiverError {
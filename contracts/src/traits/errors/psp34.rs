@@ -43,6 +43,8 @@ pub enum PSP34Error {
     TokenNotExists,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the account isn't the owner of the token.
+    NotOwner,
 }
 
 impl From<OwnableError> for PSP34Error {
@@ -60,6 +62,8 @@ impl From<AccessControlError> for PSP34Error {
             AccessControlError::MissingRole => PSP34Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP34Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP34Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::GrantNotScheduled => PSP34Error::Custom(String::from("AC::GrantNotScheduled")),
+            AccessControlError::GrantNotReady => PSP34Error::Custom(String::from("AC::GrantNotReady")),
         }
     }
 }
@@ -81,6 +85,15 @@ impl From<ReentrancyGuardError> for PSP34Error {
     }
 }
 
+impl From<ink::LangError> for PSP34Error {
+    fn from(error: ink::LangError) -> Self {
+        match error {
+            ink::LangError::CouldNotReadInput => PSP34Error::Custom(String::from("LE::CouldNotReadInput")),
+            _ => PSP34Error::Custom(String::from("LE::Unknown")),
+        }
+    }
+}
+
 /// The PSP34Receiver error types.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -62,6 +62,8 @@ impl From<AccessControlError> for PSP37Error {
             AccessControlError::MissingRole => PSP37Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP37Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP37Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::GrantNotScheduled => PSP37Error::Custom(String::from("AC::GrantNotScheduled")),
+            AccessControlError::GrantNotReady => PSP37Error::Custom(String::from("AC::GrantNotReady")),
         }
     }
 }
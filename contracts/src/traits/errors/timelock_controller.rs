@@ -55,6 +55,12 @@ impl From<AccessControlError> for TimelockControllerError {
             AccessControlError::InvalidCaller => {
                 TimelockControllerError::AccessControlError(AccessControlError::InvalidCaller)
             }
+            AccessControlError::GrantNotScheduled => {
+                TimelockControllerError::AccessControlError(AccessControlError::GrantNotScheduled)
+            }
+            AccessControlError::GrantNotReady => {
+                TimelockControllerError::AccessControlError(AccessControlError::GrantNotReady)
+            }
         }
     }
 }
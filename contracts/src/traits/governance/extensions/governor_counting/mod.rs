@@ -41,6 +41,11 @@ pub trait GovernorCounting {
     /// proposal, and `abstain` is the total number of abstained votes.
     #[ink(message)]
     fn proposal_votes(&self, proposal_id: ProposalId) -> Result<ProposalVote, GovernanceError>;
+
+    /// Returns `true` if the abstain votes count toward the quorum, `false` otherwise.
+    /// If they don't, the quorum is reached by `for` and `against` votes.
+    #[ink(message)]
+    fn abstain_counts_toward_quorum(&self) -> bool;
 }
 
 #[openbrush::wrapper]
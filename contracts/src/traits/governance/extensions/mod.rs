@@ -23,4 +23,5 @@
 pub mod governor_counting;
 pub mod governor_quorum;
 pub mod governor_settings;
+pub mod governor_timelock;
 pub mod timelock_controller;
@@ -88,6 +88,8 @@ pub trait Governor {
 
     /// Executes a proposal if it is in the `Succeeded` state.
     /// Returns the id of the executed proposal
+    ///
+    /// If the governor has a timelock, the proposal must be queued and executed after the timelock delay.
     #[ink(message)]
     fn execute(
         &mut self,
@@ -95,8 +97,12 @@ pub trait Governor {
         description_hash: HashType,
     ) -> Result<ProposalId, GovernanceError>;
 
-    /// Cancels a proposal if it is in the `Pending` state.
+    /// Cancels a proposal.
     /// Returns the id of the cancelled proposal
+    ///
+    /// The proposer can cancel the proposal while it is in the `Pending` state, or until it is executed
+    /// if their votes fell below `proposal_threshold`.
+    /// The guardian can cancel the proposal in any state until it is executed.
     #[ink(message)]
     fn cancel(
         &mut self,
@@ -104,6 +110,10 @@ pub trait Governor {
         description_hash: HashType,
     ) -> Result<ProposalId, GovernanceError>;
 
+    /// Returns the guardian that can cancel any proposal before it is executed, if any.
+    #[ink(message)]
+    fn guardian(&self) -> Option<AccountId>;
+
     /// Casts a vote for a proposal from a message sender.
     /// Returns the number of votes already casted for the proposal by the sender
     #[ink(message)]
@@ -115,6 +125,27 @@ pub trait Governor {
         params: Option<Vec<u8>>,
     ) -> Result<Balance, GovernanceError>;
 
+    /// Casts a vote with a reason for a proposal from a message sender. The reason is emitted in the `VoteCast` event.
+    /// Returns the number of votes already casted for the proposal by the sender
+    #[ink(message)]
+    fn cast_vote_with_reason(
+        &mut self,
+        proposal_id: ProposalId,
+        support: VoteType,
+        reason: String,
+    ) -> Result<Balance, GovernanceError>;
+
+    /// Casts a vote for a proposal on behalf of the account that signed
+    /// `(governor_account_id, proposal_id, support)`.
+    /// Returns the number of votes already casted for the proposal by the signer
+    #[ink(message)]
+    fn cast_vote_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: VoteType,
+        signature: Signature,
+    ) -> Result<Balance, GovernanceError>;
+
     /// Casts a vote with signature for a proposal from a message sender. Returns the number of votes already casted for the proposal by the sender
     #[ink(message)]
     fn cast_vote_with_signature(
@@ -36,6 +36,7 @@ pub mod extensions {
     pub mod governor_counting;
     pub mod governor_quorum;
     pub mod governor_settings;
+    pub mod governor_timelock;
     pub mod timelock_controller;
 }
 
@@ -25,6 +25,7 @@ pub mod diamond;
 pub mod errors;
 pub mod flashloan;
 pub mod governance;
+pub mod multicall;
 pub mod nonces;
 pub mod ownable;
 pub mod pausable;
@@ -0,0 +1,43 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use ink::prelude::vec::Vec;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type MulticallRef = dyn Multicall;
+
+/// Aggregates several read-only calls to other contracts into one call.
+#[openbrush::trait_definition]
+pub trait Multicall {
+    /// Performs each call from `calls` and returns its raw result.
+    ///
+    /// Every call is a pair of the callee and the encoded input (the selector of the message
+    /// followed by its encoded arguments). Only the messages allowed by the contract can be
+    /// called, any other call maps to `Err(())` without being performed.
+    ///
+    /// The result of a successful call is the encoded return value of the message. A message
+    /// returning `Err` is reverted, but its encoded `Err` is still returned, so it can be decoded.
+    /// A trapped call or an unknown selector maps to `Err(())` and doesn't affect the other calls.
+    #[ink(message)]
+    fn multicall(&self, calls: Vec<(AccountId, Vec<u8>)>) -> Vec<Result<Vec<u8>, ()>>;
+}
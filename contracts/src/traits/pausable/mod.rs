@@ -21,6 +21,16 @@
 
 pub use crate::traits::errors::PausableError;
 
+/// Identifier of the functionality which can be paused independently of the rest of the contract.
+pub type PauseScope = u32;
+
+/// Scope of the transfers between accounts.
+pub const TRANSFER_SCOPE: PauseScope = ink::selector_id!("TRANSFER_SCOPE");
+/// Scope of the minting of new tokens.
+pub const MINT_SCOPE: PauseScope = ink::selector_id!("MINT_SCOPE");
+/// Scope of the burning of tokens.
+pub const BURN_SCOPE: PauseScope = ink::selector_id!("BURN_SCOPE");
+
 #[openbrush::wrapper]
 pub type PausableRef = dyn Pausable;
 
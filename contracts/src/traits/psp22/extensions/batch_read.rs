@@ -0,0 +1,56 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows to read balances and allowances of many accounts in one call.
+pub use crate::traits::errors::PSP22Error;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Maximum number of entries queried by `PSP22BatchRead` in one call.
+pub const MAX_BATCH_READ_LEN: usize = 100;
+
+#[openbrush::wrapper]
+pub type PSP22BatchReadRef = dyn PSP22BatchRead;
+
+#[openbrush::trait_definition]
+pub trait PSP22BatchRead {
+    /// Returns the balances of `accounts`.
+    ///
+    /// The result is aligned with `accounts`.
+    ///
+    /// On error returns `PSP22Error::Custom("BatchTooLarge")` if there are more than
+    /// [`MAX_BATCH_READ_LEN`] accounts.
+    #[ink(message)]
+    fn balances_of(&self, accounts: Vec<AccountId>) -> Result<Vec<Balance>, PSP22Error>;
+
+    /// Returns the allowances of `(owner, spender)` pairs.
+    ///
+    /// The result is aligned with `pairs`.
+    ///
+    /// On error returns `PSP22Error::Custom("BatchTooLarge")` if there are more than
+    /// [`MAX_BATCH_READ_LEN`] pairs.
+    #[ink(message)]
+    fn allowances_of(&self, pairs: Vec<(AccountId, AccountId)>) -> Result<Vec<Balance>, PSP22Error>;
+}
@@ -25,6 +25,7 @@ pub use crate::traits::errors::PSP22Error;
 use openbrush::traits::{
     AccountId,
     Balance,
+    String,
 };
 
 #[openbrush::wrapper]
@@ -38,4 +39,28 @@ pub trait PSP22Burnable {
     /// See [`PSP22::_burn_from`].
     #[ink(message)]
     fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// Destroys `amount` tokens of the caller and emits a `BurnWithMemo` event
+    /// with `memo` in addition to the `Transfer` event.
+    ///
+    /// See [`PSP22::_burn_from`].
+    #[ink(message)]
+    fn burn_with_memo(&mut self, amount: Balance, memo: String) -> Result<(), PSP22Error>;
+
+    /// Allows `burner` to burn up to `amount` of the caller's tokens with `burn_from_approved`.
+    ///
+    /// The burn allowance is separate from the transfer allowance, so a spender approved
+    /// with `PSP22::approve` can't burn the caller's tokens.
+    #[ink(message)]
+    fn approve_burn(&mut self, burner: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// Returns the amount of `owner`'s tokens that `burner` is allowed to burn.
+    #[ink(message)]
+    fn burn_allowance(&self, owner: AccountId, burner: AccountId) -> Balance;
+
+    /// Destroys `amount` tokens from `account`, deducting from the caller's burn allowance.
+    ///
+    /// On error returns `InsufficientAllowance` if the caller's burn allowance is lower than `amount`.
+    #[ink(message)]
+    fn burn_from_approved(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
 }
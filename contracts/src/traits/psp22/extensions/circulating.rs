@@ -0,0 +1,43 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22CirculatingRef = dyn PSP22Circulating;
+
+/// Extension of [`PSP22`] that reports the supply that is not held by the excluded accounts,
+/// like a treasury or a vesting contract.
+#[openbrush::trait_definition]
+pub trait PSP22Circulating {
+    /// Returns the total supply minus the balances of the excluded accounts.
+    #[ink(message)]
+    fn circulating_supply(&self) -> Balance;
+
+    /// Returns `true` if the balance of `account` is excluded from the circulating supply.
+    #[ink(message)]
+    fn is_excluded(&self, account: AccountId) -> bool;
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type PSP22DeflationaryRef = dyn PSP22Deflationary;
+
+/// Extension of [`PSP22`] that burns a fraction of each transfer.
+#[openbrush::trait_definition]
+pub trait PSP22Deflationary {
+    /// Returns the share of each transfer that is burned while no pair is set, in basis points.
+    #[ink(message)]
+    fn burn_bps(&self) -> u16;
+
+    /// Returns the share of each transfer from a pair that is burned, in basis points.
+    #[ink(message)]
+    fn buy_fee_bps(&self) -> u16;
+
+    /// Returns the share of each transfer to a pair that is burned, in basis points.
+    #[ink(message)]
+    fn sell_fee_bps(&self) -> u16;
+
+    /// Returns `true` if transfers from and to `account` are charged the buy and sell fees.
+    #[ink(message)]
+    fn is_pair(&self, account: AccountId) -> bool;
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that mints tokens with a constant rate per second.
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type PSP22InflationRef = dyn PSP22Inflation;
+
+#[openbrush::trait_definition]
+pub trait PSP22Inflation {
+    /// Returns the amount of tokens that becomes mintable every second.
+    #[ink(message)]
+    fn rate_per_second(&self) -> Balance;
+
+    /// Returns the timestamp up to which the inflation is already minted.
+    #[ink(message)]
+    fn last_mint_time(&self) -> Timestamp;
+
+    /// Mints `rate_per_second` tokens for every second elapsed since the last mint to `to`.
+    ///
+    /// On error returns `PSP22Error::Custom("NoTimeElapsed")` if less than a second
+    /// has passed since the last mint.
+    ///
+    /// See [`PSP22::_mint_to`].
+    #[ink(message)]
+    fn mint_inflation(&mut self, to: AccountId) -> Result<(), PSP22Error>;
+}
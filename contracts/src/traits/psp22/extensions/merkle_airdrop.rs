@@ -0,0 +1,55 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PSP22Error;
+use ink::prelude::vec::Vec;
+use openbrush::traits::Balance;
+
+#[openbrush::wrapper]
+pub type PSP22MerkleAirdropRef = dyn PSP22MerkleAirdrop;
+
+/// Extension of [`PSP22`] that allows accounts from the merkle tree to claim their airdrop.
+///
+/// The leaf of the tree is `keccak256(scale::Encode((index, account, amount)))`,
+/// the nodes are `keccak256` of the sorted pair of their children.
+#[openbrush::trait_definition]
+pub trait PSP22MerkleAirdrop {
+    /// Returns the merkle root of the airdrop.
+    #[ink(message)]
+    fn merkle_root(&self) -> [u8; 32];
+
+    /// Returns `true` if the airdrop at `index` is already claimed.
+    #[ink(message)]
+    fn is_claimed(&self, index: u32) -> bool;
+
+    /// Mints `amount` tokens to the caller if `(index, caller, amount)` is a leaf of the merkle tree.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AlreadyClaimed")` error if the airdrop at `index` is already claimed.
+    ///
+    /// Returns `Custom("InvalidProof")` error if the `proof` doesn't match the merkle root.
+    #[ink(message)]
+    fn claim(&mut self, index: u32, amount: Balance, proof: Vec<[u8; 32]>) -> Result<(), PSP22Error>;
+}
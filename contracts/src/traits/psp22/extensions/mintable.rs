@@ -21,12 +21,21 @@
 
 /// Extension of [`PSP22`] that allows create `amount` tokens
 /// and assigns them to `account`, increasing the total supply
-pub use crate::traits::errors::PSP22Error;
+pub use crate::traits::{
+    access_control::RoleType,
+    errors::PSP22Error,
+};
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
 };
 
+/// Role that is expected to be allowed to call [`PSP22Mintable::mint_to_many`] and [`PSP22Mintable::finish_minting`].
+///
+/// The default implementation doesn't know about roles, the contract grants it by overriding `_check_minter`.
+pub const MINTER: RoleType = ink::selector_id!("MINTER");
+
 #[openbrush::wrapper]
 pub type PSP22MintableRef = dyn PSP22Mintable;
 
@@ -35,6 +44,37 @@ pub trait PSP22Mintable {
     /// Minting `amount` tokens to the account.
     ///
     /// See [`PSP22::_mint_to`].
+    ///
+    /// Returns `Custom("MintingFinished")` error if minting is finished.
     #[ink(message)]
     fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// Minting tokens to many accounts at once, `recipients` is a list of `(account, amount)`.
+    ///
+    /// The total amount is checked for overflow and passed to `_before_mint_to_many`
+    /// before anything is minted, so the call either mints to all recipients or to none.
+    /// A `Transfer` event from zero is emitted per recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("NotMinter")` error if the caller isn't allowed to mint,
+    /// nobody is allowed until the contract overrides `_check_minter`.
+    ///
+    /// Returns `Custom("Overflow")` error if the total amount overflows.
+    ///
+    /// Returns `Custom("MintingFinished")` error if minting is finished.
+    #[ink(message)]
+    fn mint_to_many(&mut self, recipients: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error>;
+
+    /// Disables minting permanently, there is no way to enable it back.
+    /// After that, `mint` and `mint_to_many` return `Custom("MintingFinished")` error.
+    ///
+    /// Returns `Custom("NotMinter")` error if the caller isn't allowed to finish minting,
+    /// nobody is allowed until the contract overrides `_check_minter`.
+    #[ink(message)]
+    fn finish_minting(&mut self) -> Result<(), PSP22Error>;
+
+    /// Returns `true` if minting is finished.
+    #[ink(message)]
+    fn minting_finished(&self) -> bool;
 }
@@ -22,12 +22,24 @@
 /// Extension of [`PSP22`] that allows create `amount` tokens
 /// and assigns them to `account`, increasing the total supply
 pub use crate::traits::errors::PSP22Error;
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
 };
 pub use openbrush::utils::crypto::Signature;
 
+/// Arguments of a single permit applied by [`PSP22Permit::permit_batch`].
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PermitArgs {
+    pub owner: AccountId,
+    pub spender: AccountId,
+    pub value: Balance,
+    pub deadline: u64,
+    pub signature: Signature,
+}
+
 #[openbrush::wrapper]
 pub type PSP22PermitRef = dyn PSP22Permit;
 
@@ -48,4 +60,33 @@ pub trait PSP22Permit {
 
     #[ink(message)]
     fn domain_separator(&mut self) -> [u8; 32];
+
+    /// Applies the permit of `owner` for the caller and transfers `value` tokens
+    /// from `owner` to `to`.
+    ///
+    /// The permit is signed for the caller as `spender` with `permitted` amount.
+    /// Both steps are executed in one call, so if any of them fails the whole call fails.
+    ///
+    /// See [`PSP22Permit::permit`] and [`PSP22::transfer_from`].
+    #[ink(message)]
+    fn permit_transfer_from(
+        &mut self,
+        owner: AccountId,
+        permitted: Balance,
+        deadline: u64,
+        signature: Signature,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Applies several permits in one call.
+    ///
+    /// Each permit is signed with its own nonce, so permits of the same `owner`
+    /// must be signed with consecutive nonces in the order they appear in `permits`.
+    /// If any permit is expired or has an invalid signature, the whole batch fails
+    /// and none of the permits is applied.
+    ///
+    /// See [`PSP22Permit::permit`].
+    #[ink(message)]
+    fn permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error>;
 }
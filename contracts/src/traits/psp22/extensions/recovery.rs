@@ -0,0 +1,61 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows the owner to recover tokens sent to the token contract
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP22RecoveryRef = dyn PSP22Recovery;
+
+#[openbrush::trait_definition]
+pub trait PSP22Recovery {
+    /// Transfers the whole balance of the token held by the token contract itself to `to`,
+    /// except the protected part.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("O::CallerIsNotOwner")` error if the caller is not the owner.
+    ///
+    /// See [`PSP22::_transfer_from_to`].
+    #[ink(message)]
+    fn recover_self(&mut self, to: AccountId) -> Result<(), PSP22Error>;
+
+    /// Transfers `amount` of the PSP22 `token` held by the contract to `to`.
+    /// `token` can be the contract itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("O::CallerIsNotOwner")` error if the caller is not the owner.
+    ///
+    /// Returns `Custom("ProtectedBalance")` error if the contract doesn't hold `amount`
+    /// on top of the protected balance of `token`.
+    ///
+    /// Returns the error of `token` if its transfer fails.
+    #[ink(message)]
+    fn rescue_token(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+}
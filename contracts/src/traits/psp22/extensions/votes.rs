@@ -24,7 +24,11 @@ use crate::traits::{
     errors::GovernanceError,
     governance::utils::votes::*,
 };
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    BlockNumber,
+};
 pub use openbrush::utils::checkpoints::Checkpoint;
 
 /// Extension of ERC20 to support Compound-like voting and delegation.
@@ -44,6 +48,13 @@ pub trait PSP22Votes {
     /// Get the `pos`-th checkpoint for `account`.
     #[ink(message)]
     fn checkpoints(&self, account: AccountId, pos: u32) -> Result<Checkpoint, GovernanceError>;
+
+    /// Get the balance of `account` at the end of `block`.
+    ///
+    /// Returns 0 if no balance was recorded for `account` up to `block`.
+    /// Balances are recorded by `PSP22VotesInternal::_checkpoint_balances`.
+    #[ink(message)]
+    fn balance_of_at_block(&self, account: AccountId, block: BlockNumber) -> Balance;
 }
 
 #[openbrush::wrapper]
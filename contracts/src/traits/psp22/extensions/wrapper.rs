@@ -30,6 +30,11 @@ use openbrush::traits::{
 
 /// The idea of PSP22Wrapper is that it is PSP22 by itself.
 /// Wrapper only adds 2 additional methods for depositing and withdrawing.
+///
+/// Both methods call the underlying token without allowing reentry, so a malicious underlying token
+/// can't call the wrapper back in the middle of a deposit or withdrawal. If `wrapper::Internal::_deposit`
+/// or `wrapper::Internal::_withdraw` is overridden to allow reentry, implement the wrapper with
+/// `#[openbrush::implementation(PSP22, PSP22Wrapper(reentrant_guard))]` to reject such calls.
 #[openbrush::wrapper]
 pub type PSP22WrapperRef = dyn PSP22Wrapper + PSP22;
 
@@ -25,14 +25,25 @@ mod psp22;
 pub use psp22::*;
 
 pub mod extensions {
+    pub mod batch_read;
     pub mod burnable;
     pub mod capped;
+    pub mod circulating;
+    pub mod cooldown;
+    pub mod deflationary;
+    pub mod inflation;
+    pub mod max_tx;
+    pub mod merkle_airdrop;
     pub mod metadata;
     pub mod mintable;
     pub mod permit;
+    #[cfg(feature = "ownable")]
+    pub mod recovery;
+    pub mod restricted;
     #[cfg(feature = "governance")]
     pub mod votes;
     pub mod wrapper;
+    pub mod zero_transfer;
 }
 
 pub mod utils {
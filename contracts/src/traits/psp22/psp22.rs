@@ -67,6 +67,20 @@ pub trait PSP22 {
     #[ink(message)]
     fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error>;
 
+    /// Transfers the whole balance of the caller to account `to`
+    /// with additional `data` in unspecified format.
+    ///
+    /// Returns the transferred amount, which is `0` if the caller has no tokens.
+    /// In that case nothing is transferred and no event is emitted.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `transfer`.
+    #[ink(message)]
+    fn transfer_all(&mut self, to: AccountId, data: Vec<u8>) -> Result<Balance, PSP22Error>;
+
     /// Transfers `value` tokens on the behalf of `from` to the account `to`
     /// with additional `data` in unspecified format.
     ///
@@ -75,6 +89,9 @@ pub trait PSP22 {
     ///
     /// On success a `Transfer` and `Approval` events are emitted.
     ///
+    /// An allowance of `Balance::MAX` is treated as infinite: it is not decreased
+    /// and no `Approval` event is emitted.
+    ///
     /// # Errors
     ///
     /// Returns `InsufficientAllowance` error if there are not enough tokens allowed
@@ -107,6 +124,9 @@ pub trait PSP22 {
     /// Returns `ZeroSenderAddress` error if sender's address is zero.
     ///
     /// Returns `ZeroRecipientAddress` error if recipient's address is zero.
+    ///
+    /// Returns `Custom("NonZeroToNonZero")` error if the strict approvals mode is enabled
+    /// and both the current allowance and `value` are non-zero.
     #[ink(message)]
     fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error>;
 
@@ -0,0 +1,39 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of `PSP34Metadata` that indexes the tokens by the values of their attributes.
+pub use crate::traits::psp34::Id;
+use ink::prelude::vec::Vec;
+use openbrush::traits::String;
+
+#[openbrush::wrapper]
+pub type PSP34AttributeIndexRef = dyn PSP34AttributeIndex;
+
+#[openbrush::trait_definition]
+pub trait PSP34AttributeIndex {
+    /// Returns up to `count` token ids that have the attribute `key` set to `value`,
+    /// starting from `start` index of the bucket.
+    ///
+    /// The start index is zero. The order of the ids in the bucket isn't preserved
+    /// when a token leaves the bucket.
+    #[ink(message)]
+    fn tokens_with_attribute(&self, key: String, value: String, start: u128, count: u128) -> Vec<Id>;
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::PSP34Error,
+    psp34::Id,
+};
+use openbrush::traits::AccountId;
+
+#[openbrush::wrapper]
+pub type PSP34AutoIdRef = dyn PSP34AutoId;
+
+/// Extension of [`PSP34`] that mints tokens with sequential `Id::U128` ids.
+#[openbrush::trait_definition]
+pub trait PSP34AutoId {
+    /// Returns the id that the next minted token receives.
+    #[ink(message)]
+    fn next_id(&self) -> u128;
+
+    /// Mints a new token with the next sequential id to `to` and returns its id.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("Overflow")` error if the id counter can't be incremented.
+    #[ink(message)]
+    fn mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error>;
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::PSP34Error,
+    psp34::Id,
+};
+
+#[openbrush::wrapper]
+pub type PSP34EvolveRef = dyn PSP34Evolve;
+
+#[openbrush::trait_definition]
+pub trait PSP34Evolve {
+    /// Burns the token with `from_id` and mints the token with `to_id` to the same owner.
+    ///
+    /// Caller must be the owner of `from_id` or be approved to transfer it.
+    ///
+    /// On success two `Transfer` events are emitted, one for the burn and one for the mint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `from_id` does not exist.
+    ///
+    /// Returns `NotApproved` error if caller is not allowed to manage `from_id`.
+    ///
+    /// Returns `TokenExists` error if `to_id` already exists.
+    #[ink(message)]
+    fn evolve(&mut self, from_id: Id, to_id: Id) -> Result<(), PSP34Error>;
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+/// Extension of [`PSP34`] that allows accounts with `LOCKER` role to lock tokens, e.g. while they are staked.
+/// A locked token stays with its owner, but it can't be transferred or burned.
+pub use crate::traits::{
+    access_control::RoleType,
+    errors::PSP34Error,
+    psp34::Id,
+};
+
+/// Role that is allowed to lock and unlock tokens via [`PSP34Lockable::lock`] and [`PSP34Lockable::unlock`].
+pub const LOCKER: RoleType = ink::selector_id!("LOCKER");
+
+#[openbrush::wrapper]
+pub type PSP34LockableRef = dyn PSP34Lockable;
+
+#[openbrush::trait_definition]
+pub trait PSP34Lockable {
+    /// Returns `true` if the token with `id` is locked.
+    #[ink(message)]
+    fn is_locked(&self, id: Id) -> bool;
+
+    /// Locks the token with `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` error if the caller doesn't have `LOCKER` role.
+    ///
+    /// Returns `TokenNotExists` error if the token doesn't exist.
+    #[ink(message)]
+    fn lock(&mut self, id: Id) -> Result<(), PSP34Error>;
+
+    /// Unlocks the token with `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` error if the caller doesn't have `LOCKER` role.
+    #[ink(message)]
+    fn unlock(&mut self, id: Id) -> Result<(), PSP34Error>;
+}
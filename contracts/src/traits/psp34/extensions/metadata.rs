@@ -34,4 +34,14 @@ pub trait PSP34Metadata {
     /// If `id` is a collection id of the token, it returns attributes for collection.
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: String) -> Option<String>;
+
+    /// Returns the number of attribute keys set for `id`.
+    #[ink(message)]
+    fn get_attribute_count(&self, id: Id) -> u32;
+
+    /// Returns the attribute key of `id` at `index`, `None` if `index` is out of bounds.
+    ///
+    /// Keys are enumerated in the order they were set for the first time.
+    #[ink(message)]
+    fn get_attribute_name(&self, id: Id, index: u32) -> Option<String>;
 }
@@ -0,0 +1,50 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34Metadata`] that allows accounts with `METADATA_ADMIN` role to set attributes of tokens
+pub use crate::traits::{
+    access_control::RoleType,
+    errors::PSP34Error,
+    psp34::Id,
+};
+use openbrush::traits::String;
+
+/// Role that is allowed to set attributes of tokens via [`PSP34MetadataAdmin::set_attribute`].
+pub const METADATA_ADMIN: RoleType = ink::selector_id!("METADATA_ADMIN");
+
+#[openbrush::wrapper]
+pub type PSP34MetadataAdminRef = dyn PSP34MetadataAdmin;
+
+#[openbrush::trait_definition]
+pub trait PSP34MetadataAdmin {
+    /// Sets the attribute of `id` for the given `key` to `value`.
+    ///
+    /// On success an `AttributeSet` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AC::MissingRole")` error if the caller doesn't have `METADATA_ADMIN` role.
+    ///
+    /// See [`metadata::Internal::_set_attribute`].
+    #[ink(message)]
+    fn set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), PSP34Error>;
+}
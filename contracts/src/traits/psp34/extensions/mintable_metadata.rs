@@ -0,0 +1,55 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34Mintable`] and [`PSP34Metadata`] that mints a token together with its attributes
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    String,
+};
+
+#[openbrush::wrapper]
+pub type PSP34MintableMetadataRef = dyn PSP34MintableMetadata;
+
+#[openbrush::trait_definition]
+pub trait PSP34MintableMetadata {
+    /// Mints a new token with `id` to `account` and sets its `attributes` in one call,
+    /// so the token never exists without its attributes.
+    ///
+    /// It isn't restricted by default, user should protect it with a modifier like `only_owner`.
+    ///
+    /// On success a `Transfer` event and an `AttributeSet` event per attribute are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("DuplicateAttribute")` error if `attributes` contain the same key twice.
+    ///
+    /// See [`PSP34::_mint_to`].
+    #[ink(message)]
+    fn mint_with_attributes(
+        &mut self,
+        account: AccountId,
+        id: Id,
+        attributes: Vec<(String, String)>,
+    ) -> Result<(), PSP34Error>;
+}
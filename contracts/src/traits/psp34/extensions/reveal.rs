@@ -0,0 +1,55 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+/// Extension of [`PSP34`] that hides the real token URIs behind a placeholder until the collection is revealed
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use openbrush::traits::String;
+
+#[openbrush::wrapper]
+pub type PSP34RevealRef = dyn PSP34Reveal;
+
+#[openbrush::trait_definition]
+pub trait PSP34Reveal {
+    /// Returns `true` if the collection is revealed.
+    #[ink(message)]
+    fn is_revealed(&self) -> bool;
+
+    /// Returns the URI of the token with `id`.
+    ///
+    /// Before reveal it is the placeholder URI for every token, after reveal it is `base_uri` followed by `id`.
+    ///
+    /// On error returns `PSP34Error::TokenNotExists`.
+    #[ink(message)]
+    fn token_uri(&self, id: Id) -> Result<String, PSP34Error>;
+
+    /// Reveals the real URIs of the tokens. Reveal is irreversible.
+    ///
+    /// On success a `Revealed` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error with `O::CallerIsNotOwner` if the caller is not the owner.
+    ///
+    /// Returns `Custom` error with `AlreadyRevealed` if the collection is already revealed.
+    #[ink(message)]
+    fn reveal(&mut self) -> Result<(), PSP34Error>;
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP34`] that allows to buy tokens for the native currency
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PSP34SaleRef = dyn PSP34Sale;
+
+#[openbrush::trait_definition]
+pub trait PSP34Sale {
+    /// Returns the price of one token in the native currency.
+    #[ink(message)]
+    fn price(&self) -> Balance;
+
+    /// Mints a new token with `id` to `to` for the transferred value
+    /// and forwards the price to the sale recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("InsufficientPayment")` error if the transferred value is less than the price.
+    ///
+    /// Returns `Custom("Overpayment")` error if the transferred value is greater than the price
+    /// and refunds of the overpayment are disabled. Otherwise, the overpayment is refunded to the caller.
+    ///
+    /// Returns `Custom("TransferFailed")` error if the refund or the proceeds transfer fails.
+    ///
+    /// See [`PSP34::_mint_to`].
+    #[ink(message, payable)]
+    fn mint_sale(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error>;
+}
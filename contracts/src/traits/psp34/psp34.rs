@@ -32,6 +32,9 @@ use openbrush::traits::{
     Balance,
 };
 
+/// Maximum number of ids queried by `PSP34::owners_of` in one call.
+pub const MAX_OWNERS_OF_IDS: usize = 100;
+
 #[openbrush::wrapper]
 pub type PSP34Ref = dyn PSP34;
 
@@ -51,9 +54,27 @@ pub trait PSP34 {
     fn balance_of(&self, owner: AccountId) -> u32;
 
     /// Returns the owner of the token if any.
+    ///
+    /// Returns `None` only if the token doesn't exist, see [`PSP34::exists`].
     #[ink(message)]
     fn owner_of(&self, id: Id) -> Option<AccountId>;
 
+    /// Returns the owners of `ids`, `None` for tokens that don't exist.
+    ///
+    /// The result is aligned with `ids`.
+    ///
+    /// On error returns `PSP34Error::Custom("BatchTooLarge")` if there are more than
+    /// [`MAX_OWNERS_OF_IDS`] ids.
+    #[ink(message)]
+    fn owners_of(&self, ids: Vec<Id>) -> Result<Vec<Option<AccountId>>, PSP34Error>;
+
+    /// Returns `true` if the token with `id` is minted and not burned.
+    ///
+    /// Unlike `owner_of`, it doesn't depend on the owner, so it can't be confused with
+    /// a token owned by the zero address.
+    #[ink(message)]
+    fn exists(&self, id: Id) -> bool;
+
     /// Returns `true` if the operator is approved by the owner to withdraw `id` token.
     /// If `id` is `None`, returns `true` if the operator is approved to withdraw all owner's tokens.
     #[ink(message)]
@@ -72,7 +93,25 @@ pub trait PSP34 {
     #[ink(message)]
     fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error>;
 
-    /// Transfer approved or owned token from caller.
+    /// Approves or disapproves `operator` to withdraw each of `ids` tokens from the caller's account.
+    ///
+    /// On success a `Approval` event is emitted for each token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if any of `ids` does not exist.
+    ///
+    /// Returns `SelfApprove` error if `operator` owns any of `ids`.
+    ///
+    /// Returns `NotApproved` error if caller is not allowed to manage any of `ids`.
+    #[ink(message)]
+    fn approve_batch(&mut self, operator: AccountId, ids: Vec<Id>, approved: bool) -> Result<(), PSP34Error>;
+
+    /// Transfer token owned by the caller.
+    ///
+    /// Approved operators must use [`PSP34::transfer_from`] instead.
+    ///
+    /// If `to` is a contract, [`PSP34Receiver::on_received`] is called on it with `data`.
     ///
     /// On success a `Transfer` event is emitted.
     ///
@@ -80,13 +119,57 @@ pub trait PSP34 {
     ///
     /// Returns `TokenNotExists` error if `id` does not exist.
     ///
-    /// Returns `NotApproved` error if `from` doesn't have allowance for transferring.
+    /// Returns `NotOwner` error if caller is not owner of `id`.
     ///
     /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
     #[ink(message)]
     fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error>;
 
+    /// Transfer token owned by `from` on the behalf of `from`.
+    ///
+    /// The caller must be `from` or an operator approved by `from` for `id` or all its tokens.
+    ///
+    /// If `to` is a contract, [`PSP34Receiver::on_received`] is called on it with `data`.
+    ///
+    /// On success a `Transfer` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    ///
+    /// Returns `NotOwner` error if `from` is not owner of `id`.
+    ///
+    /// Returns `NotApproved` error if caller doesn't have allowance for transferring.
+    ///
+    /// Returns `SafeTransferCheckFailed` error if `to` doesn't accept transfer.
+    #[ink(message)]
+    fn transfer_from(&mut self, from: AccountId, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error>;
+
     /// Returns current NFT total supply.
     #[ink(message)]
     fn total_supply(&self) -> Balance;
 }
+
+/// Selector that `PSP34Receiver::on_received` must return to acknowledge the transfer.
+pub const ON_PSP34_RECEIVED: [u8; 4] = ink::selector_bytes!("PSP34Receiver::on_received");
+
+#[openbrush::wrapper]
+pub type PSP34ReceiverRef = dyn PSP34Receiver;
+
+/// PSP34Receiver is an interface for any contract that wants to support safe transfers from a PSP34 token smart contract.
+#[openbrush::trait_definition]
+pub trait PSP34Receiver {
+    /// Ensures that the smart contract allows reception of PSP34 token.
+    /// Token contract calls this method on the receiver contract after the transfer of `id` token.
+    ///
+    /// Must return [`ON_PSP34_RECEIVED`] to acknowledge the transfer,
+    /// any other value or an error causes the transfer to be reverted.
+    #[ink(message)]
+    fn on_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        id: Id,
+        data: Vec<u8>,
+    ) -> Result<[u8; 4], PSP34ReceiverError>;
+}
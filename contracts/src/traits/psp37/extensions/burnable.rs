@@ -37,7 +37,16 @@ pub type PSP37BurnableRef = dyn PSP37Burnable;
 pub trait PSP37Burnable {
     /// Destroys `amount` tokens of token type `id` from `from`
     ///
+    /// The caller must be `from` or an operator approved by `from` for every burned `id`.
+    /// The whole batch is burned or nothing is.
+    ///
     /// See [`PSP37::_burn_from`].
+    ///
+    /// # Errors
+    ///
+    /// Returns with `NotAllowed` error if the caller is not allowed to burn any of the `ids_amounts`.
+    ///
+    /// Returns with `InsufficientBalance` error if `from` doesn't contain enough balance of any of the `ids_amounts`.
     #[ink(message)]
     fn burn(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error>;
 }
@@ -28,5 +28,6 @@ pub mod extensions {
     pub mod burnable;
     pub mod enumerable;
     pub mod metadata;
+    pub mod metadata_named;
     pub mod mintable;
 }
@@ -32,6 +32,9 @@ use openbrush::traits::{
     Balance,
 };
 
+/// Maximum number of entries queried by `PSP37::balance_of_batch` in one call.
+pub const MAX_BALANCE_OF_BATCH_LEN: usize = 100;
+
 #[openbrush::wrapper]
 pub type PSP37Ref = dyn PSP37;
 
@@ -46,6 +49,15 @@ pub trait PSP37 {
     #[ink(message)]
     fn balance_of(&self, owner: AccountId, id: Option<Id>) -> Balance;
 
+    /// Returns the balances of `(owner, id)` pairs, like ERC-1155 `balanceOfBatch`.
+    ///
+    /// The result is aligned with `accounts_ids`.
+    ///
+    /// On error returns `PSP37Error::Custom("BatchTooLarge")` if there are more than
+    /// [`MAX_BALANCE_OF_BATCH_LEN`] pairs.
+    #[ink(message)]
+    fn balance_of_batch(&self, accounts_ids: Vec<(AccountId, Id)>) -> Result<Vec<Balance>, PSP37Error>;
+
     /// Returns the total amount of token type `id` in the supply.
     ///
     /// If `id` is `None` returns the total number of tokens.
@@ -66,6 +78,12 @@ pub trait PSP37 {
     #[ink(message)]
     fn approve(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error>;
 
+    /// Revokes all operators approved by the caller for all of its tokens.
+    ///
+    /// `Approval` and `ApprovalForAll` events are emitted for each revoked operator.
+    #[ink(message)]
+    fn revoke_all_operators(&mut self) -> Result<(), PSP37Error>;
+
     /// Transfers `value` of `id` token from `caller` to `to`
     ///
     /// On success a `TransferSingle` event is emitted.
@@ -0,0 +1,141 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    multicall,
+    traits::multicall::*,
+};
+use ink::{
+    env::{
+        call::{
+            build_call,
+            ExecutionInput,
+            Selector,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+pub use multicall::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// Messages that can be called through `multicall`, keyed by the callee and the selector.
+    pub allowed_calls: Mapping<(AccountId, [u8; 4]), ()>,
+}
+
+struct RawInput<'a>(&'a [u8]);
+
+impl scale::Encode for RawInput<'_> {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0)
+    }
+}
+
+struct RawOutput(Vec<u8>);
+
+impl scale::Decode for RawOutput {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let len = input.remaining_len()?.ok_or("Unknown length of the output")?;
+        let mut output = ink::prelude::vec![0u8; len];
+        input.read(&mut output)?;
+        Ok(RawOutput(output))
+    }
+}
+
+pub trait MulticallImpl: Internal {
+    fn multicall(&self, calls: Vec<(AccountId, Vec<u8>)>) -> Vec<Result<Vec<u8>, ()>> {
+        calls
+            .iter()
+            .map(|(callee, input)| self._static_call(callee, input).ok_or(()))
+            .collect()
+    }
+}
+
+pub trait Internal {
+    /// Returns `true` if the message of `callee` with `selector` can be called through `multicall`.
+    fn _is_call_allowed(&self, callee: &AccountId, selector: &[u8; 4]) -> bool;
+
+    /// Allows or disallows calling the message of `callee` with `selector` through `multicall`.
+    ///
+    /// The contracts can't be called in read-only mode, so only view messages must be allowed.
+    /// An allowed message that modifies the state of `callee` can be called by anyone
+    /// with the multicall contract as the caller.
+    fn _set_call_allowed(&mut self, callee: AccountId, selector: [u8; 4], allowed: bool);
+
+    /// Calls `callee` with `input` and returns the encoded return value of the message,
+    /// or `None` if the message is not allowed or the call failed.
+    ///
+    /// A message that returns an `Err` reverts its changes, but the callee still returns
+    /// the encoded `Err`, so it is returned as is and the caller can decode the error.
+    /// Traps, unknown selectors and undecodable input return `None`.
+    fn _static_call(&self, callee: &AccountId, input: &[u8]) -> Option<Vec<u8>>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _is_call_allowed(&self, callee: &AccountId, selector: &[u8; 4]) -> bool {
+        self.data().allowed_calls.contains(&(*callee, *selector))
+    }
+
+    fn _set_call_allowed(&mut self, callee: AccountId, selector: [u8; 4], allowed: bool) {
+        if allowed {
+            self.data().allowed_calls.insert(&(callee, selector), &());
+        } else {
+            self.data().allowed_calls.remove(&(callee, selector));
+        }
+    }
+
+    fn _static_call(&self, callee: &AccountId, input: &[u8]) -> Option<Vec<u8>> {
+        if input.len() < 4 {
+            return None
+        }
+        let (selector, args) = input.split_at(4);
+        let selector = [selector[0], selector[1], selector[2], selector[3]];
+
+        if !Internal::_is_call_allowed(self, callee, &selector) {
+            return None
+        }
+
+        let result = build_call::<DefaultEnvironment>()
+            .call(*callee)
+            .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(args)))
+            .returns::<RawOutput>()
+            .try_invoke();
+
+        match result {
+            Ok(Ok(RawOutput(output))) => Some(output),
+            _ => None,
+        }
+    }
+}
@@ -23,6 +23,10 @@ pub mod shares {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
+        burnable: burnable::Data,
+        #[storage_field]
         ownable: ownable::Data,
         #[storage_field]
         metadata: metadata::Data,
@@ -33,6 +37,15 @@ pub mod shares {
     #[modifiers(only_owner)]
     fn mint() {}
 
+    /// override the `_check_minter` function to allow only the owner to call `mint_to_many` and `finish_minting`
+    #[overrider(mintable::Internal)]
+    fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error> {
+        if Ownable::owner(self) != Some(*caller) {
+            return Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        }
+        Ok(())
+    }
+
     /// override the `burn` function to add the `only_owner` modifier
     #[default_impl(PSP22Burnable)]
     #[modifiers(only_owner)]
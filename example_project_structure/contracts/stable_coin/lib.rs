@@ -17,6 +17,8 @@ pub mod token {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
         metadata: metadata::Data,
     }
 
@@ -25,5 +25,51 @@ pub mod diamond_caller {
         pub fn transfer(&mut self, token: AccountId, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
             PSP22Ref::transfer_from(&token, Self::env().caller(), to, value, Vec::<u8>::new())
         }
+
+        /// Returns `PSP22Error::Custom("LE::CouldNotReadInput")` if `token` doesn't implement `PSP22`.
+        #[ink(message)]
+        pub fn try_balance_of(&mut self, token: AccountId, account: AccountId) -> Result<Balance, PSP22Error> {
+            Ok(PSP22Ref::try_balance_of(&token, account)?)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::build_message;
+        use openbrush::traits::String;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn try_balance_of_returns_lang_error(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = DiamondCallerRef::new();
+            let address = client
+                .instantiate("diamond_caller", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // The callee is a contract that doesn't implement `PSP22`
+            let constructor = DiamondCallerRef::new();
+            let callee = client
+                .instantiate("diamond_caller", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let result = {
+                let _msg = build_message::<DiamondCallerRef>(address.clone())
+                    .call(|contract| contract.try_balance_of(callee.clone(), callee.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result, Err(PSP22Error::Custom(String::from("LE::CouldNotReadInput"))));
+
+            Ok(())
+        }
     }
 }
@@ -1,11 +1,47 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(Governor, GovernorSettings, GovernorQuorum, GovernorVotes, GovernorCounting)]
+#[openbrush::implementation(
+    Governor,
+    GovernorSettings,
+    GovernorQuorum,
+    GovernorVotes,
+    GovernorCounting,
+    GovernorTimelock
+)]
 #[openbrush::contract]
 pub mod my_governor {
     use ink::prelude::vec::Vec;
     use openbrush::traits::{Storage, String};
 
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        proposal_id: ProposalId,
+        #[ink(topic)]
+        voter: AccountId,
+        support: VoteType,
+        weight: Balance,
+        reason: String,
+    }
+
+    #[overrider(GovernorEvents)]
+    fn emit_vote_cast(
+        &self,
+        proposal_id: ProposalId,
+        voter: AccountId,
+        support: VoteType,
+        weight: Balance,
+        reason: String,
+    ) {
+        Self::env().emit_event(VoteCast {
+            proposal_id,
+            voter,
+            support,
+            weight,
+            reason,
+        });
+    }
+
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct Contract {
@@ -19,6 +55,8 @@ pub mod my_governor {
         settings: governor_settings::Data,
         #[storage_field]
         quorum: governor_quorum::Data,
+        #[storage_field]
+        timelock: governor_timelock::Data,
         mock_timestamp: Timestamp,
     }
 
@@ -43,6 +81,35 @@ pub mod my_governor {
             instance
         }
 
+        /// Creates the governor that executes proposals through the `timelock`.
+        ///
+        /// The governor must be granted the proposer and executor roles of the timelock.
+        #[ink(constructor)]
+        pub fn new_with_timelock(
+            token: AccountId,
+            voting_delay: u64,
+            voting_period: u64,
+            proposal_threshold: u128,
+            numerator: u128,
+            timelock: AccountId,
+        ) -> Self {
+            let mut instance = Self::new(token, voting_delay, voting_period, proposal_threshold, numerator);
+
+            instance._init_governor_timelock(timelock).unwrap();
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_guardian(&mut self, guardian: Option<AccountId>) {
+            self._set_guardian(guardian);
+        }
+
+        #[ink(message)]
+        pub fn set_abstain_counts_toward_quorum(&mut self, counts: bool) {
+            self._set_abstain_counts_toward_quorum(counts);
+        }
+
         #[ink(message)]
         pub fn block_timestamp(&self) -> Timestamp {
             self.mock_timestamp
@@ -10,6 +10,8 @@ pub mod my_psp22_burnable {
     pub struct Contract {
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        burnable: burnable::Data,
     }
 
     impl Contract {
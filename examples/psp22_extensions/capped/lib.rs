@@ -14,6 +14,8 @@ pub mod my_psp22_capped {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
         cap: capped::Data,
     }
 
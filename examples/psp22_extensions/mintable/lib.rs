@@ -10,6 +10,8 @@ pub mod my_psp22_mintable {
     pub struct Contract {
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        mintable: mintable::Data,
     }
 
     impl Contract {
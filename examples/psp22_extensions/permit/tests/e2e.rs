@@ -19,7 +19,10 @@ use openbrush::contracts::{
 use crate::my_psp22_permit::*;
 // use openbrush::contracts::psp22::extensions::permit::PERMIT_TYPE_HASH;
 use openbrush::{
-    contracts::psp22::extensions::permit::PermitMessage,
+    contracts::psp22::{
+        extensions::permit::PermitMessage,
+        PSP22Error,
+    },
     traits::{
         AccountId,
         Balance,
@@ -27,6 +30,7 @@ use openbrush::{
     utils::crypto::{
         hash_blake2b256,
         Signature,
+        SigningScheme,
     },
 };
 use scale::Encode;
@@ -132,7 +136,7 @@ async fn permit_accepts_owner_signature(mut client: ink_e2e::Client<C, E>) -> E2
 
     let message = &scale::Encode::encode(&permit_message);
 
-    let msg_hash = hash_blake2b256(message);
+    let msg_hash = SigningScheme::Eip712.digest(&domain_separator, message);
 
     let msg = Message::from_slice(&msg_hash).expect("message creation failed");
     let seckey = SecretKey::from_slice(&seckey).expect("secret key creation failed");
@@ -201,7 +205,7 @@ async fn permit_rejects_reused_signature(mut client: ink_e2e::Client<C, E>) -> E
 
     let message = &scale::Encode::encode(&permit_message);
 
-    let msg_hash = hash_blake2b256(message);
+    let msg_hash = SigningScheme::Eip712.digest(&domain_separator, message);
 
     let msg = Message::from_slice(&msg_hash).expect("message creation failed");
     let seckey = SecretKey::from_slice(&seckey).expect("secret key creation failed");
@@ -296,7 +300,7 @@ async fn permit_rejects_other_signature(mut client: ink_e2e::Client<C, E>) -> E2
 
     let message = &scale::Encode::encode(&permit_message);
 
-    let msg_hash = hash_blake2b256(message);
+    let msg_hash = SigningScheme::Eip712.digest(&domain_separator, message);
 
     let msg = Message::from_slice(&msg_hash).expect("message creation failed");
     let seckey = SecretKey::from_slice(&seckey).expect("secret key creation failed");
@@ -364,7 +368,7 @@ async fn permit_rejects_expired_permit(mut client: ink_e2e::Client<C, E>) -> E2E
 
     let message = &scale::Encode::encode(&permit_message);
 
-    let msg_hash = hash_blake2b256(message);
+    let msg_hash = SigningScheme::Eip712.digest(&domain_separator, message);
 
     let msg = Message::from_slice(&msg_hash).expect("message creation failed");
     let seckey = SecretKey::from_slice(&seckey).expect("secret key creation failed");
@@ -393,3 +397,165 @@ async fn permit_rejects_expired_permit(mut client: ink_e2e::Client<C, E>) -> E2E
 
     Ok(())
 }
+
+#[ink_e2e::test]
+async fn permit_transfer_from_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+    let constructor = ContractRef::new(1000);
+    let address = client
+        .instantiate("my_psp22_permit", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    let owner = AccountId::from(hash_blake2b256(&permit_signer_pubkey().serialize().to_vec()));
+    let spender = address_of!(Bob);
+    let receiver = address_of!(Charlie);
+
+    let _transfer: Result<(), PSP22Error> = method_call!(client, address, transfer(owner, 1000, vec![]));
+
+    let nonce: u64 = method_call_dry_run!(client, address, nonces(owner));
+    let deadline: u64 = 30_000_000_000_000;
+    let permitted: Balance = 500;
+
+    let domain_separator: [u8; 32] = method_call_dry_run!(client, address, domain_separator());
+
+    let signature = sign_permit(&PermitMessage {
+        domain_separator,
+        owner,
+        spender,
+        amount: permitted,
+        deadline,
+        nonce,
+    });
+
+    let result = method_call!(
+        client,
+        address,
+        bob,
+        permit_transfer_from(owner, permitted, deadline, Signature::ECDSA(signature), receiver, 300)
+    );
+    assert_eq!(result, Ok(()));
+
+    let owner_balance: Balance = method_call_dry_run!(client, address, balance_of(owner));
+    let allowance: Balance = method_call_dry_run!(client, address, allowance(owner, spender));
+
+    assert_eq!(owner_balance, 700);
+    assert!(matches!(balance_of!(client, address, Charlie), 300));
+    assert_eq!(allowance, 200);
+
+    Ok(())
+}
+
+#[ink_e2e::test]
+async fn permit_transfer_from_rejects_other_signature(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+    let constructor = ContractRef::new(1000);
+    let address = client
+        .instantiate("my_psp22_permit", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    let owner = address_of!(Alice);
+    let spender = address_of!(Bob);
+    let receiver = address_of!(Charlie);
+
+    let nonce: u64 = method_call_dry_run!(client, address, nonces(owner));
+    let deadline: u64 = 30_000_000_000_000;
+    let permitted: Balance = 500;
+
+    let domain_separator: [u8; 32] = method_call_dry_run!(client, address, domain_separator());
+
+    let signature = sign_permit(&PermitMessage {
+        domain_separator,
+        owner,
+        spender,
+        amount: permitted,
+        deadline,
+        nonce,
+    });
+
+    let result = method_call_dry_run!(
+        client,
+        address,
+        bob,
+        permit_transfer_from(owner, permitted, deadline, Signature::ECDSA(signature), receiver, 300)
+    );
+    assert_eq!(result, Err(PSP22Error::PermitInvalidSignature));
+
+    assert!(matches!(balance_of!(client, address, Alice), 1000));
+    assert!(matches!(balance_of!(client, address, Charlie), 0));
+
+    Ok(())
+}
+
+#[ink_e2e::test]
+async fn permit_transfer_from_rejects_value_above_permitted(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+    let constructor = ContractRef::new(1000);
+    let address = client
+        .instantiate("my_psp22_permit", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    let owner = AccountId::from(hash_blake2b256(&permit_signer_pubkey().serialize().to_vec()));
+    let spender = address_of!(Bob);
+    let receiver = address_of!(Charlie);
+
+    let _transfer: Result<(), PSP22Error> = method_call!(client, address, transfer(owner, 1000, vec![]));
+
+    let nonce: u64 = method_call_dry_run!(client, address, nonces(owner));
+    let deadline: u64 = 30_000_000_000_000;
+    let permitted: Balance = 500;
+
+    let domain_separator: [u8; 32] = method_call_dry_run!(client, address, domain_separator());
+
+    let signature = sign_permit(&PermitMessage {
+        domain_separator,
+        owner,
+        spender,
+        amount: permitted,
+        deadline,
+        nonce,
+    });
+
+    let result = method_call_dry_run!(
+        client,
+        address,
+        bob,
+        permit_transfer_from(owner, permitted, deadline, Signature::ECDSA(signature), receiver, 600)
+    );
+    assert_eq!(result, Err(PSP22Error::InsufficientAllowance));
+
+    let owner_balance: Balance = method_call_dry_run!(client, address, balance_of(owner));
+    assert_eq!(owner_balance, 1000);
+
+    Ok(())
+}
+
+const PERMIT_SIGNER_SECKEY: [u8; 32] = [
+    59, 148, 11, 85, 134, 130, 61, 253, 2, 174, 59, 70, 27, 180, 51, 107, 94, 203, 174, 253, 102, 39, 170, 146, 46,
+    252, 4, 143, 236, 12, 136, 28,
+];
+
+fn permit_signer_pubkey() -> PublicKey {
+    PublicKey::from_secret_key(
+        &SECP256K1,
+        &SecretKey::from_slice(&PERMIT_SIGNER_SECKEY).expect("seckey creation failed"),
+    )
+}
+
+fn sign_permit(permit_message: &PermitMessage) -> [u8; 65] {
+    let msg_hash =
+        SigningScheme::Eip712.digest(&permit_message.domain_separator, &scale::Encode::encode(permit_message));
+
+    let msg = Message::from_slice(&msg_hash).expect("message creation failed");
+    let seckey = SecretKey::from_slice(&PERMIT_SIGNER_SECKEY).expect("secret key creation failed");
+    let recoverable_signature: RecoverableSignature = SECP256K1.sign_ecdsa_recoverable(&msg, &seckey);
+
+    let recovery_id = recoverable_signature.serialize_compact().0.to_i32() as u8;
+    let mut signature = recoverable_signature.serialize_compact().1.to_vec();
+    signature.push(recovery_id);
+    signature
+        .try_into()
+        .expect("unable to create signature with recovery id")
+}
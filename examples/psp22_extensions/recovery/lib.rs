@@ -0,0 +1,205 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(PSP22, PSP22Recovery, Ownable)]
+#[openbrush::contract]
+pub mod my_psp22_recovery {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        staked_token: Option<AccountId>,
+        total_staked: Balance,
+    }
+
+    #[overrider(recovery::Internal)]
+    fn _protected_balance(&self, token: &AccountId) -> Balance {
+        if Some(*token) == self.staked_token {
+            self.total_staked
+        } else {
+            0
+        }
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, staked_token: AccountId) -> Self {
+            let mut instance = Self::default();
+            let caller = Self::env().caller();
+
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            psp22::Internal::_mint_to(&mut instance, caller, total_supply).expect("Should mint");
+            instance.staked_token = Some(staked_token);
+
+            instance
+        }
+
+        /// Transfers `amount` of the staked token from the caller to the contract
+        #[ink(message)]
+        pub fn stake(&mut self, amount: Balance) -> Result<(), PSP22Error> {
+            let token = self.staked_token.unwrap();
+            PSP22Ref::transfer_from(
+                &token,
+                Self::env().caller(),
+                Self::env().account_id(),
+                amount,
+                Vec::<u8>::new(),
+            )?;
+            self.total_staked += amount;
+            Ok(())
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        use openbrush::contracts::psp22::{
+            extensions::recovery::psp22recovery_external::PSP22Recovery,
+            psp22_external::PSP22,
+        };
+
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::{build_message, PolkadotConfig};
+
+        use my_psp22::my_psp22::ContractRef as TokenRef;
+        use openbrush::traits::String;
+        use test_helpers::address_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        macro_rules! token_balance_of {
+            ($client:ident, $token:expr, $account:expr) => {{
+                let _msg = build_message::<TokenRef>($token.clone()).call(|token| token.balance_of($account));
+                $client
+                    .call_dry_run(&ink_e2e::alice(), &_msg, 0, None)
+                    .await
+                    .return_value()
+            }};
+        }
+
+        #[ink_e2e::test]
+        async fn rescue_token_transfers_foreign_token(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = TokenRef::new(1000);
+            let staked_token = client
+                .instantiate("my_psp22", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = TokenRef::new(1000);
+            let foreign_token = client
+                .instantiate("my_psp22", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ContractRef::new(100, staked_token);
+            let address = client
+                .instantiate("my_psp22_recovery", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Alice sends the foreign token to the contract by mistake
+            let result = {
+                let _msg = build_message::<TokenRef>(foreign_token.clone())
+                    .call(|token| token.transfer(address.clone(), 50, vec![]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            };
+
+            assert!(matches!(result.return_value(), Ok(())));
+
+            let result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.rescue_token(foreign_token.clone(), address_of!(Bob), 50));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("rescue_token failed")
+            };
+
+            assert!(matches!(result.return_value(), Ok(())));
+
+            assert_eq!(token_balance_of!(client, foreign_token, address.clone()), 0);
+            assert_eq!(token_balance_of!(client, foreign_token, address_of!(Bob)), 50);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn rescue_token_keeps_staked_balance(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = TokenRef::new(1000);
+            let staked_token = client
+                .instantiate("my_psp22", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ContractRef::new(100, staked_token);
+            let address = client
+                .instantiate("my_psp22_recovery", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let _msg = build_message::<TokenRef>(staked_token.clone()).call(|token| token.approve(address.clone(), 30));
+            client
+                .call(&ink_e2e::alice(), _msg, 0, None)
+                .await
+                .expect("approve failed");
+
+            let result = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| contract.stake(30));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("stake failed")
+            };
+
+            assert!(matches!(result.return_value(), Ok(())));
+
+            // Alice sends the staked token to the contract by mistake
+            let _msg = build_message::<TokenRef>(staked_token.clone())
+                .call(|token| token.transfer(address.clone(), 20, vec![]));
+            client
+                .call(&ink_e2e::alice(), _msg, 0, None)
+                .await
+                .expect("transfer failed");
+
+            let result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.rescue_token(staked_token.clone(), address_of!(Bob), 21));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            };
+
+            assert_eq!(
+                result.return_value(),
+                Err(PSP22Error::Custom(String::from("ProtectedBalance")))
+            );
+
+            let result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.rescue_token(staked_token.clone(), address_of!(Bob), 20));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("rescue_token failed")
+            };
+
+            assert!(matches!(result.return_value(), Ok(())));
+
+            assert_eq!(token_balance_of!(client, staked_token, address.clone()), 30);
+            assert_eq!(token_balance_of!(client, staked_token, address_of!(Bob)), 20);
+
+            Ok(())
+        }
+    }
+}
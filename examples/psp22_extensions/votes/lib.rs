@@ -11,6 +11,8 @@ pub mod my_psp22_votes {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
         votes: votes::Data,
         #[storage_field]
         nonces: nonces::Data,
@@ -1,9 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(PSP22, PSP22Wrapper)]
+#[openbrush::implementation(PSP22, PSP22Wrapper(reentrant_guard))]
 #[openbrush::contract]
 pub mod my_psp22_wrapper {
-    use openbrush::traits::Storage;
+    use openbrush::{
+        contracts::reentrancy_guard,
+        traits::Storage,
+    };
 
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -12,6 +15,8 @@ pub mod my_psp22_wrapper {
         psp22: psp22::Data,
         #[storage_field]
         wrapper: wrapper::Data,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
     }
 
     impl Contract {
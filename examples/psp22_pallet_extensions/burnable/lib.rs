@@ -10,6 +10,8 @@ pub mod my_psp22_pallet_burnable {
     pub struct Contract {
         #[storage_field]
         pallet: psp22_pallet::Data,
+        #[storage_field]
+        burnable: burnable::Data,
     }
 
     impl Contract {
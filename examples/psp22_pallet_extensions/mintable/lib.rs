@@ -10,6 +10,8 @@ pub mod my_psp22_pallet_mintable {
     pub struct Contract {
         #[storage_field]
         pallet: psp22_pallet::Data,
+        #[storage_field]
+        mintable: mintable::Data,
     }
 
     impl Contract {
@@ -0,0 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::contract]
+pub mod psp34_receiver {
+    use ink::prelude::vec::Vec;
+    use openbrush::{
+        contracts::psp34::*,
+        traits::String,
+    };
+
+    #[ink(storage)]
+    pub struct Receiver {
+        accept: bool,
+    }
+
+    impl Receiver {
+        #[ink(constructor)]
+        pub fn new(accept: bool) -> Self {
+            Self { accept }
+        }
+    }
+
+    impl PSP34Receiver for Receiver {
+        #[ink(message)]
+        fn on_received(
+            &mut self,
+            _operator: AccountId,
+            _from: AccountId,
+            _id: Id,
+            _data: Vec<u8>,
+        ) -> Result<[u8; 4], PSP34ReceiverError> {
+            if !self.accept {
+                return Err(PSP34ReceiverError::TransferRejected(String::from(
+                    "Receiver doesn't accept tokens",
+                )))
+            }
+            Ok(ON_PSP34_RECEIVED)
+        }
+    }
+}
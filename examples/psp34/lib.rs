@@ -39,6 +39,7 @@ pub mod my_psp34 {
         use super::*;
         #[rustfmt::skip]
         use ink_e2e::{build_message, PolkadotConfig};
+        use psp34_receiver::psp34_receiver::ReceiverRef;
 
         use test_helpers::{
             address_of,
@@ -192,8 +193,9 @@ pub mod my_psp34 {
             assert_eq!(approve_result, Ok(()));
 
             let transfer_result = {
-                let _msg = build_message::<ContractRef>(address.clone())
-                    .call(|contract| contract.transfer(address_of!(Bob), Id::U8(0), vec![]));
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.transfer_from(address_of!(Alice), address_of!(Bob), Id::U8(0), vec![])
+                });
                 client
                     .call(&ink_e2e::bob(), _msg, 0, None)
                     .await
@@ -248,8 +250,9 @@ pub mod my_psp34 {
             assert_eq!(approve_result, Ok(()));
 
             let transfer_result = {
-                let _msg = build_message::<ContractRef>(address.clone())
-                    .call(|contract| contract.transfer(address_of!(Bob), Id::U8(0), vec![]));
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.transfer_from(address_of!(Alice), address_of!(Bob), Id::U8(0), vec![])
+                });
                 client
                     .call(&ink_e2e::bob(), _msg, 0, None)
                     .await
@@ -349,8 +352,9 @@ pub mod my_psp34 {
             assert_eq!(mint_result, Ok(()));
 
             let transfer_result = {
-                let _msg = build_message::<ContractRef>(address.clone())
-                    .call(|contract| contract.transfer(address_of!(Bob), Id::U8(0), vec![]));
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.transfer_from(address_of!(Alice), address_of!(Bob), Id::U8(0), vec![])
+                });
                 client.call_dry_run(&ink_e2e::bob(), &_msg, 0, None).await
             }
             .return_value();
@@ -399,5 +403,129 @@ pub mod my_psp34 {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn transfer_to_accepting_receiver_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_psp34", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ReceiverRef::new(true);
+            let receiver = client
+                .instantiate("psp34_receiver", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| contract.mint_token());
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint_token failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_result, Ok(()));
+
+            let transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(receiver, Id::U8(0), vec![1, 2, 3]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_result, Ok(()));
+
+            assert_eq!(owner_of!(client, address, Id::U8(0)), Some(receiver));
+            assert_eq!(0, balance_of!(client, address, Alice));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfer_to_rejecting_receiver_fails(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_psp34", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ReceiverRef::new(false);
+            let receiver = client
+                .instantiate("psp34_receiver", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| contract.mint_token());
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint_token failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_result, Ok(()));
+
+            let transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(receiver, Id::U8(0), vec![]));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(matches!(transfer_result, Err(PSP34Error::SafeTransferCheckFailed(_))));
+
+            assert_eq!(owner_of!(client, address, Id::U8(0)), Some(address_of!(Alice)));
+            assert_eq!(1, balance_of!(client, address, Alice));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfer_to_account_skips_receiver_check(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_psp34", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| contract.mint_token());
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint_token failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_result, Ok(()));
+
+            let transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(Charlie), Id::U8(0), vec![1, 2, 3]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_result, Ok(()));
+
+            assert_eq!(owner_of!(client, address, Id::U8(0)), Some(address_of!(Charlie)));
+
+            Ok(())
+        }
     }
 }
@@ -152,10 +152,7 @@ pub mod my_psp37 {
             let burn_tx = {
                 let _msg = build_message::<ContractRef>(address.clone())
                     .call(|contract| contract.burn(address_of!(Bob), vec![(token_1.clone(), amount_1.clone())]));
-                client
-                    .call(&ink_e2e::alice(), _msg, 0, None)
-                    .await
-                    .expect("burn failed")
+                client.call(&ink_e2e::bob(), _msg, 0, None).await.expect("burn failed")
             }
             .return_value();
 
@@ -271,10 +268,7 @@ pub mod my_psp37 {
                         vec![(token_1.clone(), 0), (token_2.clone(), amount_2.clone())],
                     )
                 });
-                client
-                    .call(&ink_e2e::alice(), _msg, 0, None)
-                    .await
-                    .expect("burn failed")
+                client.call(&ink_e2e::bob(), _msg, 0, None).await.expect("burn failed")
             }
             .return_value();
 
@@ -353,7 +347,7 @@ pub mod my_psp37 {
                         vec![(token_1.clone(), amount_1 + 1), (token_2.clone(), amount_2.clone())],
                     )
                 });
-                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+                client.call_dry_run(&ink_e2e::bob(), &_msg, 0, None).await
             }
             .return_value();
 
@@ -362,7 +356,7 @@ pub mod my_psp37 {
             let burn_tx = {
                 let _msg = build_message::<ContractRef>(address.clone())
                     .call(|contract| contract.burn(address_of!(Bob), vec![(token_1.clone(), amount_1 + 1)]));
-                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+                client.call_dry_run(&ink_e2e::bob(), &_msg, 0, None).await
             }
             .return_value();
 
@@ -20,6 +20,8 @@ pub mod contract_v2 {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        burnable: burnable::Data,
+        #[storage_field]
         ownable: ownable::Data,
         fee_collector: Lazy<AccountId, ManualKey<STORAGE_KEY>>,
     }
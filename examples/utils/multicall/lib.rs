@@ -0,0 +1,149 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Multicall)]
+#[openbrush::contract]
+pub mod multicall {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        multicall: multicall::Data,
+    }
+
+    impl Contract {
+        /// Creates the contract that can call the view messages from `allowed_calls`.
+        #[ink(constructor)]
+        pub fn new(allowed_calls: Vec<(AccountId, [u8; 4])>) -> Self {
+            let mut instance = Self::default();
+            for (callee, selector) in allowed_calls {
+                multicall::Internal::_set_call_allowed(&mut instance, callee, selector, true);
+            }
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        use openbrush::contracts::multicall::multicall_external::Multicall;
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::{build_message, PolkadotConfig};
+        use mock_view::mock_view::MockViewRef;
+        use scale::Decode;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn multicall_returns_result_of_each_call(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = MockViewRef::new(42, false);
+            let working = client
+                .instantiate("mock_view", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = MockViewRef::new(7, true);
+            let trapping = client
+                .instantiate("mock_view", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let get = ink::selector_bytes!("get");
+            let constructor = ContractRef::new(vec![(working, get), (trapping, get)]);
+            let address = client
+                .instantiate("multicall", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let get = get.to_vec();
+            let calls = vec![(working, get.clone()), (trapping, get.clone()), (working, get)];
+
+            let results = {
+                let _msg =
+                    build_message::<ContractRef>(address.clone()).call(|contract| contract.multicall(calls.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(results.len(), 3);
+            assert_eq!(u32::decode(&mut &results[0].clone().unwrap()[..]), Ok(42));
+            assert_eq!(results[1], Err(()));
+            assert_eq!(u32::decode(&mut &results[2].clone().unwrap()[..]), Ok(42));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn multicall_fails_call_with_invalid_input(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = MockViewRef::new(42, false);
+            let working = client
+                .instantiate("mock_view", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ContractRef::new(vec![(working, ink::selector_bytes!("get"))]);
+            let address = client
+                .instantiate("multicall", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let calls = vec![(working, vec![0, 1]), (working, vec![0, 0, 0, 0])];
+
+            let results = {
+                let _msg =
+                    build_message::<ContractRef>(address.clone()).call(|contract| contract.multicall(calls.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(results, vec![Err(()), Err(())]);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn multicall_rejects_not_allowed_message(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = MockViewRef::new(42, false);
+            let working = client
+                .instantiate("mock_view", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let constructor = ContractRef::new(vec![(working, ink::selector_bytes!("get"))]);
+            let address = client
+                .instantiate("multicall", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mut set = ink::selector_bytes!("set").to_vec();
+            set.extend(scale::Encode::encode(&7u32));
+            let get = ink::selector_bytes!("get").to_vec();
+            let calls = vec![(working, set), (working, get)];
+
+            let results = {
+                let _msg =
+                    build_message::<ContractRef>(address.clone()).call(|contract| contract.multicall(calls.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("multicall failed")
+            }
+            .return_value();
+
+            // The mutating message is not called, so the value is unchanged
+            assert_eq!(results[0], Err(()));
+            assert_eq!(u32::decode(&mut &results[1].clone().unwrap()[..]), Ok(42));
+
+            Ok(())
+        }
+    }
+}
@@ -25,19 +25,27 @@ use crate::{
     metadata::LockedTrait,
     trait_definition,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{
+    TokenStream,
+    TokenTree,
+};
 use quote::{
+    format_ident,
     quote,
+    quote_spanned,
     ToTokens,
 };
 use syn::Item;
 
+const TEST_EXPORTS: &str = "test_exports";
+const VERSION: &str = "version";
+
 pub fn generate(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     if internal::skip() {
         return quote! {}
     }
     let input: TokenStream = ink_module;
-    let attrs: TokenStream = _attrs;
+    let (attrs, test_exports, version) = extract_openbrush_args(_attrs);
     let mut module = syn::parse2::<syn::ItemMod>(input).expect("Can't parse contract module");
     let (braces, mut items) = match module.content {
         Some((brace, items)) => (brace, items),
@@ -53,7 +61,16 @@ pub fn generate(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     // After, we can consume all other stuff.
     items = consume_traits(items);
 
-    let generated_items = generate_impls(items);
+    let mut generated_items = generate_impls(items);
+    if test_exports {
+        generated_items = generate_test_exports(generated_items);
+    }
+    if let Some(version) = version {
+        match generate_version(generated_items, &version) {
+            Ok(items) => generated_items = items,
+            Err(error) => return error,
+        }
+    }
 
     module.content = Some((braces, generated_items));
 
@@ -114,3 +131,176 @@ fn generate_impls(mut items: Vec<syn::Item>) -> Vec<syn::Item> {
 
     generated_items
 }
+
+/// Removes the `test_exports` flag and the `version = "..."` argument from the arguments of the macro,
+/// all other arguments are passed to ink! as is.
+fn extract_openbrush_args(attrs: TokenStream) -> (TokenStream, bool, Option<syn::LitStr>) {
+    let mut args: Vec<Vec<TokenTree>> = vec![vec![]];
+    for token in attrs {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(vec![]),
+            _ => args.last_mut().unwrap().push(token),
+        }
+    }
+
+    let mut test_exports = false;
+    let mut version = None;
+    let args: Vec<TokenStream> = args
+        .into_iter()
+        .filter(|arg| {
+            let is_flag = matches!(arg.as_slice(), [TokenTree::Ident(ident)] if ident == TEST_EXPORTS);
+            test_exports |= is_flag;
+
+            let is_version = match arg.as_slice() {
+                [TokenTree::Ident(ident), TokenTree::Punct(punct), TokenTree::Literal(literal)]
+                    if ident == VERSION && punct.as_char() == '=' =>
+                {
+                    version = Some(
+                        syn::parse2::<syn::LitStr>(literal.to_token_stream())
+                            .expect("`version` argument must be a string literal"),
+                    );
+                    true
+                }
+                _ => false,
+            };
+
+            !is_flag && !is_version && !arg.is_empty()
+        })
+        .map(|arg| arg.into_iter().collect())
+        .collect();
+
+    (quote! { #(#args),* }, test_exports, version)
+}
+
+/// Generates `version` message on the storage struct that returns the `(major, minor, patch)`
+/// triple parsed from the `version` argument of the macro.
+fn generate_version(mut items: Vec<syn::Item>, version: &syn::LitStr) -> Result<Vec<syn::Item>, TokenStream> {
+    let parts: Vec<Option<u32>> = version
+        .value()
+        .split('.')
+        .map(|part| part.parse::<u32>().ok())
+        .collect();
+    let (major, minor, patch) = match parts.as_slice() {
+        [Some(major), Some(minor), Some(patch)] => (*major, *minor, *patch),
+        _ => {
+            let message = format!("Invalid version `{}`, expected `MAJOR.MINOR.PATCH`", version.value());
+            return Err(quote_spanned! {
+                version.span() =>
+                    compile_error!(#message);
+            })
+        }
+    };
+
+    let storage_ident = items
+        .iter()
+        .find_map(|item| {
+            match item {
+                Item::Struct(item_struct) if is_ink_storage(&item_struct.attrs) => Some(item_struct.ident.clone()),
+                _ => None,
+            }
+        })
+        .expect("Contract storage struct not found!");
+
+    items.push(
+        syn::parse2::<syn::Item>(quote! {
+            impl #storage_ident {
+                /// Returns the `(major, minor, patch)` version of the contract.
+                #[ink(message)]
+                pub fn version(&self) -> (u32, u32, u32) {
+                    (#major, #minor, #patch)
+                }
+            }
+        })
+        .expect("Can't parse version message"),
+    );
+
+    Ok(items)
+}
+
+fn is_ink_storage(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| is_attr(&[(*attr).clone()], "ink"))
+        .any(|attr| {
+            attr.parse_args::<syn::Path>()
+                .map(|path| path.is_ident("storage"))
+                .unwrap_or(false)
+        })
+}
+
+/// Generates `#[cfg(test)]` inherent methods on the storage struct for all `_` methods
+/// of the implemented `Internal` traits, so tests can call them directly on the contract.
+fn generate_test_exports(mut items: Vec<syn::Item>) -> Vec<syn::Item> {
+    let mut exported: Vec<(String, syn::Ident)> = vec![];
+    let mut exports: Vec<syn::Item> = vec![];
+
+    for item in items.iter() {
+        let item_impl = match item {
+            Item::Impl(item_impl) => item_impl,
+            _ => continue,
+        };
+        let trait_path = match &item_impl.trait_ {
+            Some((_, trait_path, _)) => trait_path,
+            None => continue,
+        };
+        if trait_path.segments.last().expect("Trait path is empty").ident != "Internal" {
+            continue
+        }
+        let self_ty = &item_impl.self_ty;
+        let self_ty_key = self_ty.to_token_stream().to_string();
+
+        let methods: Vec<TokenStream> = item_impl
+            .items
+            .iter()
+            .filter_map(|item| {
+                match item {
+                    syn::ImplItem::Method(method) => Some(method),
+                    _ => None,
+                }
+            })
+            .filter(|method| method.sig.ident.to_string().starts_with('_') && method.sig.receiver().is_some())
+            .filter(|method| {
+                let key = (self_ty_key.clone(), method.sig.ident.clone());
+                // The first trait wins if several traits define a method with the same name.
+                if exported.contains(&key) {
+                    return false
+                }
+                exported.push(key);
+                true
+            })
+            .map(|method| {
+                let mut sig = method.sig.clone();
+                let mut args = vec![];
+                sig.inputs.iter_mut().enumerate().for_each(|(i, input)| {
+                    if let syn::FnArg::Typed(pat_type) = input {
+                        let arg = format_ident!("__openbrush_arg_{}", i);
+                        pat_type.pat = Box::new(syn::parse_quote! { #arg });
+                        args.push(arg);
+                    }
+                });
+                let ident = &sig.ident;
+
+                quote! {
+                    pub(crate) #sig {
+                        <#self_ty as #trait_path>::#ident(self, #(#args),*)
+                    }
+                }
+            })
+            .collect();
+
+        if !methods.is_empty() {
+            exports.push(
+                syn::parse2::<syn::Item>(quote! {
+                    #[cfg(test)]
+                    impl #self_ty {
+                        #(#methods)*
+                    }
+                })
+                .expect("Can't parse test exports"),
+            );
+        }
+    }
+
+    items.append(&mut exports);
+    items
+}
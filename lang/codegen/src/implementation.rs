@@ -35,12 +35,17 @@ use syn::{
     Path,
 };
 
+const REENTRANT_GUARD: &str = "reentrant_guard";
+
 pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     if internal::skip() {
         return quote! {}
     }
     let input: TokenStream = ink_module;
 
+    // names of default implementations which messages are protected by the reentrancy guard
+    let mut guarded = Vec::<String>::new();
+
     // map attribute args to default contract names
     let args = syn::parse2::<AttributeArgs>(attrs)
         .expect("No default contracts to implement provided")
@@ -48,7 +53,16 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
         .map(|arg| {
             match arg {
                 NestedMeta::Path(method) => method.to_token_stream().to_string().replace(' ', ""),
-                _ => panic!("Expected names of OpenBrush traits to implement in the contract!"),
+                NestedMeta::List(list) => {
+                    let name = list.path.to_token_stream().to_string().replace(' ', "");
+                    for option in list.nested.iter() {
+                        match option.to_token_stream().to_string().as_str() {
+                            REENTRANT_GUARD => guarded.push(name.clone()),
+                            option => panic!("openbrush::implementation({name}({option})) is not supported!"),
+                        }
+                    }
+                    name
+                }
             }
         })
         .collect::<Vec<String>>();
@@ -77,14 +91,26 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     let mut impl_args = ImplArgs::new(&map, &mut items, &mut imports, &mut overriden_traits, ident);
 
     for to_implement in &args {
+        let generated_from = impl_args.items.len();
+
         match to_implement.as_str() {
             "PSP22" => impl_psp22(&mut impl_args),
             "PSP22Mintable" => impl_psp22_mintable(&mut impl_args),
             "PSP22Burnable" => impl_psp22_burnable(&mut impl_args),
             "PSP22Permit" => impl_psp22_permit(&mut impl_args),
             "PSP22Metadata" => impl_psp22_metadata(&mut impl_args),
+            "PSP22BatchRead" => impl_psp22_batch_read(&mut impl_args),
             "PSP22Capped" => impl_psp22_capped(&mut impl_args),
+            "PSP22Circulating" => impl_psp22_circulating(&mut impl_args),
+            "PSP22Cooldown" => impl_psp22_cooldown(&mut impl_args),
+            "PSP22Deflationary" => impl_psp22_deflationary(&mut impl_args),
+            "PSP22Inflation" => impl_psp22_inflation(&mut impl_args),
+            "PSP22MaxTx" => impl_psp22_max_tx(&mut impl_args),
+            "PSP22MerkleAirdrop" => impl_psp22_merkle_airdrop(&mut impl_args),
+            "PSP22Recovery" => impl_psp22_recovery(&mut impl_args),
+            "PSP22Restricted" => impl_psp22_restricted(&mut impl_args),
             "PSP22Wrapper" => impl_psp22_wrapper(&mut impl_args),
+            "PSP22ZeroTransfer" => impl_psp22_zero_transfer(&mut impl_args),
             "PSP22Votes" => impl_psp22_votes(&mut impl_args),
             "Flashmint" => impl_flashmint(&mut impl_args),
             "PSP22TokenTimelock" => impl_token_timelock(&mut impl_args),
@@ -93,20 +119,36 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP22PalletMetadata" => impl_psp22_pallet_metadata(&mut impl_args),
             "PSP22PalletMintable" => impl_psp22_pallet_mintable(&mut impl_args),
             "PSP34" => impl_psp34(&mut impl_args),
+            "PSP34AttributeIndex" => impl_psp34_attribute_index(&mut impl_args),
+            "PSP34AutoId" => impl_psp34_auto_id(&mut impl_args),
             "PSP34Burnable" => impl_psp34_burnable(&mut impl_args),
+            "PSP34Capped" => impl_psp34_capped(&mut impl_args),
+            "PSP34Evolve" => impl_psp34_evolve(&mut impl_args),
+            "PSP34Lockable" => impl_psp34_lockable(&mut impl_args),
+            "PSP34MaxBalance" => impl_psp34_max_balance(&mut impl_args),
             "PSP34Mintable" => impl_psp34_mintable(&mut impl_args),
             "PSP34Metadata" => impl_psp34_metadata(&mut impl_args),
+            "PSP34Provenance" => impl_psp34_provenance(&mut impl_args),
+            "PSP34MetadataAdmin" => impl_psp34_metadata_admin(&mut impl_args),
+            "PSP34MintableMetadata" => impl_psp34_mintable_metadata(&mut impl_args),
+            "PSP34Reveal" => impl_psp34_reveal(&mut impl_args),
+            "PSP34Sale" => impl_psp34_sale(&mut impl_args),
+            "PSP34TokenFreeze" => impl_psp34_token_freeze(&mut impl_args),
+            "PSP34TransferLock" => impl_psp34_transfer_lock(&mut impl_args),
             "PSP34Enumerable" => impl_psp34_enumerable(&mut impl_args),
             "PSP37" => impl_psp37(&mut impl_args),
             "PSP37Batch" => impl_psp37_batch(&mut impl_args),
             "PSP37Burnable" => impl_psp37_burnable(&mut impl_args),
             "PSP37Metadata" => impl_psp37_metadata(&mut impl_args),
+            "PSP37MetadataNamed" => impl_psp37_metadata_named(&mut impl_args),
             "PSP37Mintable" => impl_psp37_mintable(&mut impl_args),
             "PSP37Enumerable" => impl_psp37_enumerable(&mut impl_args),
             "Ownable" => impl_ownable(&mut impl_args),
             "PaymentSplitter" => impl_payment_splitter(&mut impl_args),
             "AccessControl" => impl_access_control(&mut impl_args),
             "AccessControlEnumerable" => impl_access_control_enumerable(&mut impl_args),
+            "AccessControlBootstrap" => impl_access_control_bootstrap(&mut impl_args),
+            "AccessControlTimelockedGrant" => impl_access_control_timelocked_grant(&mut impl_args),
             "Pausable" => impl_pausable(&mut impl_args),
             "TimelockController" => impl_timelock_controller(&mut impl_args),
             "Proxy" => impl_proxy(&mut impl_args),
@@ -115,13 +157,19 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "Upgradeable" => impl_upgradeable(&mut impl_args),
             "Governor" => impl_governor(&mut impl_args),
             "GovernorSettings" => impl_governor_settings(&mut impl_args),
+            "GovernorTimelock" => impl_governor_timelock(&mut impl_args),
             "GovernorVotes" => impl_governor_votes(&mut impl_args),
             "GovernorQuorum" => impl_governor_quorum(&mut impl_args),
             "GovernorCounting" => impl_governor_counting(&mut impl_args),
             "Nonces" => impl_nonces(&mut impl_args),
+            "Multicall" => impl_multicall(&mut impl_args),
             "PSP61" => impl_psp61(&mut impl_args, args.clone()),
             _ => panic!("openbrush::implementation({to_implement}) not implemented!"),
         }
+
+        if guarded.contains(to_implement) {
+            guard_messages(&mut impl_args.items[generated_from..]);
+        }
     }
 
     cleanup_imports(impl_args.imports);
@@ -143,15 +191,68 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     }
 }
 
+/// Adds the `non_reentrant` modifier to all generated messages that accept `&mut self` and return `Result`.
+///
+/// The modifier requires the contract to implement `Storage<reentrancy_guard::Data>`.
+fn guard_messages(items: &mut [syn::Item]) {
+    for item in items.iter_mut() {
+        let item_impl = match item {
+            Item::Impl(item_impl) if item_impl.trait_.is_some() => item_impl,
+            _ => continue,
+        };
+
+        for impl_item in item_impl.items.iter_mut() {
+            let method = match impl_item {
+                syn::ImplItem::Method(method) => method,
+                _ => continue,
+            };
+            let is_message = method.attrs.iter().any(|attr| {
+                attr.path.is_ident("ink") && attr.tokens.to_string().replace(' ', "").starts_with("(message")
+            });
+            let is_mutable = matches!(
+                method.sig.receiver(),
+                Some(syn::FnArg::Receiver(receiver)) if receiver.mutability.is_some()
+            );
+            let returns_result = match &method.sig.output {
+                syn::ReturnType::Type(_, ty) => {
+                    match ty.as_ref() {
+                        syn::Type::Path(path) => {
+                            path.path.segments.last().expect("Return type is empty").ident == "Result"
+                        }
+                        _ => false,
+                    }
+                }
+                syn::ReturnType::Default => false,
+            };
+
+            if is_message && is_mutable && returns_result {
+                method.attrs.push(syn::parse_quote! {
+                    #[openbrush::modifiers(openbrush::contracts::reentrancy_guard::non_reentrant)]
+                });
+            }
+        }
+    }
+}
+
 fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     // we will remove unnecessary imports
     let psp22_impls = vec![
         "PSP22Mintable",
         "PSP22Burnable",
         "PSP22Capped",
+        "PSP22Circulating",
+        "PSP22Cooldown",
+        "PSP22Deflationary",
         "PSP22Metadata",
         "PSP22Wrapper",
+        "PSP22ZeroTransfer",
         "PSP22Permit",
+        "PSP22BatchRead",
+        "PSP22Inflation",
+        "PSP22MaxTx",
+        "PSP22MerkleAirdrop",
+        "PSP22Recovery",
+        "PSP22Restricted",
         "Flashmint",
     ];
     check_and_remove_import("PSP22", psp22_impls, imports);
@@ -159,19 +260,42 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     let psp22_pallet_impls = vec!["PSP22PalletMintable", "PSP22PalletBurnable", "PSP22PalletMetadata"];
     check_and_remove_import("PSP22Pallet", psp22_pallet_impls, imports);
 
-    let psp34_impls = vec!["PSP34Mintable", "PSP34Burnable", "PSP34Metadata", "PSP34Enumerable"];
+    let psp34_impls = vec![
+        "PSP34AttributeIndex",
+        "PSP34AutoId",
+        "PSP34Mintable",
+        "PSP34Burnable",
+        "PSP34Capped",
+        "PSP34Evolve",
+        "PSP34Lockable",
+        "PSP34MaxBalance",
+        "PSP34Metadata",
+        "PSP34MetadataAdmin",
+        "PSP34MintableMetadata",
+        "PSP34Enumerable",
+        "PSP34Provenance",
+        "PSP34Reveal",
+        "PSP34Sale",
+        "PSP34TokenFreeze",
+        "PSP34TransferLock",
+    ];
     check_and_remove_import("PSP34", psp34_impls, imports);
 
     let psp37_impls = vec![
         "PSP37Batch",
         "PSP37Burnable",
         "PSP37Metadata",
+        "PSP37MetadataNamed",
         "PSP37Mintable",
         "PSP37Enumerable",
     ];
     check_and_remove_import("PSP37", psp37_impls, imports);
 
-    let access_impls = vec!["AccessControlEnumerable", "TimelockController"];
+    let access_impls = vec![
+        "AccessControlEnumerable",
+        "AccessControlTimelockedGrant",
+        "TimelockController",
+    ];
     check_and_remove_import("AccessControl", access_impls, imports);
 
     check_and_remove_import("Diamond", vec!["DiamondLoupe"], imports);
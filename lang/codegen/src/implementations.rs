@@ -45,6 +45,40 @@ impl<'a> ImplArgs<'a> {
         self.imports.insert("vec", vec_import);
     }
 
+    /// Implements `Clock` for the contract once, however many time-dependent extensions use it.
+    fn clock_impl(&mut self) {
+        let storage_struct_name = self.contract_name();
+        let clock_impl = syn::parse2::<syn::ItemImpl>(quote!(
+            impl ClockImpl for #storage_struct_name {}
+        ))
+        .expect("Should parse");
+
+        let mut clock = syn::parse2::<syn::ItemImpl>(quote!(
+            impl Clock for #storage_struct_name {
+                fn now(&self) -> Timestamp {
+                    ClockImpl::now(self)
+                }
+            }
+        ))
+        .expect("Should parse");
+
+        let import = syn::parse2::<syn::ItemUse>(quote!(
+            use openbrush::utils::clock::{
+                Clock,
+                ClockImpl,
+            };
+        ))
+        .expect("Should parse");
+        self.imports.insert("Clock", import);
+
+        override_functions("Clock", &mut clock, self.map);
+
+        self.overriden_traits
+            .entry("ClockImpl")
+            .or_insert(syn::Item::Impl(clock_impl));
+        self.overriden_traits.entry("Clock").or_insert(syn::Item::Impl(clock));
+    }
+
     fn signature_import(&mut self) {
         let sig_import = syn::parse2::<syn::ItemUse>(quote!(
             use openbrush::utils::crypto::Signature;
@@ -102,6 +136,22 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 psp22::InternalImpl::_approve_from_to(self, owner, spender, amount)
             }
 
+            fn _strict_approvals(&self) -> bool {
+                psp22::InternalImpl::_strict_approvals(self)
+            }
+
+            fn _set_strict_approvals(&mut self, strict: bool) {
+                psp22::InternalImpl::_set_strict_approvals(self, strict)
+            }
+
+            fn _reject_transfers_to_contract(&self) -> bool {
+                psp22::InternalImpl::_reject_transfers_to_contract(self)
+            }
+
+            fn _set_reject_transfers_to_contract(&mut self, reject: bool) {
+                psp22::InternalImpl::_set_reject_transfers_to_contract(self, reject)
+            }
+
             fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
                 psp22::InternalImpl::_mint_to(self, account, amount)
             }
@@ -158,6 +208,11 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 PSP22Impl::transfer(self, to, value, data)
             }
 
+            #[ink(message)]
+            fn transfer_all(&mut self, to: AccountId, data: Vec<u8>) -> Result<Balance, PSP22Error> {
+                PSP22Impl::transfer_all(self, to, data)
+            }
+
             #[ink(message)]
             fn transfer_from(
                 &mut self,
@@ -205,6 +260,36 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
 
 pub(crate) fn impl_psp22_mintable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl mintable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl mintable::Internal for #storage_struct_name {
+            fn _before_mint_to_many(&mut self, total: &Balance) -> Result<(), PSP22Error> {
+                mintable::InternalImpl::_before_mint_to_many(self, total)
+            }
+
+            fn _minting_finished(&self) -> bool {
+                mintable::InternalImpl::_minting_finished(self)
+            }
+
+            fn _finish_minting(&mut self) {
+                mintable::InternalImpl::_finish_minting(self)
+            }
+
+            fn _check_minting_not_finished(&self) -> Result<(), PSP22Error> {
+                mintable::InternalImpl::_check_minting_not_finished(self)
+            }
+
+            fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error> {
+                mintable::InternalImpl::_check_minter(self, caller)
+            }
+        }
+    ))
+    .expect("Should parse");
+
     let mintable_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP22MintableImpl for #storage_struct_name {}
     ))
@@ -216,6 +301,21 @@ pub(crate) fn impl_psp22_mintable(impl_args: &mut ImplArgs) {
             fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
                 PSP22MintableImpl::mint(self, account, amount)
             }
+
+            #[ink(message)]
+            fn mint_to_many(&mut self, recipients: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error> {
+                PSP22MintableImpl::mint_to_many(self, recipients)
+            }
+
+            #[ink(message)]
+            fn finish_minting(&mut self) -> Result<(), PSP22Error> {
+                PSP22MintableImpl::finish_minting(self)
+            }
+
+            #[ink(message)]
+            fn minting_finished(&self) -> bool {
+                PSP22MintableImpl::minting_finished(self)
+            }
         }
     ))
     .expect("Should parse");
@@ -227,14 +327,51 @@ pub(crate) fn impl_psp22_mintable(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("PSP22Mintable", import);
     impl_args.vec_import();
 
+    override_functions("mintable::Internal", &mut internal, impl_args.map);
     override_functions("PSP22Mintable", &mut mintable, impl_args.map);
 
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(mintable_impl));
     impl_args.items.push(syn::Item::Impl(mintable));
 }
 
 pub(crate) fn impl_psp22_burnable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl burnable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl burnable::Internal for #storage_struct_name {
+            fn _emit_burn_with_memo_event(&self, account: AccountId, amount: Balance, memo: String) {
+                burnable::InternalImpl::_emit_burn_with_memo_event(self, account, amount, memo)
+            }
+
+            fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance {
+                burnable::InternalImpl::_burn_allowance(self, owner, burner)
+            }
+
+            fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance) {
+                burnable::InternalImpl::_approve_burn(self, owner, burner, amount)
+            }
+
+            fn _min_supply(&self) -> Balance {
+                burnable::InternalImpl::_min_supply(self)
+            }
+
+            fn _set_min_supply(&mut self, min_supply: Balance) {
+                burnable::InternalImpl::_set_min_supply(self, min_supply)
+            }
+
+            fn _check_min_supply(&self, amount: Balance) -> Result<(), PSP22Error> {
+                burnable::InternalImpl::_check_min_supply(self, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
     let burnable_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP22BurnableImpl for #storage_struct_name {}
     ))
@@ -246,6 +383,26 @@ pub(crate) fn impl_psp22_burnable(impl_args: &mut ImplArgs) {
             fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
                 PSP22BurnableImpl::burn(self, account, amount)
             }
+
+            #[ink(message)]
+            fn burn_with_memo(&mut self, amount: Balance, memo: String) -> Result<(), PSP22Error> {
+                PSP22BurnableImpl::burn_with_memo(self, amount, memo)
+            }
+
+            #[ink(message)]
+            fn approve_burn(&mut self, burner: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22BurnableImpl::approve_burn(self, burner, amount)
+            }
+
+            #[ink(message)]
+            fn burn_allowance(&self, owner: AccountId, burner: AccountId) -> Balance {
+                PSP22BurnableImpl::burn_allowance(self, owner, burner)
+            }
+
+            #[ink(message)]
+            fn burn_from_approved(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22BurnableImpl::burn_from_approved(self, account, amount)
+            }
         }
     ))
     .expect("Should parse");
@@ -257,8 +414,11 @@ pub(crate) fn impl_psp22_burnable(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("PSP22Burnable", import);
     impl_args.vec_import();
 
+    override_functions("burnable::Internal", &mut internal, impl_args.map);
     override_functions("PSP22Burnable", &mut burnable, impl_args.map);
 
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(burnable_impl));
     impl_args.items.push(syn::Item::Impl(burnable));
 }
@@ -270,7 +430,7 @@ pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let permit_internal = syn::parse2::<syn::ItemImpl>(quote!(
+    let mut permit_internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl permit::Internal for #storage_struct_name {
             fn _permit(
                 &mut self,
@@ -282,9 +442,38 @@ pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
             ) -> Result<(), PSP22Error> {
                 permit::InternalImpl::_permit(self, owner, spender, amount, deadline, signature)
             }
+
+            fn _permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error> {
+                permit::InternalImpl::_permit_batch(self, permits)
+            }
+
+            fn _verify_permit(
+                &mut self,
+                owner: AccountId,
+                spender: AccountId,
+                amount: Balance,
+                deadline: u64,
+                nonce: u64,
+                signature: &Signature,
+            ) -> Result<(), PSP22Error> {
+                permit::InternalImpl::_verify_permit(self, owner, spender, amount, deadline, nonce, signature)
+            }
+
             fn _domain_separator(&mut self) -> [u8; 32] {
                 permit::InternalImpl::_domain_separator(self)
             }
+
+            fn _signing_scheme(&self) -> SigningScheme {
+                permit::InternalImpl::_signing_scheme(self)
+            }
+
+            fn _clock(&self) -> u64 {
+                permit::InternalImpl::_clock(self)
+            }
+
+            fn _emit_permit_used_event(&self, owner: AccountId, spender: AccountId, value: Balance, nonce: u64) {
+                permit::InternalImpl::_emit_permit_used_event(self, owner, spender, value, nonce)
+            }
         }
     ))
     .expect("Should parse");
@@ -294,7 +483,7 @@ pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let permit = syn::parse2::<syn::ItemImpl>(quote!(
+    let mut permit = syn::parse2::<syn::ItemImpl>(quote!(
         impl permit::PSP22Permit for #storage_struct_name {
             #[ink(message)]
             fn permit(
@@ -312,6 +501,24 @@ pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
             fn domain_separator(&mut self) -> [u8; 32] {
                 permit::PSP22PermitImpl::domain_separator(self)
             }
+
+            #[ink(message)]
+            fn permit_transfer_from(
+                &mut self,
+                owner: AccountId,
+                permitted: Balance,
+                deadline: u64,
+                signature: Signature,
+                to: AccountId,
+                value: Balance,
+            ) -> Result<(), PSP22Error> {
+                permit::PSP22PermitImpl::permit_transfer_from(self, owner, permitted, deadline, signature, to, value)
+            }
+
+            #[ink(message)]
+            fn permit_batch(&mut self, permits: Vec<PermitArgs>) -> Result<(), PSP22Error> {
+                permit::PSP22PermitImpl::permit_batch(self, permits)
+            }
         }
     ))
     .expect("Should parse");
@@ -322,11 +529,12 @@ pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
     .expect("Should parse");
 
     impl_args.imports.insert("PSP22Permit", import);
+    impl_args.clock_impl();
     impl_args.signature_import();
     impl_args.vec_import();
 
-    // TODO
-    // override_functions("PSP22Permit", &mut burnable, impl_args.map);
+    override_functions("permit::Internal", &mut permit_internal, impl_args.map);
+    override_functions("PSP22Permit", &mut permit, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(permit_internal_impl));
     impl_args.items.push(syn::Item::Impl(permit_internal));
@@ -375,6 +583,41 @@ pub(crate) fn impl_psp22_metadata(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(metadata));
 }
 
+pub(crate) fn impl_psp22_batch_read(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let batch_read_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22BatchReadImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut batch_read = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22BatchRead for #storage_struct_name {
+            #[ink(message)]
+            fn balances_of(&self, accounts: Vec<AccountId>) -> Result<Vec<Balance>, PSP22Error> {
+                PSP22BatchReadImpl::balances_of(self, accounts)
+            }
+
+            #[ink(message)]
+            fn allowances_of(&self, pairs: Vec<(AccountId, AccountId)>) -> Result<Vec<Balance>, PSP22Error> {
+                PSP22BatchReadImpl::allowances_of(self, pairs)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::batch_read::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22BatchRead", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22BatchRead", &mut batch_read, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(batch_read_impl));
+    impl_args.items.push(syn::Item::Impl(batch_read));
+}
+
 pub(crate) fn impl_psp22_capped(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -430,799 +673,2173 @@ pub(crate) fn impl_psp22_capped(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(capped));
 }
 
-pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_circulating(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl wrapper::InternalImpl for #storage_struct_name {}
+        impl circulating::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl wrapper::Internal for #storage_struct_name {
-            fn _recover(&mut self, account: AccountId) -> Result<Balance, PSP22Error> {
-                wrapper::InternalImpl::_recover(self, account)
-            }
-
-            fn _deposit(&mut self, amount: Balance) -> Result<(), PSP22Error> {
-                wrapper::InternalImpl::_deposit(self, amount)
-            }
-
-            fn _withdraw(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                wrapper::InternalImpl::_withdraw(self, account, amount)
-            }
-
-            fn _underlying_balance(&mut self) -> Balance {
-                wrapper::InternalImpl::_underlying_balance(self)
+        impl circulating::Internal for #storage_struct_name {
+            fn _circulating_supply(&self) -> Balance {
+                circulating::InternalImpl::_circulating_supply(self)
             }
 
-            fn _init(&mut self, underlying: AccountId) {
-                wrapper::InternalImpl::_init(self, underlying)
+            fn _is_excluded(&self, account: &AccountId) -> bool {
+                circulating::InternalImpl::_is_excluded(self, account)
             }
 
-            fn _underlying(&mut self) -> Option<AccountId> {
-                wrapper::InternalImpl::_underlying(self)
+            fn _set_excluded(&mut self, account: AccountId, excluded: bool) {
+                circulating::InternalImpl::_set_excluded(self, account, excluded)
             }
         }
     ))
     .expect("Should parse");
 
-    let wrapper_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22WrapperImpl for #storage_struct_name {}
+    let circulating_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22CirculatingImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut wrapper = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22Wrapper for #storage_struct_name {
+    let mut circulating = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Circulating for #storage_struct_name {
             #[ink(message)]
-            fn deposit_for(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                PSP22WrapperImpl::deposit_for(self, account, amount)
+            fn circulating_supply(&self) -> Balance {
+                PSP22CirculatingImpl::circulating_supply(self)
             }
 
             #[ink(message)]
-            fn withdraw_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                PSP22WrapperImpl::withdraw_to(self, account, amount)
+            fn is_excluded(&self, account: AccountId) -> bool {
+                PSP22CirculatingImpl::is_excluded(self, account)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22::extensions::wrapper::*;
+        use openbrush::contracts::psp22::extensions::circulating::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22Wrapper", import);
+    impl_args.imports.insert("PSP22Circulating", import);
     impl_args.vec_import();
 
-    override_functions("wrapper::Internal", &mut internal, impl_args.map);
-    override_functions("PSP22Wrapper", &mut wrapper, impl_args.map);
+    override_functions("circulating::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Circulating", &mut circulating, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(wrapper_impl));
-    impl_args.items.push(syn::Item::Impl(wrapper));
+    impl_args.items.push(syn::Item::Impl(circulating_impl));
+    impl_args.items.push(syn::Item::Impl(circulating));
 }
 
-pub(crate) fn impl_flashmint(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_cooldown(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl flashmint::InternalImpl for #storage_struct_name {}
+        impl cooldown::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl flashmint::Internal for #storage_struct_name {
-            fn _get_fee(&self, amount: Balance) -> Balance {
-                flashmint::InternalImpl::_get_fee(self, amount)
+        impl cooldown::Internal for #storage_struct_name {
+            fn _cooldown_secs(&self) -> u64 {
+                cooldown::InternalImpl::_cooldown_secs(self)
             }
 
-            fn _on_flashloan(
-                &mut self,
-                receiver_account: AccountId,
-                token: AccountId,
-                fee: Balance,
-                amount: Balance,
-                data: Vec<u8>,
-            ) -> Result<(), FlashLenderError> {
-                flashmint::InternalImpl::_on_flashloan(self, receiver_account, token, fee, amount, data)
+            fn _set_cooldown_secs(&mut self, cooldown_secs: u64) {
+                cooldown::InternalImpl::_set_cooldown_secs(self, cooldown_secs)
+            }
+
+            fn _last_transfer_time(&self, account: &AccountId) -> Option<Timestamp> {
+                cooldown::InternalImpl::_last_transfer_time(self, account)
+            }
+
+            fn _check_cooldown(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+                cooldown::InternalImpl::_check_cooldown(self, from, to)
             }
         }
     ))
     .expect("Should parse");
 
-    let flashlender_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl FlashLenderImpl for #storage_struct_name {}
+    let cooldown_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22CooldownImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut flashlender = syn::parse2::<syn::ItemImpl>(quote!(
-        impl FlashLender for #storage_struct_name {
-            #[ink(message)]
-            fn max_flashloan(&mut self, token: AccountId) -> Balance {
-                FlashLenderImpl::max_flashloan(self, token)
-            }
-
+    let mut cooldown = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Cooldown for #storage_struct_name {
             #[ink(message)]
-            fn flash_fee(&self, token: AccountId, amount: Balance) -> Result<Balance, FlashLenderError> {
-                FlashLenderImpl::flash_fee(self, token, amount)
+            fn cooldown_secs(&self) -> u64 {
+                PSP22CooldownImpl::cooldown_secs(self)
             }
 
             #[ink(message)]
-            fn flashloan(
-                &mut self,
-                receiver_account: AccountId,
-                token: AccountId,
-                amount: Balance,
-                data: Vec<u8>,
-            ) -> Result<(), FlashLenderError> {
-                FlashLenderImpl::flashloan(self, receiver_account, token, amount, data)
+            fn last_transfer_time(&self, account: AccountId) -> Option<Timestamp> {
+                PSP22CooldownImpl::last_transfer_time(self, account)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22::extensions::flashmint::*;
+        use openbrush::contracts::psp22::extensions::cooldown::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("Flashmint", import);
+    impl_args.imports.insert("PSP22Cooldown", import);
+    impl_args.clock_impl();
     impl_args.vec_import();
 
-    override_functions("flashmint::Internal", &mut internal, impl_args.map);
-    override_functions("FlashLender", &mut flashlender, impl_args.map);
+    override_functions("cooldown::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Cooldown", &mut cooldown, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(flashlender_impl));
-    impl_args.items.push(syn::Item::Impl(flashlender));
+    impl_args.items.push(syn::Item::Impl(cooldown_impl));
+    impl_args.items.push(syn::Item::Impl(cooldown));
 }
 
-pub(crate) fn impl_token_timelock(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_deflationary(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl token_timelock::InternalImpl for #storage_struct_name {}
+        impl deflationary::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl token_timelock::Internal for #storage_struct_name {
-            fn _withdraw(&mut self, amount: Balance) -> Result<(), PSP22TokenTimelockError> {
-                token_timelock::InternalImpl::_withdraw(self, amount)
+        impl deflationary::Internal for #storage_struct_name {
+            fn _burn_bps(&self) -> u16 {
+                deflationary::InternalImpl::_burn_bps(self)
             }
 
-            fn _contract_balance(&mut self) -> Balance {
-                token_timelock::InternalImpl::_contract_balance(self)
+            fn _set_burn_bps(&mut self, burn_bps: u16) -> Result<(), PSP22Error> {
+                deflationary::InternalImpl::_set_burn_bps(self, burn_bps)
             }
 
-            fn _init(
-                &mut self,
-                token: AccountId,
-                beneficiary: AccountId,
-                release_time: Timestamp,
-            ) -> Result<(), PSP22TokenTimelockError> {
-                token_timelock::InternalImpl::_init(self, token, beneficiary, release_time)
+            fn _buy_fee_bps(&self) -> u16 {
+                deflationary::InternalImpl::_buy_fee_bps(self)
             }
 
-            fn _token(&self) -> Option<AccountId> {
-                token_timelock::InternalImpl::_token(self)
+            fn _set_buy_fee_bps(&mut self, buy_fee_bps: u16) -> Result<(), PSP22Error> {
+                deflationary::InternalImpl::_set_buy_fee_bps(self, buy_fee_bps)
             }
 
-            fn _beneficiary(&self) -> Option<AccountId> {
-                token_timelock::InternalImpl::_beneficiary(self)
+            fn _sell_fee_bps(&self) -> u16 {
+                deflationary::InternalImpl::_sell_fee_bps(self)
             }
-        }
-    ))
-    .expect("Should parse");
 
-    let timelock_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22TokenTimelockImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
+            fn _set_sell_fee_bps(&mut self, sell_fee_bps: u16) -> Result<(), PSP22Error> {
+                deflationary::InternalImpl::_set_sell_fee_bps(self, sell_fee_bps)
+            }
 
-    let mut timelock = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22TokenTimelock for #storage_struct_name {
-            #[ink(message)]
-            fn token(&self) -> Option<AccountId> {
-                PSP22TokenTimelockImpl::token(self)
+            fn _is_pair(&self, account: &AccountId) -> bool {
+                deflationary::InternalImpl::_is_pair(self, account)
             }
 
-            #[ink(message)]
-            fn beneficiary(&self) -> Option<AccountId> {
-                PSP22TokenTimelockImpl::beneficiary(self)
+            fn _set_pair(&mut self, account: AccountId, is_pair: bool) {
+                deflationary::InternalImpl::_set_pair(self, account, is_pair)
             }
 
-            #[ink(message)]
-            fn release_time(&self) -> Timestamp {
-                PSP22TokenTimelockImpl::release_time(self)
+            fn _fee_bps(&self, from: &AccountId, to: &AccountId) -> u16 {
+                deflationary::InternalImpl::_fee_bps(self, from, to)
             }
 
+            fn _burn_amount(&self, amount: Balance) -> Balance {
+                deflationary::InternalImpl::_burn_amount(self, amount)
+            }
+
+            fn _transfer_with_burn(
+                &mut self,
+                from: AccountId,
+                to: AccountId,
+                amount: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                deflationary::InternalImpl::_transfer_with_burn(self, from, to, amount, data)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let deflationary_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22DeflationaryImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut deflationary = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Deflationary for #storage_struct_name {
             #[ink(message)]
-            fn release(&mut self) -> Result<(), PSP22TokenTimelockError> {
-                PSP22TokenTimelockImpl::release(self)
+            fn burn_bps(&self) -> u16 {
+                PSP22DeflationaryImpl::burn_bps(self)
+            }
+
+            #[ink(message)]
+            fn buy_fee_bps(&self) -> u16 {
+                PSP22DeflationaryImpl::buy_fee_bps(self)
+            }
+
+            #[ink(message)]
+            fn sell_fee_bps(&self) -> u16 {
+                PSP22DeflationaryImpl::sell_fee_bps(self)
+            }
+
+            #[ink(message)]
+            fn is_pair(&self, account: AccountId) -> bool {
+                PSP22DeflationaryImpl::is_pair(self, account)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22::utils::token_timelock::*;
+        use openbrush::contracts::psp22::extensions::deflationary::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22TokenTimelock", import);
+    impl_args.imports.insert("PSP22Deflationary", import);
+    impl_args.vec_import();
 
-    override_functions("token_timelock::Internal", &mut internal, impl_args.map);
-    override_functions("PSP22TokenTimelock", &mut timelock, impl_args.map);
+    override_functions("deflationary::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Deflationary", &mut deflationary, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(timelock_impl));
-    impl_args.items.push(syn::Item::Impl(timelock));
+    impl_args.items.push(syn::Item::Impl(deflationary_impl));
+    impl_args.items.push(syn::Item::Impl(deflationary));
 }
 
-pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
-    let _storage_struct_name = impl_args.contract_name();
-    let votes_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl VotesEvents for Contract {}
+pub(crate) fn impl_psp22_inflation(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl inflation::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let votes_internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl VotesInternal for Contract {
-            fn _get_voting_units(&self, account: &AccountId) -> Balance {
-                PSP22VotesInternal::_get_voting_units(self, account)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl inflation::Internal for #storage_struct_name {
+            fn _init_inflation(&mut self, rate_per_second: Balance) {
+                inflation::InternalImpl::_init_inflation(self, rate_per_second)
             }
         }
     ))
     .expect("Should parse");
 
-    let votes_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl VotesImpl for Contract {}
+    let inflation_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22InflationImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let votes = syn::parse2::<syn::ItemImpl>(quote!(
-        impl Votes for Contract {
+    let mut inflation = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Inflation for #storage_struct_name {
             #[ink(message)]
-            fn get_votes(&self, account: AccountId) -> Balance {
-                VotesImpl::get_votes(self, account)
+            fn rate_per_second(&self) -> Balance {
+                PSP22InflationImpl::rate_per_second(self)
             }
 
             #[ink(message)]
-            fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance, GovernanceError> {
-                VotesImpl::get_past_votes(self, account, timestamp)
+            fn last_mint_time(&self) -> Timestamp {
+                PSP22InflationImpl::last_mint_time(self)
             }
 
             #[ink(message)]
-            fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance, GovernanceError> {
-                VotesImpl::get_past_total_supply(self, timestamp)
+            fn mint_inflation(&mut self, to: AccountId) -> Result<(), PSP22Error> {
+                PSP22InflationImpl::mint_inflation(self, to)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            #[ink(message)]
-            fn delegates(&mut self, delegator: AccountId) -> Option<AccountId> {
-                VotesImpl::delegates(self, delegator)
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::inflation::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Inflation", import);
+    impl_args.clock_impl();
+    impl_args.vec_import();
+
+    override_functions("inflation::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Inflation", &mut inflation, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(inflation_impl));
+    impl_args.items.push(syn::Item::Impl(inflation));
+}
+
+pub(crate) fn impl_psp22_max_tx(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl max_tx::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl max_tx::Internal for #storage_struct_name {
+            fn _max_tx_amount(&self) -> Balance {
+                max_tx::InternalImpl::_max_tx_amount(self)
             }
 
-            #[ink(message)]
-            fn delegate(&mut self, delegatee: AccountId) -> Result<(), GovernanceError> {
-                VotesImpl::delegate(self, delegatee)
+            fn _set_max_tx(&mut self, max_tx_amount: Balance) {
+                max_tx::InternalImpl::_set_max_tx(self, max_tx_amount)
             }
 
-            #[ink(message)]
-            fn delegate_by_signature(
-                &mut self,
-                signer: AccountId,
-                delegatee: AccountId,
-                nonce: u64,
-                expiry: Timestamp,
-                signature: Signature,
-            ) -> Result<(), GovernanceError> {
-                VotesImpl::delegate_by_signature(self, signer, delegatee, nonce, expiry, signature)
+            fn _check_max_tx(&self, from: Option<&AccountId>, to: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                max_tx::InternalImpl::_check_max_tx(self, from, to, amount)
             }
         }
     ))
     .expect("Should parse");
 
-    let psp22_votes_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22VotesImpl for Contract {}
-    ))
-    .expect("Should parse");
-
-    let psp22_votes_internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22VotesInternal for Contract {}
+    let max_tx_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22MaxTxImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let psp22_votes = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22Votes for Contract {
-            #[ink(message)]
-            fn num_checkpoints(&self, account: AccountId) -> u32 {
-                PSP22VotesImpl::num_checkpoints(self, account)
-            }
-
+    let mut max_tx = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22MaxTx for #storage_struct_name {
             #[ink(message)]
-            fn checkpoints(&self, account: AccountId, pos: u32) -> Result<Checkpoint, GovernanceError> {
-                PSP22VotesImpl::checkpoints(self, account, pos)
+            fn max_tx_amount(&self) -> Balance {
+                PSP22MaxTxImpl::max_tx_amount(self)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::{
-            governance::utils::votes::*,
-            psp22::extensions::votes::*,
-        };
+        use openbrush::contracts::psp22::extensions::max_tx::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22Votes", import);
+    impl_args.imports.insert("PSP22MaxTx", import);
+    impl_args.vec_import();
 
-    impl_args.items.push(syn::Item::Impl(votes_events));
-    impl_args.items.push(syn::Item::Impl(votes_internal));
-    impl_args.items.push(syn::Item::Impl(votes_impl));
-    impl_args.items.push(syn::Item::Impl(votes));
-    impl_args.items.push(syn::Item::Impl(psp22_votes_impl));
-    impl_args.items.push(syn::Item::Impl(psp22_votes_internal));
-    impl_args.items.push(syn::Item::Impl(psp22_votes));
+    override_functions("max_tx::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22MaxTx", &mut max_tx, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(max_tx_impl));
+    impl_args.items.push(syn::Item::Impl(max_tx));
 }
-pub(crate) fn impl_psp22_pallet(impl_args: &mut ImplArgs) {
+
+pub(crate) fn impl_psp22_merkle_airdrop(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp22_pallet::InternalImpl for #storage_struct_name {}
+        impl merkle_airdrop::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp22_pallet::Internal for #storage_struct_name {
-            fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _amount: Balance) {
-                psp22_pallet::InternalImpl::_emit_transfer_event(self, _from, _to, _amount)
+        impl merkle_airdrop::Internal for #storage_struct_name {
+            fn _merkle_root(&self) -> [u8; 32] {
+                merkle_airdrop::InternalImpl::_merkle_root(self)
             }
 
-            fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance) {
-                psp22_pallet::InternalImpl::_emit_approval_event(self, _owner, _spender, _amount)
-            }
-
-            fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                psp22_pallet::InternalImpl::_mint_to(self, account, amount)
+            fn _set_merkle_root(&mut self, merkle_root: [u8; 32]) {
+                merkle_airdrop::InternalImpl::_set_merkle_root(self, merkle_root)
             }
 
-            fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                psp22_pallet::InternalImpl::_burn_from(self, account, amount)
+            fn _is_claimed(&self, index: u32) -> bool {
+                merkle_airdrop::InternalImpl::_is_claimed(self, index)
             }
 
-            fn _create(
-                &mut self,
-                asset_id: u32,
-                admin: AccountId,
-                min_balance: Balance,
-            ) -> Result<(), Error<DefaultEnvironment>> {
-                psp22_pallet::InternalImpl::_create(self, asset_id, admin, min_balance)
+            fn _set_claimed(&mut self, index: u32) {
+                merkle_airdrop::InternalImpl::_set_claimed(self, index)
             }
 
-            fn _sender(&self) -> AccountId {
-                psp22_pallet::InternalImpl::_sender(self)
+            fn _verify_claim(&self, index: u32, account: &AccountId, amount: Balance, proof: &[[u8; 32]]) -> bool {
+                merkle_airdrop::InternalImpl::_verify_claim(self, index, account, amount, proof)
             }
         }
     ))
     .expect("Should parse");
 
-    let psp22_pallet_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22PalletImpl for #storage_struct_name {}
+    let merkle_airdrop_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22MerkleAirdropImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut psp22 = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22 for #storage_struct_name {
-            #[ink(message)]
-            fn total_supply(&self) -> Balance {
-                PSP22PalletImpl::total_supply(self)
-            }
-
-            #[ink(message)]
-            fn balance_of(&self, owner: AccountId) -> Balance {
-                PSP22PalletImpl::balance_of(self, owner)
-            }
-
-            #[ink(message)]
-            fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
-                PSP22PalletImpl::allowance(self, owner, spender)
-            }
-
-            #[ink(message)]
-            fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
-                PSP22PalletImpl::transfer(self, to, value, data)
-            }
-
-            #[ink(message)]
-            fn transfer_from(
-                &mut self,
-                from: AccountId,
-                to: AccountId,
-                value: Balance,
-                data: Vec<u8>,
-            ) -> Result<(), PSP22Error> {
-                PSP22PalletImpl::transfer_from(self, from, to, value, data)
-            }
-
+    let mut merkle_airdrop = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22MerkleAirdrop for #storage_struct_name {
             #[ink(message)]
-            fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
-                PSP22PalletImpl::approve(self, spender, value)
+            fn merkle_root(&self) -> [u8; 32] {
+                PSP22MerkleAirdropImpl::merkle_root(self)
             }
 
             #[ink(message)]
-            fn increase_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
-                PSP22PalletImpl::increase_allowance(self, spender, delta_value)
+            fn is_claimed(&self, index: u32) -> bool {
+                PSP22MerkleAirdropImpl::is_claimed(self, index)
             }
 
             #[ink(message)]
-            fn decrease_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
-                PSP22PalletImpl::decrease_allowance(self, spender, delta_value)
+            fn claim(&mut self, index: u32, amount: Balance, proof: Vec<[u8; 32]>) -> Result<(), PSP22Error> {
+                PSP22MerkleAirdropImpl::claim(self, index, amount, proof)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22_pallet::*;
+        use openbrush::contracts::psp22::extensions::merkle_airdrop::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22Pallet", import);
+    impl_args.imports.insert("PSP22MerkleAirdrop", import);
     impl_args.vec_import();
 
-    override_functions("psp22_pallet::Internal", &mut internal, impl_args.map);
-    override_functions("PSP22", &mut psp22, impl_args.map);
+    override_functions("merkle_airdrop::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22MerkleAirdrop", &mut merkle_airdrop, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(psp22_pallet_impl));
-    impl_args.items.push(syn::Item::Impl(psp22));
+    impl_args.items.push(syn::Item::Impl(merkle_airdrop_impl));
+    impl_args.items.push(syn::Item::Impl(merkle_airdrop));
 }
 
-pub(crate) fn impl_psp22_pallet_burnable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_recovery(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let burnable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22PalletBurnableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl recovery::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22Burnable for #storage_struct_name {
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl recovery::Internal for #storage_struct_name {
+            fn _protected_balance(&self, token: &AccountId) -> Balance {
+                recovery::InternalImpl::_protected_balance(self, token)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let recovery_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22RecoveryImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut recovery = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Recovery for #storage_struct_name {
             #[ink(message)]
-            fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                PSP22PalletBurnableImpl::burn(self, account, amount)
+            fn recover_self(&mut self, to: AccountId) -> Result<(), PSP22Error> {
+                PSP22RecoveryImpl::recover_self(self, to)
+            }
+
+            #[ink(message)]
+            fn rescue_token(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22RecoveryImpl::rescue_token(self, token, to, amount)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22_pallet::extensions::burnable::*;
+        use openbrush::contracts::psp22::extensions::recovery::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22PalletBurnable", import);
+    impl_args.imports.insert("PSP22Recovery", import);
     impl_args.vec_import();
 
-    override_functions("PSP22Burnable", &mut burnable, impl_args.map);
+    override_functions("recovery::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Recovery", &mut recovery, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(burnable_impl));
-    impl_args.items.push(syn::Item::Impl(burnable));
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(recovery_impl));
+    impl_args.items.push(syn::Item::Impl(recovery));
 }
 
-pub(crate) fn impl_psp22_pallet_metadata(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_restricted(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22PalletMetadataImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl restricted::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22Metadata for #storage_struct_name {
-            #[ink(message)]
-            fn token_name(&self) -> Option<String> {
-                PSP22PalletMetadataImpl::token_name(self)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl restricted::Internal for #storage_struct_name {
+            fn _is_allowed(&self, account: &AccountId) -> bool {
+                restricted::InternalImpl::_is_allowed(self, account)
             }
 
-            #[ink(message)]
-            fn token_symbol(&self) -> Option<String> {
-                PSP22PalletMetadataImpl::token_symbol(self)
+            fn _set_allowed(&mut self, account: AccountId, allowed: bool) {
+                restricted::InternalImpl::_set_allowed(self, account, allowed)
             }
 
+            fn _check_restricted(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+                restricted::InternalImpl::_check_restricted(self, from, to)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let restricted_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22RestrictedImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut restricted = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Restricted for #storage_struct_name {
             #[ink(message)]
-            fn token_decimals(&self) -> u8 {
-                PSP22PalletMetadataImpl::token_decimals(self)
+            fn is_allowed(&self, account: AccountId) -> bool {
+                PSP22RestrictedImpl::is_allowed(self, account)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22_pallet::extensions::metadata::*;
+        use openbrush::contracts::psp22::extensions::restricted::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22PalletMetadata", import);
+    impl_args.imports.insert("PSP22Restricted", import);
     impl_args.vec_import();
 
-    override_functions("PSP22Metadata", &mut burnable, impl_args.map);
+    override_functions("restricted::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Restricted", &mut restricted, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(metadata_impl));
-    impl_args.items.push(syn::Item::Impl(burnable));
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(restricted_impl));
+    impl_args.items.push(syn::Item::Impl(restricted));
 }
 
-pub(crate) fn impl_psp22_pallet_mintable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let mintable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22PalletMintableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl wrapper::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP22Mintable for #storage_struct_name {
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl wrapper::Internal for #storage_struct_name {
+            fn _recover(&mut self, account: AccountId) -> Result<Balance, PSP22Error> {
+                wrapper::InternalImpl::_recover(self, account)
+            }
+
+            fn _deposit(&mut self, amount: Balance) -> Result<(), PSP22Error> {
+                wrapper::InternalImpl::_deposit(self, amount)
+            }
+
+            fn _withdraw(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                wrapper::InternalImpl::_withdraw(self, account, amount)
+            }
+
+            fn _underlying_balance(&mut self) -> Balance {
+                wrapper::InternalImpl::_underlying_balance(self)
+            }
+
+            fn _init(&mut self, underlying: AccountId) {
+                wrapper::InternalImpl::_init(self, underlying)
+            }
+
+            fn _underlying(&mut self) -> Option<AccountId> {
+                wrapper::InternalImpl::_underlying(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let wrapper_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22WrapperImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut wrapper = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Wrapper for #storage_struct_name {
             #[ink(message)]
-            fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
-                PSP22PalletMintableImpl::mint(self, account, amount)
+            fn deposit_for(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22WrapperImpl::deposit_for(self, account, amount)
+            }
+
+            #[ink(message)]
+            fn withdraw_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22WrapperImpl::withdraw_to(self, account, amount)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp22_pallet::extensions::mintable::*;
+        use openbrush::contracts::psp22::extensions::wrapper::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP22PalletMintable", import);
+    impl_args.imports.insert("PSP22Wrapper", import);
     impl_args.vec_import();
 
-    override_functions("PSP22Mintable", &mut mintable, impl_args.map);
+    override_functions("wrapper::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Wrapper", &mut wrapper, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(mintable_impl));
-    impl_args.items.push(syn::Item::Impl(mintable));
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(wrapper_impl));
+    impl_args.items.push(syn::Item::Impl(wrapper));
 }
 
-pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_zero_transfer(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp34::InternalImpl for #storage_struct_name {}
+        impl zero_transfer::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp34::Internal for #storage_struct_name {
-            fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
-                psp34::InternalImpl::_emit_transfer_event(self, from, to, id)
+        impl zero_transfer::Internal for #storage_struct_name {
+            fn _rejects_zero_transfers(&self) -> bool {
+                zero_transfer::InternalImpl::_rejects_zero_transfers(self)
             }
 
-            fn _emit_approval_event(&self, from: AccountId, to: AccountId, id: Option<Id>, approved: bool) {
-                psp34::InternalImpl::_emit_approval_event(self, from, to, id, approved)
+            fn _set_reject_zero_transfers(&mut self, reject: bool) {
+                zero_transfer::InternalImpl::_set_reject_zero_transfers(self, reject)
             }
 
-            fn _approve_for(&mut self, to: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_approve_for(self, to, id, approved)
+            fn _check_zero_transfer(&self, from: Option<&AccountId>, to: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                zero_transfer::InternalImpl::_check_zero_transfer(self, from, to, amount)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
-                psp34::InternalImpl::_owner_of(self, id)
-            }
+    let zero_transfer_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22ZeroTransferImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
-            fn _transfer_token(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_transfer_token(self, to, id, data)
+    let mut zero_transfer = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22ZeroTransfer for #storage_struct_name {
+            #[ink(message)]
+            fn rejects_zero_transfers(&self) -> bool {
+                PSP22ZeroTransferImpl::rejects_zero_transfers(self)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_mint_to(self, to, id)
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::zero_transfer::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22ZeroTransfer", import);
+    impl_args.vec_import();
+
+    override_functions("zero_transfer::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22ZeroTransfer", &mut zero_transfer, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(zero_transfer_impl));
+    impl_args.items.push(syn::Item::Impl(zero_transfer));
+}
+
+pub(crate) fn impl_flashmint(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl flashmint::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl flashmint::Internal for #storage_struct_name {
+            fn _get_fee(&self, amount: Balance) -> Balance {
+                flashmint::InternalImpl::_get_fee(self, amount)
             }
 
-            fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_burn_from(self, from, id)
+            fn _on_flashloan(
+                &mut self,
+                receiver_account: AccountId,
+                token: AccountId,
+                fee: Balance,
+                amount: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), FlashLenderError> {
+                flashmint::InternalImpl::_on_flashloan(self, receiver_account, token, fee, amount, data)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool {
-                psp34::InternalImpl::_allowance(self, owner, operator, id)
+    let flashlender_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl FlashLenderImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut flashlender = syn::parse2::<syn::ItemImpl>(quote!(
+        impl FlashLender for #storage_struct_name {
+            #[ink(message)]
+            fn max_flashloan(&mut self, token: AccountId) -> Balance {
+                FlashLenderImpl::max_flashloan(self, token)
             }
 
-            fn _check_token_exists(&self, id: &Id) -> Result<AccountId, PSP34Error> {
-                psp34::InternalImpl::_check_token_exists(self, id)
+            #[ink(message)]
+            fn flash_fee(&self, token: AccountId, amount: Balance) -> Result<Balance, FlashLenderError> {
+                FlashLenderImpl::flash_fee(self, token, amount)
             }
 
-            fn _before_token_transfer(
+            #[ink(message)]
+            fn flashloan(
                 &mut self,
-                from: Option<&AccountId>,
-                to: Option<&AccountId>,
-                id: &Id,
-            ) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_before_token_transfer(self, from, to, id)
+                receiver_account: AccountId,
+                token: AccountId,
+                amount: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), FlashLenderError> {
+                FlashLenderImpl::flashloan(self, receiver_account, token, amount, data)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _after_token_transfer(
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::flashmint::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Flashmint", import);
+    impl_args.vec_import();
+
+    override_functions("flashmint::Internal", &mut internal, impl_args.map);
+    override_functions("FlashLender", &mut flashlender, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(flashlender_impl));
+    impl_args.items.push(syn::Item::Impl(flashlender));
+}
+
+pub(crate) fn impl_token_timelock(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl token_timelock::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl token_timelock::Internal for #storage_struct_name {
+            fn _withdraw(&mut self, amount: Balance) -> Result<(), PSP22TokenTimelockError> {
+                token_timelock::InternalImpl::_withdraw(self, amount)
+            }
+
+            fn _contract_balance(&mut self) -> Balance {
+                token_timelock::InternalImpl::_contract_balance(self)
+            }
+
+            fn _init(
                 &mut self,
-                from: Option<&AccountId>,
-                to: Option<&AccountId>,
-                id: &Id,
-            ) -> Result<(), PSP34Error> {
-                psp34::InternalImpl::_after_token_transfer(self, from, to, id)
+                token: AccountId,
+                beneficiary: AccountId,
+                release_time: Timestamp,
+            ) -> Result<(), PSP22TokenTimelockError> {
+                token_timelock::InternalImpl::_init(self, token, beneficiary, release_time)
+            }
+
+            fn _token(&self) -> Option<AccountId> {
+                token_timelock::InternalImpl::_token(self)
+            }
+
+            fn _beneficiary(&self) -> Option<AccountId> {
+                token_timelock::InternalImpl::_beneficiary(self)
             }
         }
     ))
     .expect("Should parse");
 
-    let psp34_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34Impl for #storage_struct_name {}
+    let timelock_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22TokenTimelockImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut psp34 = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34 for #storage_struct_name {
+    let mut timelock = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22TokenTimelock for #storage_struct_name {
             #[ink(message)]
-            fn collection_id(&self) -> Id {
-                PSP34Impl::collection_id(self)
+            fn token(&self) -> Option<AccountId> {
+                PSP22TokenTimelockImpl::token(self)
             }
 
             #[ink(message)]
-            fn balance_of(&self, owner: AccountId) -> u32 {
-                PSP34Impl::balance_of(self, owner)
+            fn beneficiary(&self) -> Option<AccountId> {
+                PSP22TokenTimelockImpl::beneficiary(self)
             }
 
             #[ink(message)]
-            fn owner_of(&self, id: Id) -> Option<AccountId> {
-                PSP34Impl::owner_of(self, id)
+            fn release_time(&self) -> Timestamp {
+                PSP22TokenTimelockImpl::release_time(self)
             }
 
             #[ink(message)]
-            fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
-                PSP34Impl::allowance(self, owner, operator, id)
+            fn release(&mut self) -> Result<(), PSP22TokenTimelockError> {
+                PSP22TokenTimelockImpl::release(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::utils::token_timelock::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22TokenTimelock", import);
+
+    override_functions("token_timelock::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22TokenTimelock", &mut timelock, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(timelock_impl));
+    impl_args.items.push(syn::Item::Impl(timelock));
+}
+
+pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
+    let _storage_struct_name = impl_args.contract_name();
+    let votes_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl VotesEvents for Contract {}
+    ))
+    .expect("Should parse");
+
+    let votes_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl VotesInternal for Contract {
+            fn _get_voting_units(&self, account: &AccountId) -> Balance {
+                PSP22VotesInternal::_get_voting_units(self, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let votes_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl VotesImpl for Contract {}
+    ))
+    .expect("Should parse");
+
+    let votes = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Votes for Contract {
+            #[ink(message)]
+            fn get_votes(&self, account: AccountId) -> Balance {
+                VotesImpl::get_votes(self, account)
+            }
+
+            #[ink(message)]
+            fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance, GovernanceError> {
+                VotesImpl::get_past_votes(self, account, timestamp)
+            }
+
+            #[ink(message)]
+            fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance, GovernanceError> {
+                VotesImpl::get_past_total_supply(self, timestamp)
+            }
+
+            #[ink(message)]
+            fn delegates(&mut self, delegator: AccountId) -> Option<AccountId> {
+                VotesImpl::delegates(self, delegator)
+            }
+
+            #[ink(message)]
+            fn delegate(&mut self, delegatee: AccountId) -> Result<(), GovernanceError> {
+                VotesImpl::delegate(self, delegatee)
+            }
+
+            #[ink(message)]
+            fn delegate_by_signature(
+                &mut self,
+                signer: AccountId,
+                delegatee: AccountId,
+                nonce: u64,
+                expiry: Timestamp,
+                signature: Signature,
+            ) -> Result<(), GovernanceError> {
+                VotesImpl::delegate_by_signature(self, signer, delegatee, nonce, expiry, signature)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let psp22_votes_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22VotesImpl for Contract {}
+    ))
+    .expect("Should parse");
+
+    let psp22_votes_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22VotesInternal for Contract {}
+    ))
+    .expect("Should parse");
+
+    let psp22_votes = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Votes for Contract {
+            #[ink(message)]
+            fn num_checkpoints(&self, account: AccountId) -> u32 {
+                PSP22VotesImpl::num_checkpoints(self, account)
+            }
+
+            #[ink(message)]
+            fn checkpoints(&self, account: AccountId, pos: u32) -> Result<Checkpoint, GovernanceError> {
+                PSP22VotesImpl::checkpoints(self, account, pos)
+            }
+
+            #[ink(message)]
+            fn balance_of_at_block(&self, account: AccountId, block: BlockNumber) -> Balance {
+                PSP22VotesImpl::balance_of_at_block(self, account, block)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::{
+            governance::utils::votes::*,
+            psp22::extensions::votes::*,
+        };
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Votes", import);
+
+    impl_args.items.push(syn::Item::Impl(votes_events));
+    impl_args.items.push(syn::Item::Impl(votes_internal));
+    impl_args.items.push(syn::Item::Impl(votes_impl));
+    impl_args.items.push(syn::Item::Impl(votes));
+    impl_args.items.push(syn::Item::Impl(psp22_votes_impl));
+    impl_args.items.push(syn::Item::Impl(psp22_votes_internal));
+    impl_args.items.push(syn::Item::Impl(psp22_votes));
+}
+pub(crate) fn impl_psp22_pallet(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp22_pallet::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp22_pallet::Internal for #storage_struct_name {
+            fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _amount: Balance) {
+                psp22_pallet::InternalImpl::_emit_transfer_event(self, _from, _to, _amount)
+            }
+
+            fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance) {
+                psp22_pallet::InternalImpl::_emit_approval_event(self, _owner, _spender, _amount)
+            }
+
+            fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                psp22_pallet::InternalImpl::_mint_to(self, account, amount)
+            }
+
+            fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                psp22_pallet::InternalImpl::_burn_from(self, account, amount)
+            }
+
+            fn _create(
+                &mut self,
+                asset_id: u32,
+                admin: AccountId,
+                min_balance: Balance,
+            ) -> Result<(), Error<DefaultEnvironment>> {
+                psp22_pallet::InternalImpl::_create(self, asset_id, admin, min_balance)
+            }
+
+            fn _sender(&self) -> AccountId {
+                psp22_pallet::InternalImpl::_sender(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let psp22_pallet_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22PalletImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp22 = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22 for #storage_struct_name {
+            #[ink(message)]
+            fn total_supply(&self) -> Balance {
+                PSP22PalletImpl::total_supply(self)
+            }
+
+            #[ink(message)]
+            fn balance_of(&self, owner: AccountId) -> Balance {
+                PSP22PalletImpl::balance_of(self, owner)
+            }
+
+            #[ink(message)]
+            fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+                PSP22PalletImpl::allowance(self, owner, spender)
+            }
+
+            #[ink(message)]
+            fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+                PSP22PalletImpl::transfer(self, to, value, data)
+            }
+
+            #[ink(message)]
+            fn transfer_all(&mut self, to: AccountId, data: Vec<u8>) -> Result<Balance, PSP22Error> {
+                PSP22PalletImpl::transfer_all(self, to, data)
+            }
+
+            #[ink(message)]
+            fn transfer_from(
+                &mut self,
+                from: AccountId,
+                to: AccountId,
+                value: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                PSP22PalletImpl::transfer_from(self, from, to, value, data)
+            }
+
+            #[ink(message)]
+            fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletImpl::approve(self, spender, value)
+            }
+
+            #[ink(message)]
+            fn increase_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletImpl::increase_allowance(self, spender, delta_value)
+            }
+
+            #[ink(message)]
+            fn decrease_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletImpl::decrease_allowance(self, spender, delta_value)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22_pallet::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Pallet", import);
+    impl_args.vec_import();
+
+    override_functions("psp22_pallet::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22", &mut psp22, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(psp22_pallet_impl));
+    impl_args.items.push(syn::Item::Impl(psp22));
+}
+
+pub(crate) fn impl_psp22_pallet_burnable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl burnable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl burnable::Internal for #storage_struct_name {
+            fn _emit_burn_with_memo_event(&self, account: AccountId, amount: Balance, memo: String) {
+                burnable::InternalImpl::_emit_burn_with_memo_event(self, account, amount, memo)
+            }
+
+            fn _burn_allowance(&self, owner: &AccountId, burner: &AccountId) -> Balance {
+                burnable::InternalImpl::_burn_allowance(self, owner, burner)
+            }
+
+            fn _approve_burn(&mut self, owner: AccountId, burner: AccountId, amount: Balance) {
+                burnable::InternalImpl::_approve_burn(self, owner, burner, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let burnable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22PalletBurnableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Burnable for #storage_struct_name {
+            #[ink(message)]
+            fn burn(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletBurnableImpl::burn(self, account, amount)
+            }
+
+            #[ink(message)]
+            fn burn_with_memo(&mut self, amount: Balance, memo: String) -> Result<(), PSP22Error> {
+                PSP22PalletBurnableImpl::burn_with_memo(self, amount, memo)
+            }
+
+            #[ink(message)]
+            fn approve_burn(&mut self, burner: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletBurnableImpl::approve_burn(self, burner, amount)
+            }
+
+            #[ink(message)]
+            fn burn_allowance(&self, owner: AccountId, burner: AccountId) -> Balance {
+                PSP22PalletBurnableImpl::burn_allowance(self, owner, burner)
+            }
+
+            #[ink(message)]
+            fn burn_from_approved(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletBurnableImpl::burn_from_approved(self, account, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22_pallet::extensions::burnable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22PalletBurnable", import);
+    impl_args.vec_import();
+
+    override_functions("burnable::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Burnable", &mut burnable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(burnable_impl));
+    impl_args.items.push(syn::Item::Impl(burnable));
+}
+
+pub(crate) fn impl_psp22_pallet_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22PalletMetadataImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Metadata for #storage_struct_name {
+            #[ink(message)]
+            fn token_name(&self) -> Option<String> {
+                PSP22PalletMetadataImpl::token_name(self)
+            }
+
+            #[ink(message)]
+            fn token_symbol(&self) -> Option<String> {
+                PSP22PalletMetadataImpl::token_symbol(self)
+            }
+
+            #[ink(message)]
+            fn token_decimals(&self) -> u8 {
+                PSP22PalletMetadataImpl::token_decimals(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22_pallet::extensions::metadata::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22PalletMetadata", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22Metadata", &mut burnable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(metadata_impl));
+    impl_args.items.push(syn::Item::Impl(burnable));
+}
+
+pub(crate) fn impl_psp22_pallet_mintable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl mintable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl mintable::Internal for #storage_struct_name {
+            fn _minting_finished(&self) -> bool {
+                mintable::InternalImpl::_minting_finished(self)
+            }
+
+            fn _finish_minting(&mut self) {
+                mintable::InternalImpl::_finish_minting(self)
+            }
+
+            fn _check_minting_not_finished(&self) -> Result<(), PSP22Error> {
+                mintable::InternalImpl::_check_minting_not_finished(self)
+            }
+
+            fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error> {
+                mintable::InternalImpl::_check_minter(self, caller)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mintable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22PalletMintableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Mintable for #storage_struct_name {
+            #[ink(message)]
+            fn mint(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                PSP22PalletMintableImpl::mint(self, account, amount)
+            }
+
+            #[ink(message)]
+            fn mint_to_many(&mut self, recipients: Vec<(AccountId, Balance)>) -> Result<(), PSP22Error> {
+                PSP22PalletMintableImpl::mint_to_many(self, recipients)
+            }
+
+            #[ink(message)]
+            fn finish_minting(&mut self) -> Result<(), PSP22Error> {
+                PSP22PalletMintableImpl::finish_minting(self)
+            }
+
+            #[ink(message)]
+            fn minting_finished(&self) -> bool {
+                PSP22PalletMintableImpl::minting_finished(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22_pallet::extensions::mintable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22PalletMintable", import);
+    impl_args.vec_import();
+
+    override_functions("mintable::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Mintable", &mut mintable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(mintable_impl));
+    impl_args.items.push(syn::Item::Impl(mintable));
+}
+
+pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp34::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp34::Internal for #storage_struct_name {
+            fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
+                psp34::InternalImpl::_emit_transfer_event(self, from, to, id)
+            }
+
+            fn _emit_approval_event(&self, from: AccountId, to: AccountId, id: Option<Id>, approved: bool) {
+                psp34::InternalImpl::_emit_approval_event(self, from, to, id, approved)
+            }
+
+            fn _approve_for(&mut self, to: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_approve_for(self, to, id, approved)
+            }
+
+            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
+                psp34::InternalImpl::_owner_of(self, id)
+            }
+
+            fn _transfer_token(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_transfer_token(self, to, id, data)
+            }
+
+            fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_mint_to(self, to, id)
+            }
+
+            fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_burn_from(self, from, id)
+            }
+
+            fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool {
+                psp34::InternalImpl::_allowance(self, owner, operator, id)
+            }
+
+            fn _check_token_exists(&self, id: &Id) -> Result<AccountId, PSP34Error> {
+                psp34::InternalImpl::_check_token_exists(self, id)
+            }
+
+            fn _do_safe_transfer_check(
+                &mut self,
+                operator: &AccountId,
+                from: &AccountId,
+                to: &AccountId,
+                id: &Id,
+                data: &[u8],
+            ) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_do_safe_transfer_check(self, operator, from, to, id, data)
+            }
+
+            fn _before_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_before_token_transfer(self, from, to, id)
+            }
+
+            fn _after_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_after_token_transfer(self, from, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let psp34_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Impl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp34 = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34 for #storage_struct_name {
+            #[ink(message)]
+            fn collection_id(&self) -> Id {
+                PSP34Impl::collection_id(self)
+            }
+
+            #[ink(message)]
+            fn balance_of(&self, owner: AccountId) -> u32 {
+                PSP34Impl::balance_of(self, owner)
+            }
+
+            #[ink(message)]
+            fn owner_of(&self, id: Id) -> Option<AccountId> {
+                PSP34Impl::owner_of(self, id)
+            }
+
+            #[ink(message)]
+            fn owners_of(&self, ids: Vec<Id>) -> Result<Vec<Option<AccountId>>, PSP34Error> {
+                PSP34Impl::owners_of(self, ids)
+            }
+
+            #[ink(message)]
+            fn exists(&self, id: Id) -> bool {
+                PSP34Impl::exists(self, id)
+            }
+
+            #[ink(message)]
+            fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
+                PSP34Impl::allowance(self, owner, operator, id)
+            }
+
+            #[ink(message)]
+            fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
+                PSP34Impl::approve(self, operator, id, approved)
+            }
+
+            #[ink(message)]
+            fn approve_batch(&mut self, operator: AccountId, ids: Vec<Id>, approved: bool) -> Result<(), PSP34Error> {
+                PSP34Impl::approve_batch(self, operator, ids, approved)
+            }
+
+            #[ink(message)]
+            fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+                PSP34Impl::transfer(self, to, id, data)
+            }
+
+            #[ink(message)]
+            fn transfer_from(&mut self, from: AccountId, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+                PSP34Impl::transfer_from(self, from, to, id, data)
+            }
+
+            #[ink(message)]
+            fn total_supply(&self) -> Balance {
+                PSP34Impl::total_supply(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let psp34_balances_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp34::BalancesManagerImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp34_balances = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp34::BalancesManager for #storage_struct_name {
+            fn _balance_of(&self, owner: &Owner) -> u32 {
+                psp34::BalancesManagerImpl::_balance_of(self, owner)
+            }
+
+            fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) -> Result<(), PSP34Error> {
+                psp34::BalancesManagerImpl::_increase_balance(self, owner, id, increase_supply)
+            }
+
+            fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) -> Result<(), PSP34Error> {
+                psp34::BalancesManagerImpl::_decrease_balance(self, owner, id, decrease_supply)
+            }
+
+            fn _total_supply(&self) -> u128 {
+                psp34::BalancesManagerImpl::_total_supply(self)
+            }
+
+            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
+                psp34::BalancesManagerImpl::_owner_of(self, id)
+            }
+
+            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<()> {
+                psp34::BalancesManagerImpl::_operator_approvals(self, owner, operator, id)
+            }
+
+            fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+                psp34::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id)
+            }
+
+            fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+                psp34::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
+            }
+
+            fn _clear_token_approvals(&mut self, owner: &Owner, id: &Id) {
+                psp34::BalancesManagerImpl::_clear_token_approvals(self, owner, id)
+            }
+
+            fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
+                psp34::BalancesManagerImpl::_insert_token_owner(self, id, to)
+            }
+
+            fn _remove_token_owner(&mut self, id: &Id) {
+                psp34::BalancesManagerImpl::_remove_token_owner(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34", import);
+    impl_args.vec_import();
+
+    override_functions("psp34::BalancesManager", &mut psp34_balances, impl_args.map);
+    override_functions("psp34::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34", &mut psp34, impl_args.map);
+
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("psp34::BalancesManager")
+        .or_insert(syn::Item::Impl(psp34_balances));
+
+    impl_args
+        .overriden_traits
+        .entry("psp34::BalancesManagerImpl")
+        .or_insert(syn::Item::Impl(psp34_balances_impl));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(psp34_impl));
+    impl_args.items.push(syn::Item::Impl(psp34));
+}
+
+pub(crate) fn impl_psp34_burnable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let burnable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34BurnableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Burnable for #storage_struct_name {
+            #[ink(message)]
+            fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+                PSP34BurnableImpl::burn(self, account, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::burnable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Burnable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34Burnable", &mut burnable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(burnable_impl));
+    impl_args.items.push(syn::Item::Impl(burnable));
+}
+
+pub(crate) fn impl_psp34_attribute_index(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl attribute_index::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl attribute_index::Internal for #storage_struct_name {
+            fn _set_attribute(&mut self, id: Id, key: String, value: String) {
+                attribute_index::InternalImpl::_set_attribute(self, id, key, value)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut metadata_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl metadata::Internal for #storage_struct_name {
+            fn _emit_attribute_set_event(&self, id: Id, key: String, data: String) {
+                metadata::InternalImpl::_emit_attribute_set_event(self, id, key, data)
+            }
+
+            fn _set_attribute(&mut self, id: Id, key: String, value: String) {
+                attribute_index::Internal::_set_attribute(self, id, key, value)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let attribute_index_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34AttributeIndexImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut attribute_index = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34AttributeIndex for #storage_struct_name {
+            #[ink(message)]
+            fn tokens_with_attribute(&self, key: String, value: String, start: u128, count: u128) -> Vec<Id> {
+                PSP34AttributeIndexImpl::tokens_with_attribute(self, key, value, start, count)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::attribute_index::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34AttributeIndex", import);
+    impl_args.vec_import();
+
+    override_functions("attribute_index::Internal", &mut internal, impl_args.map);
+    override_functions("metadata::Internal", &mut metadata_internal, impl_args.map);
+    override_functions("PSP34AttributeIndex", &mut attribute_index, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("metadata::Internal", syn::Item::Impl(metadata_internal));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(attribute_index_impl));
+    impl_args.items.push(syn::Item::Impl(attribute_index));
+}
+
+pub(crate) fn impl_psp34_auto_id(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl auto_id::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl auto_id::Internal for #storage_struct_name {
+            fn _next_id(&self) -> u128 {
+                auto_id::InternalImpl::_next_id(self)
+            }
+
+            fn _set_next_id(&mut self, next_id: u128) {
+                auto_id::InternalImpl::_set_next_id(self, next_id)
+            }
+
+            fn _mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error> {
+                auto_id::InternalImpl::_mint_next(self, to)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let auto_id_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34AutoIdImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut auto_id = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34AutoId for #storage_struct_name {
+            #[ink(message)]
+            fn next_id(&self) -> u128 {
+                PSP34AutoIdImpl::next_id(self)
+            }
+
+            #[ink(message)]
+            fn mint_next(&mut self, to: AccountId) -> Result<Id, PSP34Error> {
+                PSP34AutoIdImpl::mint_next(self, to)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::auto_id::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34AutoId", import);
+    impl_args.vec_import();
+
+    override_functions("auto_id::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34AutoId", &mut auto_id, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(auto_id_impl));
+    impl_args.items.push(syn::Item::Impl(auto_id));
+}
+
+pub(crate) fn impl_psp34_capped(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::Internal for #storage_struct_name {
+            fn _init_cap(&mut self, cap: u128) -> Result<(), PSP34Error> {
+                capped::InternalImpl::_init_cap(self, cap)
+            }
+
+            fn _cap(&self) -> u128 {
+                capped::InternalImpl::_cap(self)
+            }
+
+            fn _reserved(&self) -> u128 {
+                capped::InternalImpl::_reserved(self)
+            }
+
+            fn _set_reserved(&mut self, reserved: u128) -> Result<(), PSP34Error> {
+                capped::InternalImpl::_set_reserved(self, reserved)
+            }
+
+            fn _mint_public(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+                capped::InternalImpl::_mint_public(self, to, id)
+            }
+
+            fn _mint_reserved(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+                capped::InternalImpl::_mint_reserved(self, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let capped_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34CappedImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut capped = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Capped for #storage_struct_name {
+            #[ink(message)]
+            fn cap(&self) -> u128 {
+                PSP34CappedImpl::cap(self)
+            }
+
+            #[ink(message)]
+            fn reserved(&self) -> u128 {
+                PSP34CappedImpl::reserved(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::capped::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Capped", import);
+    impl_args.vec_import();
+
+    override_functions("capped::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Capped", &mut capped, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(capped_impl));
+    impl_args.items.push(syn::Item::Impl(capped));
+}
+
+pub(crate) fn impl_psp34_evolve(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let evolve_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34EvolveImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut evolve = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Evolve for #storage_struct_name {
+            #[ink(message)]
+            fn evolve(&mut self, from_id: Id, to_id: Id) -> Result<(), PSP34Error> {
+                PSP34EvolveImpl::evolve(self, from_id, to_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::evolve::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Evolve", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34Evolve", &mut evolve, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(evolve_impl));
+    impl_args.items.push(syn::Item::Impl(evolve));
+}
+
+pub(crate) fn impl_psp34_max_balance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl max_balance::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl max_balance::Internal for #storage_struct_name {
+            fn _max_balance(&self) -> u32 {
+                max_balance::InternalImpl::_max_balance(self)
+            }
+
+            fn _set_max_balance(&mut self, max_balance: u32) {
+                max_balance::InternalImpl::_set_max_balance(self, max_balance)
+            }
+
+            fn _check_max_balance(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error> {
+                max_balance::InternalImpl::_check_max_balance(self, from, to)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let max_balance_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MaxBalanceImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut max_balance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MaxBalance for #storage_struct_name {
+            #[ink(message)]
+            fn max_balance(&self) -> u32 {
+                PSP34MaxBalanceImpl::max_balance(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::max_balance::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34MaxBalance", import);
+    impl_args.vec_import();
+
+    override_functions("max_balance::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34MaxBalance", &mut max_balance, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(max_balance_impl));
+    impl_args.items.push(syn::Item::Impl(max_balance));
+}
+
+pub(crate) fn impl_psp34_mintable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let mintable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MintableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Mintable for #storage_struct_name {
+            #[ink(message)]
+            fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+                PSP34MintableImpl::mint(self, account, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::mintable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Mintable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34Mintable", &mut mintable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(mintable_impl));
+    impl_args.items.push(syn::Item::Impl(mintable));
+}
+
+pub(crate) fn impl_psp34_provenance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl provenance::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl provenance::Internal for #storage_struct_name {
+            fn _last_transferred(&self, id: &Id) -> Option<u64> {
+                provenance::InternalImpl::_last_transferred(self, id)
+            }
+
+            fn _record_transfer(&mut self, to: Option<&AccountId>, id: &Id) {
+                provenance::InternalImpl::_record_transfer(self, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let provenance_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34ProvenanceImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut provenance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Provenance for #storage_struct_name {
+            #[ink(message)]
+            fn last_transferred(&self, id: Id) -> Option<u64> {
+                PSP34ProvenanceImpl::last_transferred(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::provenance::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Provenance", import);
+    impl_args.clock_impl();
+    impl_args.vec_import();
+
+    override_functions("provenance::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Provenance", &mut provenance, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(provenance_impl));
+    impl_args.items.push(syn::Item::Impl(provenance));
+}
+
+pub(crate) fn impl_psp34_reveal(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl reveal::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl reveal::Internal for #storage_struct_name {
+            fn _emit_revealed_event(&self) {
+                reveal::InternalImpl::_emit_revealed_event(self)
+            }
+
+            fn _is_revealed(&self) -> bool {
+                reveal::InternalImpl::_is_revealed(self)
+            }
+
+            fn _set_placeholder_uri(&mut self, placeholder_uri: String) {
+                reveal::InternalImpl::_set_placeholder_uri(self, placeholder_uri)
+            }
+
+            fn _set_base_uri(&mut self, base_uri: String) {
+                reveal::InternalImpl::_set_base_uri(self, base_uri)
+            }
+
+            fn _token_uri(&self, id: &Id) -> String {
+                reveal::InternalImpl::_token_uri(self, id)
+            }
+
+            fn _reveal(&mut self) -> Result<(), PSP34Error> {
+                reveal::InternalImpl::_reveal(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let reveal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34RevealImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut reveal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Reveal for #storage_struct_name {
+            #[ink(message)]
+            fn is_revealed(&self) -> bool {
+                PSP34RevealImpl::is_revealed(self)
+            }
+
+            #[ink(message)]
+            fn token_uri(&self, id: Id) -> Result<String, PSP34Error> {
+                PSP34RevealImpl::token_uri(self, id)
+            }
+
+            #[ink(message)]
+            fn reveal(&mut self) -> Result<(), PSP34Error> {
+                PSP34RevealImpl::reveal(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::reveal::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Reveal", import);
+    impl_args.vec_import();
+
+    override_functions("reveal::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Reveal", &mut reveal, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(reveal_impl));
+    impl_args.items.push(syn::Item::Impl(reveal));
+}
+
+pub(crate) fn impl_psp34_sale(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl sale::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl sale::Internal for #storage_struct_name {
+            fn _set_price(&mut self, price: Balance) {
+                sale::InternalImpl::_set_price(self, price)
+            }
+
+            fn _sale_recipient(&self) -> Option<AccountId> {
+                sale::InternalImpl::_sale_recipient(self)
+            }
+
+            fn _set_sale_recipient(&mut self, recipient: Option<AccountId>) {
+                sale::InternalImpl::_set_sale_recipient(self, recipient)
+            }
+
+            fn _set_refund_overpayment(&mut self, refund: bool) {
+                sale::InternalImpl::_set_refund_overpayment(self, refund)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let sale_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34SaleImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut sale = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Sale for #storage_struct_name {
+            #[ink(message)]
+            fn price(&self) -> Balance {
+                PSP34SaleImpl::price(self)
+            }
+
+            #[ink(message, payable)]
+            fn mint_sale(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+                PSP34SaleImpl::mint_sale(self, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::sale::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Sale", import);
+    impl_args.vec_import();
+
+    override_functions("sale::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Sale", &mut sale, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(sale_impl));
+    impl_args.items.push(syn::Item::Impl(sale));
+}
+
+pub(crate) fn impl_psp34_lockable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl lockable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl lockable::Internal for #storage_struct_name {
+            fn _is_locked(&self, id: &Id) -> bool {
+                lockable::InternalImpl::_is_locked(self, id)
+            }
+
+            fn _lock(&mut self, id: Id) -> Result<(), PSP34Error> {
+                lockable::InternalImpl::_lock(self, id)
+            }
+
+            fn _unlock(&mut self, id: Id) {
+                lockable::InternalImpl::_unlock(self, id)
             }
 
+            fn _check_lock(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error> {
+                lockable::InternalImpl::_check_lock(self, from, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let lockable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34LockableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut lockable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Lockable for #storage_struct_name {
             #[ink(message)]
-            fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
-                PSP34Impl::approve(self, operator, id, approved)
+            fn is_locked(&self, id: Id) -> bool {
+                PSP34LockableImpl::is_locked(self, id)
             }
 
             #[ink(message)]
-            fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
-                PSP34Impl::transfer(self, to, id, data)
+            fn lock(&mut self, id: Id) -> Result<(), PSP34Error> {
+                PSP34LockableImpl::lock(self, id)
             }
 
             #[ink(message)]
-            fn total_supply(&self) -> Balance {
-                PSP34Impl::total_supply(self)
+            fn unlock(&mut self, id: Id) -> Result<(), PSP34Error> {
+                PSP34LockableImpl::unlock(self, id)
             }
         }
     ))
     .expect("Should parse");
 
-    let psp34_balances_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp34::BalancesManagerImpl for #storage_struct_name {}
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::lockable::*;
     ))
     .expect("Should parse");
+    impl_args.imports.insert("PSP34Lockable", import);
+    impl_args.vec_import();
 
-    let mut psp34_balances = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp34::BalancesManager for #storage_struct_name {
-            fn _balance_of(&self, owner: &Owner) -> u32 {
-                psp34::BalancesManagerImpl::_balance_of(self, owner)
-            }
-
-            fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) {
-                psp34::BalancesManagerImpl::_increase_balance(self, owner, id, increase_supply)
-            }
+    override_functions("lockable::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Lockable", &mut lockable, impl_args.map);
 
-            fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) {
-                psp34::BalancesManagerImpl::_decrease_balance(self, owner, id, decrease_supply)
-            }
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(lockable_impl));
+    impl_args.items.push(syn::Item::Impl(lockable));
+}
 
-            fn _total_supply(&self) -> u128 {
-                psp34::BalancesManagerImpl::_total_supply(self)
-            }
+pub(crate) fn impl_psp34_token_freeze(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl token_freeze::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
-            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
-                psp34::BalancesManagerImpl::_owner_of(self, id)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl token_freeze::Internal for #storage_struct_name {
+            fn _is_token_frozen(&self, id: &Id) -> bool {
+                token_freeze::InternalImpl::_is_token_frozen(self, id)
             }
 
-            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<()> {
-                psp34::BalancesManagerImpl::_operator_approvals(self, owner, operator, id)
+            fn _freeze_token(&mut self, id: Id) {
+                token_freeze::InternalImpl::_freeze_token(self, id)
             }
 
-            fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
-                psp34::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id)
+            fn _unfreeze_token(&mut self, id: Id) {
+                token_freeze::InternalImpl::_unfreeze_token(self, id)
             }
 
-            fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
-                psp34::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
+            fn _check_token_freeze(&self, from: Option<&AccountId>, id: &Id) -> Result<(), PSP34Error> {
+                token_freeze::InternalImpl::_check_token_freeze(self, from, id)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
-                psp34::BalancesManagerImpl::_insert_token_owner(self, id, to)
-            }
+    let token_freeze_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34TokenFreezeImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
-            fn _remove_token_owner(&mut self, id: &Id) {
-                psp34::BalancesManagerImpl::_remove_token_owner(self, id)
+    let mut token_freeze = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34TokenFreeze for #storage_struct_name {
+            #[ink(message)]
+            fn is_token_frozen(&self, id: Id) -> bool {
+                PSP34TokenFreezeImpl::is_token_frozen(self, id)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp34::*;
+        use openbrush::contracts::psp34::extensions::token_freeze::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP34", import);
+    impl_args.imports.insert("PSP34TokenFreeze", import);
     impl_args.vec_import();
 
-    override_functions("psp34::BalancesManager", &mut psp34_balances, impl_args.map);
-    override_functions("psp34::Internal", &mut internal, impl_args.map);
-    override_functions("PSP34", &mut psp34, impl_args.map);
-
-    // only insert this if it is not present
-    impl_args
-        .overriden_traits
-        .entry("psp34::BalancesManager")
-        .or_insert(syn::Item::Impl(psp34_balances));
-
-    impl_args
-        .overriden_traits
-        .entry("psp34::BalancesManagerImpl")
-        .or_insert(syn::Item::Impl(psp34_balances_impl));
+    override_functions("token_freeze::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34TokenFreeze", &mut token_freeze, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(psp34_impl));
-    impl_args.items.push(syn::Item::Impl(psp34));
+    impl_args.items.push(syn::Item::Impl(token_freeze_impl));
+    impl_args.items.push(syn::Item::Impl(token_freeze));
 }
 
-pub(crate) fn impl_psp34_burnable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp34_transfer_lock(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let burnable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34BurnableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl transfer_lock::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34Burnable for #storage_struct_name {
-            #[ink(message)]
-            fn burn(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
-                PSP34BurnableImpl::burn(self, account, id)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl transfer_lock::Internal for #storage_struct_name {
+            fn _unlock_time(&self) -> u64 {
+                transfer_lock::InternalImpl::_unlock_time(self)
             }
-        }
-    ))
-    .expect("Should parse");
 
-    let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp34::extensions::burnable::*;
+            fn _set_unlock_time(&mut self, unlock_time: u64) {
+                transfer_lock::InternalImpl::_set_unlock_time(self, unlock_time)
+            }
+
+            fn _check_transfer_lock(&self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP34Error> {
+                transfer_lock::InternalImpl::_check_transfer_lock(self, from, to)
+            }
+        }
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP34Burnable", import);
-    impl_args.vec_import();
-
-    override_functions("PSP34Burnable", &mut burnable, impl_args.map);
-
-    impl_args.items.push(syn::Item::Impl(burnable_impl));
-    impl_args.items.push(syn::Item::Impl(burnable));
-}
 
-pub(crate) fn impl_psp34_mintable(impl_args: &mut ImplArgs) {
-    let storage_struct_name = impl_args.contract_name();
-    let mintable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34MintableImpl for #storage_struct_name {}
+    let transfer_lock_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34TransferLockImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34Mintable for #storage_struct_name {
+    let mut transfer_lock = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34TransferLock for #storage_struct_name {
             #[ink(message)]
-            fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
-                PSP34MintableImpl::mint(self, account, id)
+            fn unlock_time(&self) -> u64 {
+                PSP34TransferLockImpl::unlock_time(self)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp34::extensions::mintable::*;
+        use openbrush::contracts::psp34::extensions::transfer_lock::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP34Mintable", import);
+    impl_args.imports.insert("PSP34TransferLock", import);
+    impl_args.clock_impl();
     impl_args.vec_import();
 
-    override_functions("PSP34Mintable", &mut mintable, impl_args.map);
+    override_functions("transfer_lock::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34TransferLock", &mut transfer_lock, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(mintable_impl));
-    impl_args.items.push(syn::Item::Impl(mintable));
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transfer_lock_impl));
+    impl_args.items.push(syn::Item::Impl(transfer_lock));
 }
 
 pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
@@ -1256,6 +2873,16 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
             fn get_attribute(&self, id: Id, key: String) -> Option<String> {
                 PSP34MetadataImpl::get_attribute(self, id, key)
             }
+
+            #[ink(message)]
+            fn get_attribute_count(&self, id: Id) -> u32 {
+                PSP34MetadataImpl::get_attribute_count(self, id)
+            }
+
+            #[ink(message)]
+            fn get_attribute_name(&self, id: Id, index: u32) -> Option<String> {
+                PSP34MetadataImpl::get_attribute_name(self, id, index)
+            }
         }
     ))
     .expect("Should parse");
@@ -1270,14 +2897,111 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
     override_functions("metadata::Internal", &mut internal, impl_args.map);
     override_functions("PSP34Mintable", &mut metadata, impl_args.map);
 
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("metadata::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(metadata_impl));
     impl_args.items.push(syn::Item::Impl(metadata));
 }
 
+pub(crate) fn impl_psp34_metadata_admin(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let metadata_admin_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MetadataAdminImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut metadata_admin = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MetadataAdmin for #storage_struct_name {
+            #[ink(message)]
+            fn set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), PSP34Error> {
+                PSP34MetadataAdminImpl::set_attribute(self, id, key, value)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::metadata_admin::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34MetadataAdmin", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34MetadataAdmin", &mut metadata_admin, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(metadata_admin_impl));
+    impl_args.items.push(syn::Item::Impl(metadata_admin));
+}
+
+pub(crate) fn impl_psp34_mintable_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let mintable_metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MintableMetadataImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut mintable_metadata = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34MintableMetadata for #storage_struct_name {
+            #[ink(message)]
+            fn mint_with_attributes(
+                &mut self,
+                account: AccountId,
+                id: Id,
+                attributes: Vec<(String, String)>,
+            ) -> Result<(), PSP34Error> {
+                PSP34MintableMetadataImpl::mint_with_attributes(self, account, id, attributes)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::mintable_metadata::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34MintableMetadata", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34MintableMetadata", &mut mintable_metadata, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(mintable_metadata_impl));
+    impl_args.items.push(syn::Item::Impl(mintable_metadata));
+}
+
 pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::Internal for #storage_struct_name {
+            fn _init_sequential_supply(&mut self, first_id: u128) -> Result<(), PSP34Error> {
+                enumerable::InternalImpl::_init_sequential_supply(self, first_id)
+            }
+
+            fn _is_sequential_supply(&self) -> bool {
+                enumerable::InternalImpl::_is_sequential_supply(self)
+            }
+
+            fn _check_sequential_supply(
+                &self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error> {
+                enumerable::InternalImpl::_check_sequential_supply(self, from, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
     let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP34EnumerableImpl for #storage_struct_name {}
     ))
@@ -1310,11 +3034,11 @@ pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
                 enumerable::BalancesManagerImpl::_balance_of(self, owner)
             }
 
-            fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) {
+            fn _increase_balance(&mut self, owner: &Owner, id: &Id, increase_supply: bool) -> Result<(), PSP34Error> {
                 enumerable::BalancesManagerImpl::_increase_balance(self, owner, id, increase_supply)
             }
 
-            fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) {
+            fn _decrease_balance(&mut self, owner: &Owner, id: &Id, decrease_supply: bool) -> Result<(), PSP34Error> {
                 enumerable::BalancesManagerImpl::_decrease_balance(self, owner, id, decrease_supply)
             }
 
@@ -1338,6 +3062,10 @@ pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
                 enumerable::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
             }
 
+            fn _clear_token_approvals(&mut self, owner: &Owner, id: &Id) {
+                enumerable::BalancesManagerImpl::_clear_token_approvals(self, owner, id)
+            }
+
             fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
                 enumerable::BalancesManagerImpl::_insert_token_owner(self, id, to)
             }
@@ -1356,6 +3084,7 @@ pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("PSP34Enumerable", import);
     impl_args.vec_import();
 
+    override_functions("enumerable::Internal", &mut internal, impl_args.map);
     override_functions("psp34::BalancesManager", &mut psp34_balances, impl_args.map);
     override_functions("PSP34Enumerable", &mut psp34_enumerable, impl_args.map);
 
@@ -1366,6 +3095,8 @@ pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
         .overriden_traits
         .insert("psp34::BalancesManagerImpl", syn::Item::Impl(psp34_balances_impl));
 
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(enumerable_impl));
     impl_args.items.push(syn::Item::Impl(psp34_enumerable));
 }
@@ -1396,6 +3127,10 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
                 psp37::InternalImpl::_emit_approval_event(self, owner, operator, id, value)
             }
 
+            fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool) {
+                psp37::InternalImpl::_emit_approval_for_all_event(self, owner, operator, approved)
+            }
+
             fn _mint_to(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
                 psp37::InternalImpl::_mint_to(self, to, ids_amounts)
             }
@@ -1423,6 +3158,10 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
                 psp37::InternalImpl::_approve_for(self, operator, id, value)
             }
 
+            fn _revoke_all_operators(&mut self) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_revoke_all_operators(self)
+            }
+
             fn _decrease_allowance(
                 &mut self,
                 owner: &AccountId,
@@ -1478,6 +3217,11 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
                 PSP37Impl::balance_of(self, owner, id)
             }
 
+            #[ink(message)]
+            fn balance_of_batch(&self, accounts_ids: Vec<(AccountId, Id)>) -> Result<Vec<Balance>, PSP37Error> {
+                PSP37Impl::balance_of_batch(self, accounts_ids)
+            }
+
             #[ink(message)]
             fn total_supply(&self, id: Option<Id>) -> Balance {
                 PSP37Impl::total_supply(self, id)
@@ -1493,6 +3237,11 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
                 PSP37Impl::approve(self, operator, id, value)
             }
 
+            #[ink(message)]
+            fn revoke_all_operators(&mut self) -> Result<(), PSP37Error> {
+                PSP37Impl::revoke_all_operators(self)
+            }
+
             #[ink(message)]
             fn transfer(&mut self, to: AccountId, id: Id, value: Balance, data: Vec<u8>) -> Result<(), PSP37Error> {
                 PSP37Impl::transfer(self, to, id, value, data)
@@ -1695,59 +3444,115 @@ pub(crate) fn impl_psp37_burnable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(burnable));
 }
 
-pub(crate) fn impl_psp37_metadata(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp37_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl metadata::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl metadata::Internal for #storage_struct_name {
+            fn _emit_attribute_set_event(&self, id: &Id, key: &String, data: &String) {
+                metadata::InternalImpl::_emit_attribute_set_event(self, id, key, data);
+            }
+
+            fn _set_attribute(&mut self, id: &Id, key: &String, data: &String) -> Result<(), PSP37Error> {
+                metadata::InternalImpl::_set_attribute(self, id, key, data)
+            }
+
+            fn _get_attribute(&self, id: &Id, key: &String) -> Option<String> {
+                metadata::InternalImpl::_get_attribute(self, id, key)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37MetadataImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut metadata = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37Metadata for #storage_struct_name {
+            #[ink(message)]
+            fn get_attribute(&self, id: Id, key: String) -> Option<String> {
+                PSP37MetadataImpl::get_attribute(self, id, key)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp37::extensions::metadata::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP37Metadata", import);
+    impl_args.vec_import();
+
+    override_functions("metadata::Internal", &mut internal, impl_args.map);
+    override_functions("PSP37Metadata", &mut metadata, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(metadata_impl));
+    impl_args.items.push(syn::Item::Impl(metadata));
+}
+
+pub(crate) fn impl_psp37_metadata_named(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl metadata::InternalImpl for #storage_struct_name {}
+        impl metadata_named::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl metadata::Internal for #storage_struct_name {
-            fn _emit_attribute_set_event(&self, id: &Id, key: &String, data: &String) {
-                metadata::InternalImpl::_emit_attribute_set_event(self, id, key, data);
-            }
-
-            fn _set_attribute(&mut self, id: &Id, key: &String, data: &String) -> Result<(), PSP37Error> {
-                metadata::InternalImpl::_set_attribute(self, id, key, data)
+        impl metadata_named::Internal for #storage_struct_name {
+            fn _set_token_name(&mut self, id: &Id, name: &String) {
+                metadata_named::InternalImpl::_set_token_name(self, id, name)
             }
 
-            fn _get_attribute(&self, id: &Id, key: &String) -> Option<String> {
-                metadata::InternalImpl::_get_attribute(self, id, key)
+            fn _set_token_symbol(&mut self, id: &Id, symbol: &String) {
+                metadata_named::InternalImpl::_set_token_symbol(self, id, symbol)
             }
         }
     ))
     .expect("Should parse");
 
-    let metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP37MetadataImpl for #storage_struct_name {}
+    let metadata_named_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37MetadataNamedImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut metadata = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP37Metadata for #storage_struct_name {
+    let mut metadata_named = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37MetadataNamed for #storage_struct_name {
             #[ink(message)]
-            fn get_attribute(&self, id: Id, key: String) -> Option<String> {
-                PSP37MetadataImpl::get_attribute(self, id, key)
+            fn token_name(&self, id: Id) -> Option<String> {
+                PSP37MetadataNamedImpl::token_name(self, id)
+            }
+
+            #[ink(message)]
+            fn token_symbol(&self, id: Id) -> Option<String> {
+                PSP37MetadataNamedImpl::token_symbol(self, id)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::psp37::extensions::metadata::*;
+        use openbrush::contracts::psp37::extensions::metadata_named::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("PSP37Metadata", import);
+    impl_args.imports.insert("PSP37MetadataNamed", import);
     impl_args.vec_import();
 
-    override_functions("metadata::Internal", &mut internal, impl_args.map);
-    override_functions("PSP37Metadata", &mut metadata, impl_args.map);
+    override_functions("metadata_named::Internal", &mut internal, impl_args.map);
+    override_functions("PSP37MetadataNamed", &mut metadata_named, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(metadata_impl));
-    impl_args.items.push(syn::Item::Impl(metadata));
+    impl_args.items.push(syn::Item::Impl(metadata_named_impl));
+    impl_args.items.push(syn::Item::Impl(metadata_named));
 }
 
 pub(crate) fn impl_psp37_mintable(impl_args: &mut ImplArgs) {
@@ -2065,6 +3870,10 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
                 <Self as access_control::InternalImpl>::_default_admin()
             }
 
+            fn _default_admin_role(&self) -> RoleType {
+                access_control::InternalImpl::_default_admin_role(self)
+            }
+
             fn _init_with_caller(&mut self) {
                 access_control::InternalImpl::_init_with_caller(self);
             }
@@ -2073,6 +3882,10 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
                 access_control::InternalImpl::_init_with_admin(self, admin);
             }
 
+            fn _init_with_admin_role(&mut self, admin: Option<AccountId>, admin_role: RoleType) {
+                access_control::InternalImpl::_init_with_admin_role(self, admin, admin_role);
+            }
+
             fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>) {
                 access_control::InternalImpl::_setup_role(self, role, member);
             }
@@ -2115,7 +3928,271 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
 
             #[ink(message)]
             fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                AccessControlImpl::grant_role(self, role, account)
+                AccessControlImpl::grant_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlImpl::revoke_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlImpl::renounce_role(self, role, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::MembersManagerImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::MembersManager for #storage_struct_name {
+            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
+                access_control::MembersManagerImpl::_has_role(self, role, address)
+            }
+
+            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
+                access_control::MembersManagerImpl::_add(self, role, member)
+            }
+
+            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
+                access_control::MembersManagerImpl::_remove(self, role, member)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
+                access_control::MembersManagerImpl::_get_role_admin(self, role)
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                access_control::MembersManagerImpl::_set_role_admin(self, role, new_admin)
+            }
+
+            fn _get_default_admin_role(&self) -> Option<RoleType> {
+                access_control::MembersManagerImpl::_get_default_admin_role(self)
+            }
+
+            fn _set_default_admin_role(&mut self, role: RoleType) {
+                access_control::MembersManagerImpl::_set_default_admin_role(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::access_control::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AccessControl", import);
+
+    override_functions("access_control::MembersManager", &mut members, impl_args.map);
+    override_functions("access_control::Internal", &mut internal, impl_args.map);
+    override_functions("AccessControl", &mut access_control, impl_args.map);
+
+    // only insert these if it is not present
+    impl_args
+        .overriden_traits
+        .entry("access_control::MembersManagerImpl")
+        .or_insert(syn::Item::Impl(members_impl));
+
+    impl_args
+        .overriden_traits
+        .entry("access_control::MembersManager")
+        .or_insert(syn::Item::Impl(members));
+
+    impl_args
+        .overriden_traits
+        .entry("AccessControl")
+        .or_insert(syn::Item::Impl(access_control));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(access_control_impl));
+}
+
+pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::Internal for #storage_struct_name {
+            fn _init_admin_if_empty(&mut self, account: Option<AccountId>) {
+                enumerable::InternalImpl::_init_admin_if_empty(self, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlEnumerableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlEnumerable for #storage_struct_name {
+            #[ink(message)]
+            fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
+                AccessControlEnumerableImpl::get_role_member(self, role, index)
+            }
+
+            #[ink(message)]
+            fn get_role_member_count(&self, role: RoleType) -> u32 {
+                AccessControlEnumerableImpl::get_role_member_count(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::MembersManagerImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::MembersManager for #storage_struct_name {
+            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
+                enumerable::MembersManagerImpl::_has_role(self, role, address)
+            }
+
+            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
+                enumerable::MembersManagerImpl::_add(self, role, member)
+            }
+
+            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
+                enumerable::MembersManagerImpl::_remove(self, role, member)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
+                enumerable::MembersManagerImpl::_get_role_admin(self, role)
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                enumerable::MembersManagerImpl::_set_role_admin(self, role, new_admin)
+            }
+
+            fn _get_default_admin_role(&self) -> Option<RoleType> {
+                enumerable::MembersManagerImpl::_get_default_admin_role(self)
+            }
+
+            fn _set_default_admin_role(&mut self, role: RoleType) {
+                enumerable::MembersManagerImpl::_set_default_admin_role(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::access_control::extensions::enumerable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AccessControlEnumerable", import);
+
+    override_functions("enumerable::Internal", &mut internal, impl_args.map);
+    override_functions("access_control::MembersManager", &mut members, impl_args.map);
+    override_functions("AccessControlEnumerable", &mut enumerable, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("access_control::MembersManagerImpl", syn::Item::Impl(members_impl));
+    impl_args
+        .overriden_traits
+        .insert("access_control::MembersManager", syn::Item::Impl(members));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(enumerable_impl));
+    impl_args.items.push(syn::Item::Impl(enumerable));
+}
+
+pub(crate) fn impl_access_control_bootstrap(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl bootstrap::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl bootstrap::Internal for #storage_struct_name {
+            fn _bootstrap_access(
+                &mut self,
+                owner: AccountId,
+                admins: Vec<AccountId>,
+                roles: Vec<(RoleType, AccountId)>,
+            ) {
+                bootstrap::InternalImpl::_bootstrap_access(self, owner, admins, roles)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::access_control::extensions::bootstrap::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AccessControlBootstrap", import);
+    impl_args.vec_import();
+
+    override_functions("bootstrap::Internal", &mut internal, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+}
+
+pub(crate) fn impl_access_control_timelocked_grant(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl timelocked_grant::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl timelocked_grant::Internal for #storage_struct_name {
+            fn _emit_grant_scheduled(&self, role: RoleType, account: Option<AccountId>, ready_at: Timestamp) {
+                timelocked_grant::InternalImpl::_emit_grant_scheduled(self, role, account, ready_at)
+            }
+
+            fn _emit_grant_cancelled(&self, role: RoleType, account: Option<AccountId>) {
+                timelocked_grant::InternalImpl::_emit_grant_cancelled(self, role, account)
+            }
+
+            fn _grant_delay(&self) -> Timestamp {
+                timelocked_grant::InternalImpl::_grant_delay(self)
+            }
+
+            fn _set_grant_delay(&mut self, delay: Timestamp) {
+                timelocked_grant::InternalImpl::_set_grant_delay(self, delay)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let timelocked_grant_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlTimelockedGrantImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    // `grant_role` can't skip the delay of the scheduled grants
+    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControl for #storage_struct_name {
+            #[ink(message)]
+            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
+                AccessControlImpl::has_role(self, role, address)
+            }
+
+            #[ink(message)]
+            fn get_role_admin(&self, role: RoleType) -> RoleType {
+                AccessControlImpl::get_role_admin(self, role)
+            }
+
+            #[ink(message)]
+            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlTimelockedGrantImpl::grant_role(self, role, account)
             }
 
             #[ink(message)]
@@ -2131,133 +4208,55 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl access_control::MembersManagerImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
-    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
-        impl access_control::MembersManager for #storage_struct_name {
-            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-                access_control::MembersManagerImpl::_has_role(self, role, address)
+    let mut timelocked_grant = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlTimelockedGrant for #storage_struct_name {
+            #[ink(message)]
+            fn grant_delay(&self) -> Timestamp {
+                AccessControlTimelockedGrantImpl::grant_delay(self)
             }
 
-            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
-                access_control::MembersManagerImpl::_add(self, role, member)
+            #[ink(message)]
+            fn grant_ready_at(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp> {
+                AccessControlTimelockedGrantImpl::grant_ready_at(self, role, account)
             }
 
-            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
-                access_control::MembersManagerImpl::_remove(self, role, member)
+            #[ink(message)]
+            fn schedule_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlTimelockedGrantImpl::schedule_grant(self, role, account)
             }
 
-            fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
-                access_control::MembersManagerImpl::_get_role_admin(self, role)
+            #[ink(message)]
+            fn execute_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlTimelockedGrantImpl::execute_grant(self, role, account)
             }
 
-            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
-                access_control::MembersManagerImpl::_set_role_admin(self, role, new_admin)
+            #[ink(message)]
+            fn cancel_grant(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlTimelockedGrantImpl::cancel_grant(self, role, account)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::access_control::*;
+        use openbrush::contracts::access_control::extensions::timelocked_grant::*;
     ))
     .expect("Should parse");
-    impl_args.imports.insert("AccessControl", import);
+    impl_args.imports.insert("AccessControlTimelockedGrant", import);
+    impl_args.clock_impl();
 
-    override_functions("access_control::MembersManager", &mut members, impl_args.map);
-    override_functions("access_control::Internal", &mut internal, impl_args.map);
+    override_functions("timelocked_grant::Internal", &mut internal, impl_args.map);
+    override_functions("AccessControlTimelockedGrant", &mut timelocked_grant, impl_args.map);
     override_functions("AccessControl", &mut access_control, impl_args.map);
 
-    // only insert these if it is not present
-    impl_args
-        .overriden_traits
-        .entry("access_control::MembersManagerImpl")
-        .or_insert(syn::Item::Impl(members_impl));
-
     impl_args
         .overriden_traits
-        .entry("access_control::MembersManager")
-        .or_insert(syn::Item::Impl(members));
+        .insert("AccessControl", syn::Item::Impl(access_control));
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(access_control_impl));
-    impl_args.items.push(syn::Item::Impl(access_control));
-}
-
-pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
-    let storage_struct_name = impl_args.contract_name();
-    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl AccessControlEnumerableImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
-    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl AccessControlEnumerable for #storage_struct_name {
-            #[ink(message)]
-            fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
-                AccessControlEnumerableImpl::get_role_member(self, role, index)
-            }
-
-            #[ink(message)]
-            fn get_role_member_count(&self, role: RoleType) -> u32 {
-                AccessControlEnumerableImpl::get_role_member_count(self, role)
-            }
-        }
-    ))
-    .expect("Should parse");
-
-    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl enumerable::MembersManagerImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
-    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
-        impl access_control::MembersManager for #storage_struct_name {
-            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-                enumerable::MembersManagerImpl::_has_role(self, role, address)
-            }
-
-            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
-                enumerable::MembersManagerImpl::_add(self, role, member)
-            }
-
-            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
-                enumerable::MembersManagerImpl::_remove(self, role, member)
-            }
-
-            fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
-                enumerable::MembersManagerImpl::_get_role_admin(self, role)
-            }
-
-            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
-                enumerable::MembersManagerImpl::_set_role_admin(self, role, new_admin)
-            }
-        }
-    ))
-    .expect("Should parse");
-
-    let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::access_control::extensions::enumerable::*;
-    ))
-    .expect("Should parse");
-    impl_args.imports.insert("AccessControlEnumerable", import);
-
-    override_functions("access_control::MembersManager", &mut members, impl_args.map);
-    override_functions("AccessControlEnumerable", &mut enumerable, impl_args.map);
-
-    impl_args
-        .overriden_traits
-        .insert("access_control::MembersManagerImpl", syn::Item::Impl(members_impl));
-    impl_args
-        .overriden_traits
-        .insert("access_control::MembersManager", syn::Item::Impl(members));
-
-    impl_args.items.push(syn::Item::Impl(enumerable_impl));
-    impl_args.items.push(syn::Item::Impl(enumerable));
+    impl_args.items.push(syn::Item::Impl(timelocked_grant_impl));
+    impl_args.items.push(syn::Item::Impl(timelocked_grant));
 }
 
 pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
@@ -2292,6 +4291,30 @@ pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
             fn _switch_pause(&mut self) -> Result<(), PausableError> {
                 pausable::InternalImpl::_switch_pause(self)
             }
+
+            fn _emit_scope_paused_event(&self, account: AccountId, scope: PauseScope) {
+                pausable::InternalImpl::_emit_scope_paused_event(self, account, scope)
+            }
+
+            fn _emit_scope_unpaused_event(&self, account: AccountId, scope: PauseScope) {
+                pausable::InternalImpl::_emit_scope_unpaused_event(self, account, scope)
+            }
+
+            fn _scope_paused(&self, scope: PauseScope) -> bool {
+                pausable::InternalImpl::_scope_paused(self, scope)
+            }
+
+            fn _pause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError> {
+                pausable::InternalImpl::_pause_scope(self, scope)
+            }
+
+            fn _unpause_scope(&mut self, scope: PauseScope) -> Result<(), PausableError> {
+                pausable::InternalImpl::_unpause_scope(self, scope)
+            }
+
+            fn _ensure_not_paused(&self, scope: PauseScope) -> Result<(), PausableError> {
+                pausable::InternalImpl::_ensure_not_paused(self, scope)
+            }
         }
     ))
     .expect("Should parse");
@@ -2952,6 +4975,96 @@ pub(crate) fn impl_governor_votes(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(governor_votes_internal));
 }
 
+pub(crate) fn impl_governor_timelock(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let governor_timelock_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockInternal for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut governor_timelock_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockEvents for #storage_struct_name {
+            fn emit_proposal_queued(&self, _proposal_id: ProposalId, _eta: Timestamp) {}
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_timelock_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    // Routes the state and the execution of the succeeded proposals through the timelock
+    let mut governor_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorInternal for #storage_struct_name {
+            fn _succeeded_state(&self, proposal_id: ProposalId) -> ProposalState {
+                GovernorTimelockInternal::_timelock_state(self, proposal_id)
+            }
+
+            fn _execute(
+                &mut self,
+                transactions: Vec<Transaction>,
+                description_hash: HashType,
+            ) -> Result<(), GovernanceError> {
+                if GovernorTimelockInternal::_timelock(self).is_none() {
+                    return GovernorInternal::_execute_calls(self, transactions)
+                }
+
+                let proposal_id = GovernorInternal::_hash_proposal(self, transactions.clone(), description_hash.clone())?;
+                GovernorTimelockInternal::_timelock_execute(self, proposal_id, transactions, description_hash)
+            }
+
+            fn _cancel_queued(&mut self, proposal_id: ProposalId) -> Result<(), GovernanceError> {
+                GovernorTimelockInternal::_timelock_cancel(self, proposal_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut governor_timelock = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelock for #storage_struct_name {
+            #[ink(message)]
+            fn timelock(&self) -> Option<AccountId> {
+                GovernorTimelockImpl::timelock(self)
+            }
+
+            #[ink(message)]
+            fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+                GovernorTimelockImpl::proposal_eta(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn queue(
+                &mut self,
+                transactions: Vec<Transaction>,
+                description_hash: HashType,
+            ) -> Result<ProposalId, GovernanceError> {
+                GovernorTimelockImpl::queue(self, transactions, description_hash)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::governor_timelock::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GovernorTimelock", import);
+
+    override_functions("GovernorTimelockEvents", &mut governor_timelock_events, impl_args.map);
+    override_functions("GovernorTimelock", &mut governor_timelock, impl_args.map);
+    override_functions("GovernorInternal", &mut governor_internal, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("GovernorInternal", syn::Item::Impl(governor_internal));
+
+    impl_args.items.push(syn::Item::Impl(governor_timelock_internal));
+    impl_args.items.push(syn::Item::Impl(governor_timelock_events));
+    impl_args.items.push(syn::Item::Impl(governor_timelock_impl));
+    impl_args.items.push(syn::Item::Impl(governor_timelock));
+}
+
 pub(crate) fn impl_governor_quorum(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let quorum_events = syn::parse2::<syn::ItemImpl>(quote!(
@@ -3031,6 +5144,11 @@ pub(crate) fn impl_governor_counting(impl_args: &mut ImplArgs) {
             fn proposal_votes(&self, proposal_id: ProposalId) -> Result<ProposalVote, GovernanceError> {
                 GovernorCountingImpl::proposal_votes(self, proposal_id)
             }
+
+            #[ink(message)]
+            fn abstain_counts_toward_quorum(&self) -> bool {
+                GovernorCountingImpl::abstain_counts_toward_quorum(self)
+            }
         }
     ))
     .expect("Should parse");
@@ -3053,13 +5171,65 @@ pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let governor_internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl GovernorInternal for #storage_struct_name {}
+    let mut governor_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorInternal for #storage_struct_name {
+            fn _succeeded_state(&self, _proposal_id: ProposalId) -> ProposalState {
+                ProposalState::Succeeded
+            }
+
+            fn _execute(
+                &mut self,
+                transactions: Vec<Transaction>,
+                _description_hash: HashType,
+            ) -> Result<(), GovernanceError> {
+                GovernorInternal::_execute_calls(self, transactions)
+            }
+
+            fn _cancel_queued(&mut self, _proposal_id: ProposalId) -> Result<(), GovernanceError> {
+                Ok(())
+            }
+        }
     ))
     .expect("Should parse");
 
-    let governor_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl GovernorEvents for #storage_struct_name {}
+    let mut governor_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorEvents for #storage_struct_name {
+            fn emit_proposal_created(
+                &self,
+                _proposal_id: ProposalId,
+                _proposer: AccountId,
+                _transactions: Vec<Transaction>,
+                _vote_start: Timestamp,
+                _vote_end: Timestamp,
+                _description: String,
+            ) {
+            }
+
+            fn emit_proposal_canceled(&self, _proposal_id: ProposalId) {}
+
+            fn emit_proposal_executed(&self, _proposal_id: ProposalId) {}
+
+            fn emit_vote_cast(
+                &self,
+                _proposal_id: ProposalId,
+                _voter: AccountId,
+                _support: VoteType,
+                _weight: Balance,
+                _reason: String,
+            ) {
+            }
+
+            fn emit_vote_cast_with_params(
+                &self,
+                _proposal_id: ProposalId,
+                _voter: AccountId,
+                _support: VoteType,
+                _weight: Balance,
+                _reason: String,
+                _params: Vec<u8>,
+            ) {
+            }
+        }
     ))
     .expect("Should parse");
 
@@ -3136,11 +5306,36 @@ pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
                 GovernorImpl::cancel(self, transactions, description_hash)
             }
 
+            #[ink(message)]
+            fn guardian(&self) -> Option<AccountId> {
+                GovernorImpl::guardian(self)
+            }
+
             #[ink(message)]
             fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType, reason: Option<String>, params: Option<Vec<u8>>) -> Result<Balance, GovernanceError> {
                 GovernorImpl::cast_vote(self, proposal_id, support, reason, params)
             }
 
+            #[ink(message)]
+            fn cast_vote_with_reason(
+                &mut self,
+                proposal_id: ProposalId,
+                support: VoteType,
+                reason: String,
+            ) -> Result<Balance, GovernanceError> {
+                GovernorImpl::cast_vote_with_reason(self, proposal_id, support, reason)
+            }
+
+            #[ink(message)]
+            fn cast_vote_by_sig(
+                &mut self,
+                proposal_id: ProposalId,
+                support: VoteType,
+                signature: Signature,
+            ) -> Result<Balance, GovernanceError> {
+                GovernorImpl::cast_vote_by_sig(self, proposal_id, support, signature)
+            }
+
             #[ink(message)]
             fn cast_vote_with_signature(
                 &mut self,
@@ -3178,8 +5373,16 @@ pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
     .expect("Should parse");
     impl_args.imports.insert("Governor", import);
 
+    override_functions("GovernorEvents", &mut governor_events, impl_args.map);
+    override_functions("GovernorInternal", &mut governor_internal, impl_args.map);
+
+    // only insert this if it is not present, extensions like `GovernorTimelock` provide their own
+    impl_args
+        .overriden_traits
+        .entry("GovernorInternal")
+        .or_insert(syn::Item::Impl(governor_internal));
+
     impl_args.items.push(syn::Item::Impl(governor_storage_getters));
-    impl_args.items.push(syn::Item::Impl(governor_internal));
     impl_args.items.push(syn::Item::Impl(governor_events));
     impl_args.items.push(syn::Item::Impl(governor_impl));
     impl_args.items.push(syn::Item::Impl(governor));
@@ -3212,6 +5415,62 @@ pub(crate) fn impl_nonces(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(nonces_impl));
     impl_args.items.push(syn::Item::Impl(nonces));
 }
+pub(crate) fn impl_multicall(impl_args: &mut ImplArgs) {
+    let storage_struct_name = &impl_args.contract_name();
+
+    let multicall_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl MulticallImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl multicall::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl multicall::Internal for #storage_struct_name {
+            fn _is_call_allowed(&self, callee: &AccountId, selector: &[u8; 4]) -> bool {
+                multicall::InternalImpl::_is_call_allowed(self, callee, selector)
+            }
+
+            fn _set_call_allowed(&mut self, callee: AccountId, selector: [u8; 4], allowed: bool) {
+                multicall::InternalImpl::_set_call_allowed(self, callee, selector, allowed)
+            }
+
+            fn _static_call(&self, callee: &AccountId, input: &[u8]) -> Option<Vec<u8>> {
+                multicall::InternalImpl::_static_call(self, callee, input)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut multicall = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Multicall for #storage_struct_name {
+            #[ink(message)]
+            fn multicall(&self, calls: Vec<(AccountId, Vec<u8>)>) -> Vec<Result<Vec<u8>, ()>> {
+                MulticallImpl::multicall(self, calls)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::multicall::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Multicall", import);
+    impl_args.vec_import();
+
+    override_functions("multicall::Internal", &mut internal, impl_args.map);
+    override_functions("Multicall", &mut multicall, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(multicall_impl));
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(multicall));
+}
+
 fn override_functions(trait_name: &str, implementation: &mut syn::ItemImpl, map: &OverridenFnMap) {
     if let Some(overrides) = map.get(trait_name) {
         // we will find which fns we wanna override
@@ -54,15 +54,27 @@ pub fn generate(_attrs: TokenStream, _input: TokenStream) -> TokenStream {
         }
     }
 
-    let receiver;
-    if let syn::FnArg::Receiver(rec) = impl_item.sig.inputs.first().expect("Expect at least one argument") {
-        receiver = rec;
-    } else {
-        return quote_spanned! {
-            impl_item.sig.inputs.first().expect("Expect at least one argument").span() =>
-                compile_error!("First argument in method must be `self`.");
+    let receiver = match impl_item.sig.inputs.first().expect("Expect at least one argument") {
+        syn::FnArg::Receiver(rec) if rec.reference.is_some() => rec,
+        syn::FnArg::Receiver(rec) => {
+            return quote_spanned! {
+                rec.span() =>
+                    compile_error!("Modifiers only support `&self` and `&mut self` receivers, `self` can't be taken by value.");
+            }
         }
-    }
+        syn::FnArg::Typed(arg) if matches!(&*arg.pat, syn::Pat::Ident(pat) if pat.ident == "self") => {
+            return quote_spanned! {
+                arg.span() =>
+                    compile_error!("Modifiers only support `&self` and `&mut self` receivers, typed receivers like `self: Rc<Self>` are not supported.");
+            }
+        }
+        arg => {
+            return quote_spanned! {
+                arg.span() =>
+                    compile_error!("First argument in method must be `self`.");
+            }
+        }
+    };
 
     // We skip every function without body(it means that it contains only `{ ; }`)
     if impl_item.block.to_token_stream().to_string() == "{ ; }" {
@@ -106,8 +118,15 @@ pub fn generate(_attrs: TokenStream, _input: TokenStream) -> TokenStream {
                 let cloned_variables_definitions = meta_list.nested.iter().map(|nested_meta| {
                     let cloned_ident = format_ident!("{}_cloned_{}", BRUSH_PREFIX, cloned_variables_idents.len());
                     cloned_variables_idents.push(cloned_ident.clone());
-                    quote! {
-                        let #cloned_ident = #nested_meta.clone();
+                    // The bound is checked at the span of the argument,
+                    // so the error points to the argument instead of the generated `.clone()`.
+                    quote_spanned! { nested_meta.span() =>
+                        let #cloned_ident = {
+                            fn modifier_argument_must_implement_clone<T: ::core::clone::Clone>(argument: &T) -> T {
+                                argument.clone()
+                            }
+                            modifier_argument_must_implement_clone(&#nested_meta)
+                        };
                     }
                 });
 
@@ -39,28 +39,157 @@ pub fn storage_derive(item: TokenStream) -> TokenStream {
         Data::Union(un) => un.fields.named.iter().collect(),
     };
 
-    let impls = fields
+    let missing_storage_fields = missing_storage_field_checks(&derive.generics, &fields);
+
+    let impls = fields.iter().filter(|field| is_storage_field(field)).map(|field| {
+        let field_ident = field.ident.clone();
+        let ty = field.ty.clone();
+        let span = field.span();
+
+        quote::quote_spanned!(span=>
+            impl #impls ::openbrush::traits::Storage<#ty> for #struct_ident #types #where_clause {
+                fn get(&self) -> &#ty {
+                    &self.#field_ident
+                }
+
+                fn get_mut(&mut self) -> &mut #ty {
+                    &mut self.#field_ident
+                }
+            }
+        )
+    });
+
+    let overlap_checks = storage_keys_overlap_checks(&derive.generics, &fields);
+
+    quote! {
+        #(#missing_storage_fields)*
+        #(#impls)*
+        #(#overlap_checks)*
+    }
+}
+
+/// Generates the compile-time checks that the storage items of the contract are `#[storage_field]`s,
+/// otherwise `Storage` isn't implemented for them and the default implementations can't use them.
+///
+/// Whether the type of a field is a storage item is resolved in constant evaluation, so any
+/// other type, even called `Data`, is allowed.
+fn missing_storage_field_checks(generics: &syn::Generics, fields: &[&syn::Field]) -> Vec<TokenStream> {
+    // The checks are generated as free constants, which can't refer to the generic parameters
+    if !generics.params.is_empty() {
+        return vec![]
+    }
+
+    fields
         .iter()
-        .filter(|field| field.attrs.iter().any(|a| a.path.is_ident("storage_field")))
+        .filter(|field| !is_storage_field(field))
         .map(|field| {
-            let field_ident = field.ident.clone();
-            let ty = field.ty.clone();
-            let span = field.span();
-
-            quote::quote_spanned!(span=>
-                impl #impls ::openbrush::traits::Storage<#ty> for #struct_ident #types #where_clause {
-                    fn get(&self) -> &#ty {
-                        &self.#field_ident
-                    }
-
-                    fn get_mut(&mut self) -> &mut #ty {
-                        &mut self.#field_ident
-                    }
-                }
+            let ty = &field.ty;
+            let ty_name = quote!(#ty).to_string().replace(' ', "");
+            let message = format!(
+                "storage data `{ty_name}` is not marked with `#[storage_field]`; \
+                 add `#[storage_field]` to the field to implement `Storage<{ty_name}>`"
+            );
+
+            quote::quote_spanned!(field.span()=>
+                const _: () = {
+                    use ::openbrush::traits::NoStorageKeys as _;
+                    ::core::assert!(!::openbrush::traits::StorageKeysOf::<#ty>::IS_STORAGE_ITEM, #message);
+                };
             )
-        });
+        })
+        .collect()
+}
+
+/// Generates the compile-time checks that the storage data of different `#[storage_field]`s
+/// don't use the same storage keys, otherwise they would silently overwrite each other.
+///
+/// The keys are derived in constant evaluation, so the check is an assertion evaluated by the compiler.
+/// Fields that aren't storage items have no keys, so they are never reported.
+fn storage_keys_overlap_checks(generics: &syn::Generics, fields: &[&syn::Field]) -> Vec<TokenStream> {
+    // The checks are generated as free constants, which can't refer to the generic parameters
+    if !generics.params.is_empty() {
+        return vec![]
+    }
+
+    let storage_data: Vec<_> = fields.iter().filter(|field| is_storage_field(field)).collect();
+
+    let mut checks = vec![];
+    for (i, first) in storage_data.iter().enumerate() {
+        for second in storage_data.iter().skip(i + 1) {
+            let (first_ty, second_ty) = (&first.ty, &second.ty);
+            let message = format!(
+                "storage fields `{}` and `{}` have overlapping storage keys",
+                field_name(first),
+                field_name(second)
+            );
+
+            checks.push(quote::quote_spanned!(second.span()=>
+                const _: () = {
+                    use ::openbrush::traits::NoStorageKeys as _;
+                    ::core::assert!(
+                        !::openbrush::traits::storage_keys_overlap(
+                            ::openbrush::traits::StorageKeysOf::<#first_ty>::KEYS,
+                            ::openbrush::traits::StorageKeysOf::<#second_ty>::KEYS,
+                        ),
+                        #message
+                    );
+                };
+            ));
+        }
+    }
+    checks
+}
+
+fn field_name(field: &syn::Field) -> String {
+    field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_else(|| {
+        let ty = &field.ty;
+        quote!(#ty).to_string().replace(' ', "")
+    })
+}
+
+pub fn storage_default_derive(item: TokenStream) -> TokenStream {
+    let derive: syn::DeriveInput = parse2(item).expect("Expected DeriveInput");
+
+    let struct_ident = derive.ident;
+    let (impls, types, where_clause) = derive.generics.split_for_impl();
+
+    let fields = match &derive.data {
+        Data::Struct(st) => &st.fields,
+        _ => {
+            return syn::Error::new_spanned(struct_ident, "`StorageDefault` can be derived only for structs")
+                .to_compile_error()
+        }
+    };
+
+    let values = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let span = field.span();
+
+        if is_storage_field(field) {
+            quote::quote_spanned!(span=> <#ty as ::core::default::Default>::default())
+        } else {
+            quote::quote_spanned!(span=> ::core::default::Default::default())
+        }
+    });
+
+    let body = match fields {
+        syn::Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| field.ident.clone());
+            quote! { Self { #(#idents: #values,)* } }
+        }
+        syn::Fields::Unnamed(_) => quote! { Self ( #(#values,)* ) },
+        syn::Fields::Unit => quote! { Self },
+    };
 
     quote! {
-        #(#impls)*
+        impl #impls ::core::default::Default for #struct_ident #types #where_clause {
+            fn default() -> Self {
+                #body
+            }
+        }
     }
 }
+
+fn is_storage_field(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|a| a.path.is_ident("storage_field"))
+}
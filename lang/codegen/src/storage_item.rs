@@ -28,6 +28,10 @@ use quote::{
     ToTokens,
 };
 use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
     spanned::Spanned,
     Data,
     DataEnum,
@@ -37,7 +41,56 @@ use syn::{
     Fields,
 };
 
-fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>, Vec<Option<TokenStream>>) {
+struct StorageItemArgs {
+    key: Option<syn::LitStr>,
+}
+
+impl Parse for StorageItemArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self { key: None })
+        }
+
+        let name: syn::Ident = input.parse()?;
+        if name != "key" {
+            return Err(syn::Error::new_spanned(name, "expected `key = \"0x...\"`"))
+        }
+        let _: syn::Token![=] = input.parse()?;
+        let key: syn::LitStr = input.parse()?;
+        let _: Option<syn::Token![,]> = input.parse()?;
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected argument, only `key` is supported"))
+        }
+
+        Ok(Self { key: Some(key) })
+    }
+}
+
+fn parse_key(key: &syn::LitStr) -> syn::Result<u32> {
+    key.value()
+        .strip_prefix("0x")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                key,
+                "storage key should be a `u32` in the hex format, like `0x0000abcd`",
+            )
+        })
+}
+
+fn storage_key_expr(structure_name: &str, field_name: &str, pinned_key: Option<&str>) -> TokenStream {
+    match pinned_key {
+        Some(key) => quote! { ::openbrush::storage_unique_key!(key = #key, #field_name) },
+        None => quote! { ::openbrush::storage_unique_key!(#structure_name, #field_name) },
+    }
+}
+
+fn wrap_upgradeable_fields(
+    structure_name: &str,
+    pinned_key: Option<&str>,
+    fields: Fields,
+) -> (Vec<Field>, Vec<Option<(syn::Ident, TokenStream)>>) {
     fields
         .iter()
         .map(|field| {
@@ -63,11 +116,12 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                     .cloned()
                     .collect();
 
+                let key = storage_key_expr(structure_name, &field_name, pinned_key);
                 let storage_key = quote! {
-                    pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
+                    pub const #key_name: u32 = #key;
                 };
 
-                (new_field, Some(storage_key))
+                (new_field, Some((key_name, storage_key)))
             } else {
                 let mut new_field = field.clone();
                 let span = field.ty.span();
@@ -106,9 +160,13 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                 }
 
                 let storage_key = if is_mapping {
-                    Some(quote! {
-                        pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
-                    })
+                    let key = storage_key_expr(structure_name, &field_name, pinned_key);
+                    Some((
+                        key_name.clone(),
+                        quote! {
+                            pub const #key_name: u32 = #key;
+                        },
+                    ))
                 } else {
                     None
                 };
@@ -119,14 +177,30 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
         .unzip()
 }
 
-fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> TokenStream {
+fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct, pinned_key: Option<&str>) -> TokenStream {
     let struct_ident = s.ast().ident.clone();
     let vis = s.ast().vis.clone();
     let types = s.ast().generics.clone();
     let attrs = s.ast().attrs.clone();
     let (_, _, where_closure) = s.ast().generics.split_for_impl();
 
-    let (fields, storage_keys) = wrap_upgradeable_fields(struct_ident.to_string().as_str(), struct_item.fields.clone());
+    let (fields, storage_keys) = wrap_upgradeable_fields(
+        struct_ident.to_string().as_str(),
+        pinned_key,
+        struct_item.fields.clone(),
+    );
+    let (key_names, storage_keys): (Vec<_>, Vec<_>) = storage_keys.into_iter().flatten().unzip();
+    // The pinned key itself is reserved, so two items pinning the same key can't be storage fields
+    // of the same contract, even if their fields don't share keys.
+    let pinned = pinned_key.map(|key| quote! { ::openbrush::storage_unique_key!(key = #key) });
+    let key_names = key_names.into_iter().map(|key_name| quote! { #key_name }).chain(pinned);
+    let ensure_loaded = generate_ensure_loaded(s, &struct_item.fields);
+    let (impl_generics, ty_generics, _) = s.ast().generics.split_for_impl();
+    let keys = quote! {
+        impl #impl_generics ::openbrush::traits::StorageKeys for #struct_ident #ty_generics #where_closure {
+            const KEYS: &'static [u32] = &[#(#key_names),*];
+        }
+    };
 
     match struct_item.fields {
         Fields::Unnamed(_) => {
@@ -137,6 +211,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 );
 
                 #(#storage_keys)*
+
+                #keys
             }
         }
         _ => {
@@ -147,12 +223,52 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 }
 
                 #(#storage_keys)*
+
+                #keys
+
+                #ensure_loaded
             }
         }
     }
 }
 
-fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStream {
+/// Generates `_ensure_loaded` that reads every `#[lazy]` field from the storage
+/// and reports the first one that has no value, so migrations can check all lazy fields of the item.
+fn generate_ensure_loaded(s: &synstructure::Structure, fields: &Fields) -> Option<TokenStream> {
+    let lazy_fields: Vec<_> = fields
+        .iter()
+        .filter(|field| is_attr(&field.attrs, "lazy"))
+        .filter_map(|field| field.ident.clone())
+        .collect();
+
+    if lazy_fields.is_empty() {
+        return None
+    }
+
+    let field_names = lazy_fields.iter().map(|field| field.to_string());
+    let struct_ident = s.ast().ident.clone();
+    let (impls, types, where_clause) = s.ast().generics.split_for_impl();
+
+    Some(quote! {
+        impl #impls #struct_ident #types #where_clause {
+            /// Reads every `#[lazy]` field from the storage.
+            ///
+            /// Returns the name of the first field that has no value in the storage, it panics
+            /// if a stored value can't be decoded. Use it in migrations to check that all lazy fields
+            /// are initialized after an upgrade.
+            pub fn _ensure_loaded(&self) -> ::core::result::Result<(), &'static str> {
+                #(
+                    if self.#lazy_fields.get().is_none() {
+                        return ::core::result::Result::Err(#field_names)
+                    }
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum, pinned_key: Option<&str>) -> TokenStream {
     let enum_ident = s.ast().ident.clone();
     let vis = s.ast().vis.clone();
     let attrs = s.ast().attrs.clone();
@@ -169,8 +285,10 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
             quote! {}
         };
 
+        let pinned_key = pinned_key.map(|key| format!("{}::{}", key, variant_ident));
         let (fields, storage_keys) = wrap_upgradeable_fields(
             format!("{}_{}", enum_ident, variant_ident).as_str(),
+            pinned_key.as_deref(),
             variant.fields.clone(),
         );
 
@@ -180,7 +298,11 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
             Fields::Unit => quote! {},
         };
 
-        all_storage_keys.extend(storage_keys);
+        all_storage_keys.extend(
+            storage_keys
+                .into_iter()
+                .map(|key| key.map(|(_, storage_key)| storage_key)),
+        );
 
         quote! {
             #(#attrs)*
@@ -215,15 +337,30 @@ fn generate_union(s: &synstructure::Structure, union_item: DataUnion) -> TokenSt
     }
 }
 
-pub fn storage_item(_attrs: TokenStream, s: synstructure::Structure) -> TokenStream {
+pub fn storage_item(attrs: TokenStream, s: synstructure::Structure) -> TokenStream {
+    let pinned_key = syn::parse2::<StorageItemArgs>(attrs).and_then(|args| {
+        if let Some(key) = &args.key {
+            parse_key(key)?;
+        }
+        Ok(args.key.map(|key| key.value()))
+    });
+    // we still generate the item to not produce errors in the code that uses it
+    let (pinned_key, error) = match pinned_key {
+        Ok(pinned_key) => (pinned_key, None),
+        Err(err) => (None, Some(err.to_compile_error())),
+    };
+    let pinned_key = pinned_key.as_deref();
+
     let item = match s.ast().data.clone() {
-        Data::Struct(struct_item) => generate_struct(&s, struct_item),
-        Data::Enum(enum_item) => generate_enum(&s, enum_item),
+        Data::Struct(struct_item) => generate_struct(&s, struct_item, pinned_key),
+        Data::Enum(enum_item) => generate_enum(&s, enum_item, pinned_key),
         Data::Union(union_item) => generate_union(&s, union_item),
     };
 
     quote! {
         #[::ink::storage_item]
         #item
+
+        #error
     }
 }
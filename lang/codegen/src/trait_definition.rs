@@ -186,6 +186,7 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
         .for_each(|method| {
             let message_ident = method.sig.ident.clone();
             let message_builder_ident = format_ident!("{}_builder", method.sig.ident);
+            let message_try_ident = format_ident!("try_{}", method.sig.ident);
             let output_ty = match method.sig.output.clone() {
                 syn::ReturnType::Default => quote! { () },
                 syn::ReturnType::Type(_, return_type) => quote! { #return_type },
@@ -239,6 +240,13 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
                     #( , #input_bindings : #input_types )*
                 ) -> #output_ty;
 
+                /// Same as the message, but returns `LangError` instead of panicking
+                /// if the callee can't process the call, e.g. doesn't implement the message.
+                fn #message_try_ident(
+                    & self
+                    #( , #input_bindings : #input_types )*
+                ) -> ::core::result::Result<#output_ty, ::ink::LangError>;
+
                 fn #message_builder_ident(
                     & self
                     #( , #input_bindings : #input_types )*
@@ -262,6 +270,16 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
                         .unwrap_or_else(|err| ::core::panic!("Can't decode ::ink::LangErr: {:?}", err))
                 }
 
+                #[inline]
+                fn #message_try_ident(
+                    & self
+                    #( , #input_bindings : #input_types )*
+                ) -> ::core::result::Result<#output_ty, ::ink::LangError> {
+                    Self::#message_builder_ident(self #( , #input_bindings)*)
+                        .try_invoke()
+                        .unwrap_or_else(|err| ::core::panic!("{}: {:?}", #panic_str, err))
+                }
+
                 #[inline]
                 fn #message_builder_ident(
                     & self
@@ -43,6 +43,15 @@ use openbrush_lang_codegen::{
 /// After consumption, it pastes ink! code and then ink!'s macros will be processed.
 ///
 /// This macro consumes impl section for traits defined with [`#[openbrush::trait_definition]`](`macro@crate::trait_definition`).
+///
+/// All arguments of the macro are passed to `#[ink::contract]`, except `test_exports` and `version`.
+/// `#[openbrush::contract(test_exports)]` adds the methods of implemented `Internal` traits
+/// (like `_mint_to`) as `pub(crate)` methods of the contract under `#[cfg(test)]`,
+/// so unit tests can call them directly on the contract instance.
+/// It doesn't change the contract outside of tests.
+///
+/// `#[openbrush::contract(version = "1.2.3")]` adds `#[ink(message)] fn version(&self) -> (u32, u32, u32)`
+/// to the contract that returns `(1, 2, 3)`. The version must be in `MAJOR.MINOR.PATCH` format.
 #[proc_macro_attribute]
 pub fn contract(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     contract::generate(_attrs.into(), ink_module.into()).into()
@@ -210,6 +219,7 @@ pub fn modifier_definition(_attrs: TokenStream, _input: TokenStream) -> TokenStr
 /// (you can pass an argument from the signature of marked method or from the outside scope of function).
 /// The modifier accepts arguments only by value and the type of argument must support `Clone` trait,
 /// because macro will clone the argument and will pass it to the modifier.
+/// If the argument doesn't implement `Clone`, the compiler reports it at the span of the argument.
 ///
 /// # Explanation:
 ///
@@ -410,6 +420,7 @@ pub fn wrapper(attrs: TokenStream, input: TokenStream) -> TokenStream {
 /// trait for each field marked by `#[storage_field]` attribute,
 /// so it will be possible to access them via `self.data::<Type>()` method. It is mostly used for OpenBrush
 /// to understand which fields should be accessed by traits.
+/// A field holding an `#[openbrush::storage_item]` without `#[storage_field]` is a compile error.
 ///
 /// # Example
 /// ```skip
@@ -425,6 +436,26 @@ pub fn storage_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream
     storage_derive::storage_derive(item.into()).into()
 }
 
+/// The macro implements `Default` for the storage struct.
+///
+/// Each field marked by `#[storage_field]` attribute is initialized with the `default` of its data type,
+/// like `psp22::Data::default()`. Other fields are initialized with their own `Default`.
+///
+/// # Example
+/// ```skip
+///     #[ink(storage)]
+///     #[derive(Storage, StorageDefault)]
+///     pub struct Contract {
+///         #[storage_field]
+///         psp22: psp22::Data,
+///         value: u32,
+///     }
+/// ```
+#[proc_macro_derive(StorageDefault, attributes(storage_field))]
+pub fn storage_default_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    storage_derive::storage_default_derive(item.into()).into()
+}
+
 synstructure::decl_attribute!(
     [accessors] =>
     /// Macro that automatically implements accessors like get/set for struct fields, that implements `scale::Encode`
@@ -525,6 +556,12 @@ synstructure::decl_attribute!(
 ///     }
 /// }
 /// ```
+///
+/// Messages of a default implementation can be protected by the reentrancy guard with the `reentrant_guard`
+/// option, like `#[openbrush::implementation(PSP22, Flashmint(reentrant_guard))]`.
+/// The macro adds the `non_reentrant` modifier to every generated message that accepts `&mut self`
+/// and returns `Result`. It requires the `reentrancy_guard` feature and a `#[storage_field]`
+/// with `reentrancy_guard::Data` in the contract.
 #[proc_macro_attribute]
 pub fn implementation(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     implementation::generate(attrs.into(), ink_module.into()).into()
@@ -538,6 +575,15 @@ synstructure::decl_attribute!(
     /// The macro also generates constant storage keys for every mapping or lazy field and inserts them into
     /// type definition.
     ///
+    /// By default, the storage keys are derived from the module path and the name of the struct,
+    /// so moving or renaming the struct changes them. The `key = "0x..."` argument pins the base of
+    /// the storage keys, which keeps the layout the same across upgrades. The keys of fields are derived
+    /// from the pinned key and the field name. Two storage items pinning the same key can't be storage fields
+    /// of the same contract, `#[derive(Storage)]` reports it at compile time.
+    ///
+    /// Structs with `#[lazy]` fields get a `_ensure_loaded(&self)` method. It reads every lazy field and
+    /// returns the name of the first one that has no value in the storage, so migrations can check all of them.
+    ///
     /// # Example
     /// ```skip
     /// #[openbrush::storage_item]
@@ -557,6 +603,17 @@ synstructure::decl_attribute!(
     ///     #[lazy]
     ///     b: u32,
     /// }
+    /// ```
+    ///
+    /// # Example
     ///
+    /// ```skip
+    /// #[openbrush::storage_item(key = "0x0000abcd")]
+    /// pub struct MyStruct {
+    ///     #[lazy]
+    ///     a: u32,
+    ///     b: Mapping<AccountId, u32>,
+    /// }
+    /// ```
     storage_item::storage_item
 );
@@ -21,6 +21,8 @@
 
 /// Return the hash of the format!("{}::{}", ::core::module_path!(), struct_name).
 /// Also, if field naming is provided, it will return the hash of the format!("{}::{}::{}", ::core::module_path!(), struct_name, field_name).
+/// With `key = "0x..."` instead of the struct name, it returns the hash of the format!("{}::{}", key, field_name),
+/// or the hash of the key without field naming, which doesn't depend on the module path.
 /// It cam be used to generate unique storage key of the struct.
 #[macro_export]
 macro_rules! storage_unique_key {
@@ -31,6 +33,12 @@ macro_rules! storage_unique_key {
             ::core::stringify!($struct)
         ))
     }};
+    (key = $key:literal) => {{
+        $crate::traits::ConstHasher::hash($key)
+    }};
+    (key = $key:literal, $field:literal) => {{
+        $crate::traits::ConstHasher::hash($crate::traits::const_format::concatcp!($key, "::", $field))
+    }};
     ($struct:literal, $field:literal) => {{
         $crate::traits::ConstHasher::hash($crate::traits::const_format::concatcp!(
             ::core::module_path!(),
@@ -75,3 +83,24 @@ fn correct_storage_key() {
     let expected_hash_psp34 = ConstHasher::hash("openbrush_lang::macros::contracts::psp34::Data");
     assert_eq!(expected_hash_psp34, <contracts::psp34::Data as StorageKey>::KEY);
 }
+
+#[test]
+fn pinned_storage_key() {
+    use crate::traits::ConstHasher;
+
+    mod contracts {
+        pub mod psp22 {
+            pub const KEY: u32 = storage_unique_key!(key = "0x0000abcd", "balances");
+        }
+
+        pub mod psp34 {
+            pub const KEY: u32 = storage_unique_key!(key = "0x0000abcd", "balances");
+        }
+    }
+
+    // The key doesn't depend on the module path
+    let expected_hash = ConstHasher::hash("0x0000abcd::balances");
+    assert_eq!(expected_hash, contracts::psp22::KEY);
+    assert_eq!(expected_hash, contracts::psp34::KEY);
+    assert_eq!(ConstHasher::hash("0x0000abcd"), storage_unique_key!(key = "0x0000abcd"));
+}
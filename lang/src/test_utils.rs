@@ -86,3 +86,15 @@ pub fn accounts() -> DefaultAccounts<DefaultEnvironment> {
 pub fn change_caller(new_caller: <DefaultEnvironment as Environment>::AccountId) {
     ink::env::test::set_caller::<ink::env::DefaultEnvironment>(new_caller);
 }
+
+/// Decodes all events recorded in the off-chain test environment into the contract's event type `E`.
+///
+/// Usually `E` is `<Contract as ink::reflect::ContractEventBase>::Type`.
+#[cfg(feature = "std")]
+pub fn decode_events<E: scale::Decode>() -> Vec<E> {
+    ink::env::test::recorded_events()
+        .map(|event| {
+            <E as scale::Decode>::decode(&mut &event.data[..]).expect("encountered invalid contract event data buffer")
+        })
+        .collect()
+}
@@ -24,12 +24,18 @@ use ::ink::env::{
     Environment,
 };
 pub use const_format;
-use core::mem::ManuallyDrop;
+use core::{
+    marker::PhantomData,
+    mem::ManuallyDrop,
+};
 use ink::storage::traits::{
     Storable,
     StorageKey,
 };
-pub use openbrush_lang_macro::Storage;
+pub use openbrush_lang_macro::{
+    Storage,
+    StorageDefault,
+};
 pub use xxhash_rust::const_xxh32::xxh32;
 
 /// Aliases for types of the default environment
@@ -136,3 +142,46 @@ impl ConstHasher {
         xxh32(str.as_bytes(), XXH32_SEED)
     }
 }
+
+/// Storage keys of the `#[lazy]` and mapping fields of the item.
+///
+/// It is implemented by `#[openbrush::storage_item]`, and `#[derive(Storage)]` uses it
+/// to check at compile time that the storage fields of a contract don't share keys.
+pub trait StorageKeys {
+    const KEYS: &'static [u32];
+}
+
+/// Resolves in constant evaluation whether `T` is a storage item, without requiring `T: StorageKeys`.
+///
+/// The inherent constants are used when `T` implements [`StorageKeys`], otherwise the constants
+/// of [`NoStorageKeys`] are used, so it must be in scope. It only works with concrete types.
+pub struct StorageKeysOf<T: ?Sized>(PhantomData<T>);
+
+impl<T: StorageKeys + ?Sized> StorageKeysOf<T> {
+    pub const IS_STORAGE_ITEM: bool = true;
+    pub const KEYS: &'static [u32] = T::KEYS;
+}
+
+/// Fallback of [`StorageKeysOf`] for types that aren't storage items.
+pub trait NoStorageKeys {
+    const IS_STORAGE_ITEM: bool = false;
+    const KEYS: &'static [u32] = &[];
+}
+
+impl<T: ?Sized> NoStorageKeys for StorageKeysOf<T> {}
+
+/// Returns `true` if `a` and `b` contain the same key.
+pub const fn storage_keys_overlap(a: &[u32], b: &[u32]) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        let mut j = 0;
+        while j < b.len() {
+            if a[i] == b[j] {
+                return true
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    false
+}
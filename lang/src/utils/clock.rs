@@ -0,0 +1,41 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::traits::{
+    DefaultEnv,
+    Timestamp,
+};
+
+/// Source of the current time for the time-dependent logic of the contracts.
+///
+/// Extensions read the time via `self.now()` instead of `Self::env().block_timestamp()`,
+/// so unit tests can mock the time by overriding `now` with `#[overrider(Clock)]`
+/// instead of advancing the blocks of the off-chain environment.
+pub trait Clock {
+    /// Returns the current time in milliseconds.
+    fn now(&self) -> Timestamp;
+}
+
+pub trait ClockImpl: Clock {
+    fn now(&self) -> Timestamp {
+        Self::env().block_timestamp()
+    }
+}
@@ -20,15 +20,42 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use crate::traits::String;
-use ink::env::hash;
 
 use crate::traits::AccountId;
+use ink::prelude::{
+    string::ToString,
+    vec::Vec,
+};
+
+/// Hashing functions that wrap ink!'s environment hashers.
+///
+/// Substrate-native flows (like SS58 addresses and `Signature::verify`) use `blake2_256`,
+/// while Ethereum-compatible standards use `keccak256`.
+pub mod hash {
+    use ink::env::hash::{
+        Blake2x256,
+        HashOutput,
+        Keccak256,
+    };
+
+    /// Returns the Keccak-256 hash of `data`.
+    pub fn keccak256(data: &[u8]) -> [u8; 32] {
+        let mut output = <Keccak256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Keccak256>(data, &mut output);
+        output
+    }
+
+    /// Returns the BLAKE2b-256 hash of `data`.
+    pub fn blake2_256(data: &[u8]) -> [u8; 32] {
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Blake2x256>(data, &mut output);
+        output
+    }
+}
 
 /// Hashing function for bytes
 pub fn hash_blake2b256(input: &[u8]) -> [u8; 32] {
-    let mut output = <hash::Blake2x256 as hash::HashOutput>::Type::default();
-    ink::env::hash_bytes::<hash::Blake2x256>(input, &mut output);
-    output
+    hash::blake2_256(input)
 }
 
 /// Converts a compressed public key to SS58 format
@@ -36,6 +63,18 @@ pub fn pub_key_to_ss58(pub_key: &[u8; 33]) -> AccountId {
     AccountId::from(hash_blake2b256(pub_key))
 }
 
+/// Recovers the signer of `message_hash` from the ECDSA `signature`
+/// and converts its compressed public key to SS58 format.
+///
+/// Returns `None` if the public key can't be recovered.
+pub fn ecrecover_account(message_hash: &[u8; 32], signature: &[u8; 65]) -> Option<AccountId> {
+    let mut pub_key = [0u8; 33];
+
+    ink::env::ecdsa_recover(signature, message_hash, &mut pub_key).ok()?;
+
+    Some(pub_key_to_ss58(&pub_key))
+}
+
 /// Converts a public key to an Ethereum address
 pub fn pub_key_to_eth_address(pub_key: &[u8; 33]) -> Result<[u8; 20], CryptoError> {
     let mut output = [0u8; 20];
@@ -45,6 +84,51 @@ pub fn pub_key_to_eth_address(pub_key: &[u8; 33]) -> Result<[u8; 20], CryptoErro
     Ok(output)
 }
 
+/// Defines how the digest that is signed is built from a message.
+///
+/// Different ecosystems prefix signed messages differently, so a signature produced
+/// under one scheme is not valid under another one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum SigningScheme {
+    /// `blake2_256(message)`, the digest used by `Signature::verify`.
+    Raw,
+    /// EIP-191 personal message: `keccak256("\x19Ethereum Signed Message:\n" ++ len(message) ++ message)`.
+    Eip191,
+    /// EIP-712 envelope: `keccak256("\x19\x01" ++ domain_separator ++ keccak256(message))`.
+    ///
+    /// The message is hashed as raw bytes (usually SCALE-encoded), not with EIP-712 `hashStruct`,
+    /// so signatures of EIP-712 typed data produced by wallets don't match this digest.
+    #[default]
+    Eip712,
+}
+
+impl SigningScheme {
+    /// Returns the digest of `message` that is signed under the scheme.
+    ///
+    /// `domain_separator` is used only by `Eip712`.
+    pub fn digest(&self, domain_separator: &[u8; 32], message: &[u8]) -> [u8; 32] {
+        match self {
+            SigningScheme::Raw => hash::blake2_256(message),
+            SigningScheme::Eip191 => {
+                let length = message.len().to_string();
+                let mut data = Vec::with_capacity(26 + length.len() + message.len());
+                data.extend_from_slice(b"\x19Ethereum Signed Message:\n");
+                data.extend_from_slice(length.as_bytes());
+                data.extend_from_slice(message);
+                hash::keccak256(&data)
+            }
+            SigningScheme::Eip712 => {
+                let mut data = Vec::with_capacity(66);
+                data.extend_from_slice(b"\x19\x01");
+                data.extend_from_slice(domain_separator);
+                data.extend_from_slice(&hash::keccak256(message));
+                hash::keccak256(&data)
+            }
+        }
+    }
+}
+
 /// Enum to represent different signature types
 ///
 /// # Support of signatures
@@ -72,19 +156,52 @@ impl Signature {
     /// # Supported signatures
     ///
     /// - `ECDSA`
-    #[allow(unreachable_patterns)]
     pub fn verify(&self, message: &[u8], address: &AccountId) -> bool {
-        match self {
-            // Verifies ECDSA signature
-            Signature::ECDSA(sig) => {
-                let mut output: [u8; 33] = [0; 33];
-                let message_hash = hash_blake2b256(message);
+        self.recover(message).as_ref() == Some(address)
+    }
 
-                let result = ink::env::ecdsa_recover(sig, &message_hash, &mut output);
+    /// Recovers the account that signed the `message`
+    ///
+    /// # Returns
+    ///
+    /// - `Some(account)` with the signer of the message
+    /// - `None` if the signer can't be recovered
+    ///
+    /// # Supported signatures
+    ///
+    /// - `ECDSA`
+    pub fn recover(&self, message: &[u8]) -> Option<AccountId> {
+        self.recover_digest(&hash_blake2b256(message))
+    }
 
-                return result.is_ok() && pub_key_to_ss58(&output) == address.clone()
-            }
-            _ => false,
+    /// Verifies the signature of `message` signed under the `scheme`
+    ///
+    /// # Returns
+    ///
+    /// - `true` if `address` signed the digest of `message` built by the `scheme`
+    /// - `false` otherwise
+    pub fn verify_with_scheme(
+        &self,
+        scheme: SigningScheme,
+        domain_separator: &[u8; 32],
+        message: &[u8],
+        address: &AccountId,
+    ) -> bool {
+        self.recover_digest(&scheme.digest(domain_separator, message)).as_ref() == Some(address)
+    }
+
+    /// Recovers the account that signed the `digest`
+    ///
+    /// # Returns
+    ///
+    /// - `Some(account)` with the signer of the digest
+    /// - `None` if the signer can't be recovered
+    #[allow(unreachable_patterns)]
+    pub fn recover_digest(&self, digest: &[u8; 32]) -> Option<AccountId> {
+        match self {
+            // Recovers the signer of ECDSA signature
+            Signature::ECDSA(sig) => ecrecover_account(digest, sig),
+            _ => None,
         }
     }
 }
@@ -96,3 +213,172 @@ pub enum CryptoError {
     EcdsaToEthAddressFailed,
     Other(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn keccak256_works() {
+        assert_eq!(
+            hash::keccak256(b""),
+            [
+                0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5,
+                0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70,
+            ]
+        );
+        assert_eq!(
+            hash::keccak256(b"abc"),
+            [
+                0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0,
+                0xd1, 0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+            ]
+        );
+    }
+
+    #[ink::test]
+    fn blake2_256_works() {
+        assert_eq!(
+            hash::blake2_256(b""),
+            [
+                0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2, 0xe8, 0xab, 0x2e, 0xb0, 0x60, 0x99, 0xda, 0xa1, 0xd1,
+                0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87, 0xfa, 0xab, 0x45, 0xcd, 0xf1, 0x2f, 0xe3, 0xa8,
+            ]
+        );
+        assert_eq!(
+            hash::blake2_256(b"abc"),
+            [
+                0xbd, 0xdd, 0x81, 0x3c, 0x63, 0x42, 0x39, 0x72, 0x31, 0x71, 0xef, 0x3f, 0xee, 0x98, 0x57, 0x9b, 0x94,
+                0x96, 0x4e, 0x3b, 0xb1, 0xcb, 0x3e, 0x42, 0x72, 0x62, 0xc8, 0xc0, 0x68, 0xd5, 0x23, 0x19,
+            ]
+        );
+        assert_eq!(hash_blake2b256(b"abc"), hash::blake2_256(b"abc"));
+    }
+
+    const MESSAGE_HASH: [u8; 32] = [
+        0x80, 0x7c, 0x3f, 0xaf, 0x04, 0xfd, 0xc5, 0x8c, 0xa9, 0xb2, 0x95, 0xae, 0xb6, 0x3b, 0x39, 0xf3, 0x50, 0xb7,
+        0xae, 0x52, 0xd7, 0xe8, 0x11, 0x02, 0x64, 0x3b, 0xbb, 0xb5, 0x96, 0x4e, 0x06, 0x69,
+    ];
+
+    const SIGNATURE: [u8; 65] = [
+        0x66, 0xba, 0x2b, 0x80, 0x20, 0x48, 0x60, 0x8a, 0x91, 0x35, 0x67, 0x01, 0xd4, 0xf3, 0xff, 0x82, 0x27, 0xfa,
+        0xb6, 0x71, 0xdb, 0x07, 0xb5, 0xe5, 0xf2, 0x86, 0xd1, 0x3e, 0x12, 0x64, 0xa1, 0x7a, 0x7e, 0xef, 0xc7, 0xf0,
+        0x27, 0xef, 0x3e, 0x30, 0xcf, 0x1e, 0xe6, 0xf2, 0xd0, 0xb9, 0xcc, 0x4b, 0x47, 0x65, 0x5d, 0x00, 0xa7, 0xb7,
+        0x4f, 0x41, 0x21, 0xd0, 0x8c, 0x3a, 0x0b, 0x50, 0xfb, 0x3e, 0x01,
+    ];
+
+    const PUB_KEY: [u8; 33] = [
+        0x02, 0x4e, 0x3b, 0x81, 0xaf, 0x9c, 0x22, 0x34, 0xca, 0xd0, 0x9d, 0x67, 0x9c, 0xe6, 0x03, 0x5e, 0xd1, 0x39,
+        0x23, 0x47, 0xce, 0x64, 0xce, 0x40, 0x5f, 0x5d, 0xcd, 0x36, 0x22, 0x8a, 0x25, 0xde, 0x6e,
+    ];
+
+    const ACCOUNT: [u8; 32] = [
+        0x92, 0x06, 0x98, 0x70, 0xc1, 0x85, 0xd3, 0x8a, 0xcc, 0x5b, 0xc1, 0x07, 0x68, 0xff, 0x56, 0x4e, 0x76, 0x76,
+        0x17, 0x6b, 0xa6, 0xdb, 0x19, 0xc0, 0x87, 0x53, 0xfa, 0x9a, 0x16, 0xbb, 0x65, 0xf1,
+    ];
+
+    #[ink::test]
+    fn ecrecover_account_works() {
+        assert_eq!(MESSAGE_HASH, hash::blake2_256(b"openbrush"));
+        assert_eq!(pub_key_to_ss58(&PUB_KEY), AccountId::from(ACCOUNT));
+
+        assert_eq!(
+            ecrecover_account(&MESSAGE_HASH, &SIGNATURE),
+            Some(AccountId::from(ACCOUNT))
+        );
+        assert!(Signature::ECDSA(SIGNATURE).verify(b"openbrush", &AccountId::from(ACCOUNT)));
+        assert_eq!(
+            Signature::ECDSA(SIGNATURE).recover(b"openbrush"),
+            Some(AccountId::from(ACCOUNT))
+        );
+    }
+
+    const SCHEME_ACCOUNT: [u8; 32] = [
+        0xfa, 0xfb, 0x3b, 0xc8, 0x28, 0x2b, 0x8f, 0xaa, 0x42, 0xf5, 0x29, 0x3f, 0x8b, 0xc8, 0xe7, 0xe3, 0x5f, 0x8e,
+        0x92, 0x19, 0xf8, 0xe3, 0xd8, 0x48, 0xda, 0x87, 0x4b, 0x25, 0xc7, 0x23, 0x37, 0xef,
+    ];
+
+    const RAW_SIGNATURE: [u8; 65] = [
+        0xd8, 0x4a, 0x71, 0x52, 0xf3, 0x53, 0x29, 0xf5, 0x9b, 0xa8, 0xe5, 0xd0, 0x9c, 0xd5, 0xa6, 0x97, 0x7a, 0x0e,
+        0x36, 0x99, 0x18, 0x65, 0x08, 0x8b, 0x4f, 0x14, 0x90, 0x84, 0x50, 0x52, 0xe5, 0x9a, 0x65, 0xdf, 0x03, 0xf6,
+        0xc3, 0xae, 0xca, 0x30, 0x9d, 0x5a, 0x67, 0xdc, 0x1c, 0x3c, 0x82, 0x31, 0x89, 0x81, 0xc2, 0x03, 0x5f, 0x0a,
+        0x95, 0x71, 0xdb, 0x7b, 0x6e, 0x22, 0xfe, 0x36, 0xbe, 0xf1, 0x01,
+    ];
+
+    const EIP191_SIGNATURE: [u8; 65] = [
+        0x81, 0x15, 0x51, 0x5c, 0x2a, 0x48, 0xaa, 0x7b, 0x51, 0x30, 0x84, 0xb0, 0x4e, 0x3a, 0x8b, 0xaf, 0x3a, 0x29,
+        0x7d, 0x42, 0x73, 0x0c, 0x34, 0xb4, 0xcc, 0x78, 0x00, 0xcc, 0xe5, 0x8a, 0x79, 0x32, 0x49, 0x2d, 0xe0, 0xc8,
+        0xf0, 0x84, 0xb0, 0x34, 0x3e, 0xf5, 0x41, 0x6f, 0x5a, 0x90, 0x81, 0x00, 0xe9, 0x48, 0x7b, 0x76, 0x07, 0xa5,
+        0x17, 0xb4, 0xac, 0xaf, 0x89, 0xf0, 0x73, 0x7f, 0x8e, 0x42, 0x00,
+    ];
+
+    const EIP712_SIGNATURE: [u8; 65] = [
+        0x3e, 0x09, 0x63, 0x50, 0x24, 0x66, 0x9d, 0x6b, 0xb8, 0x68, 0x4e, 0x47, 0x55, 0x06, 0x49, 0x0b, 0x52, 0x1e,
+        0x5a, 0x44, 0xb2, 0x0d, 0xbb, 0xca, 0xfc, 0x67, 0x69, 0x32, 0x05, 0xe4, 0xf8, 0x75, 0x73, 0xa7, 0x7d, 0xd9,
+        0xd4, 0xce, 0x2f, 0xdd, 0x47, 0xb9, 0x40, 0x6e, 0xae, 0xd8, 0x83, 0xb1, 0xc3, 0x53, 0x52, 0xf1, 0xff, 0xaa,
+        0xc7, 0xef, 0xb6, 0xca, 0xbd, 0xeb, 0xc5, 0xc4, 0x35, 0x59, 0x01,
+    ];
+
+    const DOMAIN_SEPARATOR: [u8; 32] = [0x11; 32];
+
+    const SCHEMES: [(SigningScheme, [u8; 65]); 3] = [
+        (SigningScheme::Raw, RAW_SIGNATURE),
+        (SigningScheme::Eip191, EIP191_SIGNATURE),
+        (SigningScheme::Eip712, EIP712_SIGNATURE),
+    ];
+
+    #[ink::test]
+    fn default_signing_scheme_is_eip712() {
+        assert_eq!(SigningScheme::default(), SigningScheme::Eip712);
+    }
+
+    #[ink::test]
+    fn raw_signing_scheme_matches_verify() {
+        assert_eq!(SigningScheme::Raw.digest(&DOMAIN_SEPARATOR, b"openbrush"), MESSAGE_HASH);
+        assert!(Signature::ECDSA(SIGNATURE).verify_with_scheme(
+            SigningScheme::Raw,
+            &DOMAIN_SEPARATOR,
+            b"openbrush",
+            &AccountId::from(ACCOUNT)
+        ));
+    }
+
+    #[ink::test]
+    fn signature_is_valid_only_under_its_scheme() {
+        let signer = AccountId::from(SCHEME_ACCOUNT);
+
+        for (signed_with, signature) in SCHEMES {
+            for (scheme, _) in SCHEMES {
+                assert_eq!(
+                    Signature::ECDSA(signature).verify_with_scheme(scheme, &DOMAIN_SEPARATOR, b"openbrush", &signer),
+                    scheme == signed_with,
+                    "signed with {:?}, verified with {:?}",
+                    signed_with,
+                    scheme
+                );
+            }
+        }
+    }
+
+    #[ink::test]
+    fn eip712_signature_is_bound_to_domain_separator() {
+        assert!(!Signature::ECDSA(EIP712_SIGNATURE).verify_with_scheme(
+            SigningScheme::Eip712,
+            &[0x22; 32],
+            b"openbrush",
+            &AccountId::from(SCHEME_ACCOUNT)
+        ));
+    }
+
+    #[ink::test]
+    fn ecrecover_account_with_corrupted_signature_fails() {
+        let mut corrupted = SIGNATURE;
+        corrupted[10] ^= 0xff;
+        assert_ne!(
+            ecrecover_account(&MESSAGE_HASH, &corrupted),
+            Some(AccountId::from(ACCOUNT))
+        );
+
+        assert_eq!(ecrecover_account(&MESSAGE_HASH, &[0u8; 65]), None);
+    }
+}
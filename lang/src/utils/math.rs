@@ -0,0 +1,125 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::traits::Balance;
+
+/// Direction in which the result of an inexact division is rounded.
+///
+/// The direction matters for the invariants of the contracts that split amounts between
+/// several parties. For example, fees and payouts use `Down`, so the sum of the parts never
+/// exceeds the amount that was split.
+#[derive(scale::Decode, scale::Encode, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum RoundingMode {
+    /// Rounds towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds to the nearest integer, a result exactly between two integers is rounded up.
+    Nearest,
+}
+
+/// Returns `x * y / denominator` rounded in the direction of `rounding`.
+///
+/// `x` is split by `denominator` before the multiplication, so the intermediate product
+/// doesn't overflow as long as `(x % denominator) * y` fits into `Balance`.
+///
+/// Returns `None` if `denominator` is zero or the result doesn't fit into `Balance`.
+pub fn mul_div(x: Balance, y: Balance, denominator: Balance, rounding: RoundingMode) -> Option<Balance> {
+    if denominator == 0 {
+        return None
+    }
+
+    let remainder_product = (x % denominator).checked_mul(y)?;
+    let result = (x / denominator)
+        .checked_mul(y)?
+        .checked_add(remainder_product / denominator)?;
+    let remainder = remainder_product % denominator;
+
+    let round_up = match rounding {
+        RoundingMode::Down => false,
+        RoundingMode::Up => remainder > 0,
+        RoundingMode::Nearest => remainder >= denominator - remainder,
+    };
+
+    if round_up {
+        result.checked_add(1)
+    } else {
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_exact_result_is_not_rounded() {
+        for rounding in [RoundingMode::Down, RoundingMode::Up, RoundingMode::Nearest] {
+            assert_eq!(mul_div(10, 6, 4, rounding), Some(15));
+            assert_eq!(mul_div(0, 6, 4, rounding), Some(0));
+        }
+    }
+
+    #[test]
+    fn mul_div_rounds_half_values() {
+        // 5 * 3 / 2 = 7.5
+        assert_eq!(mul_div(5, 3, 2, RoundingMode::Down), Some(7));
+        assert_eq!(mul_div(5, 3, 2, RoundingMode::Up), Some(8));
+        assert_eq!(mul_div(5, 3, 2, RoundingMode::Nearest), Some(8));
+
+        // 1 * 5_000 / 10_000 = 0.5
+        assert_eq!(mul_div(1, 5_000, 10_000, RoundingMode::Down), Some(0));
+        assert_eq!(mul_div(1, 5_000, 10_000, RoundingMode::Up), Some(1));
+        assert_eq!(mul_div(1, 5_000, 10_000, RoundingMode::Nearest), Some(1));
+    }
+
+    #[test]
+    fn mul_div_nearest_rounds_to_closest() {
+        // 7 / 3 = 2.33
+        assert_eq!(mul_div(7, 1, 3, RoundingMode::Down), Some(2));
+        assert_eq!(mul_div(7, 1, 3, RoundingMode::Up), Some(3));
+        assert_eq!(mul_div(7, 1, 3, RoundingMode::Nearest), Some(2));
+
+        // 8 / 3 = 2.67
+        assert_eq!(mul_div(8, 1, 3, RoundingMode::Down), Some(2));
+        assert_eq!(mul_div(8, 1, 3, RoundingMode::Up), Some(3));
+        assert_eq!(mul_div(8, 1, 3, RoundingMode::Nearest), Some(3));
+    }
+
+    #[test]
+    fn mul_div_handles_large_values() {
+        // `x * y` overflows, but the result fits
+        assert_eq!(
+            mul_div(Balance::MAX, 9_999, 10_000, RoundingMode::Down),
+            Some(Balance::MAX / 10_000 * 9_999 + Balance::MAX % 10_000 * 9_999 / 10_000)
+        );
+        assert_eq!(mul_div(Balance::MAX, 1, 1, RoundingMode::Up), Some(Balance::MAX));
+
+        assert_eq!(mul_div(Balance::MAX, 2, 1, RoundingMode::Down), None);
+        assert_eq!(mul_div(Balance::MAX, 3, 2, RoundingMode::Up), None);
+    }
+
+    #[test]
+    fn mul_div_by_zero_fails() {
+        assert_eq!(mul_div(1, 1, 0, RoundingMode::Down), None);
+    }
+}
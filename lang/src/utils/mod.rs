@@ -22,5 +22,8 @@
 
 #[cfg(feature = "checkpoints")]
 pub mod checkpoints;
+pub mod clock;
 #[cfg(feature = "crypto")]
 pub mod crypto;
+pub mod math;
+pub mod transfer;
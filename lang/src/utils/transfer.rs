@@ -0,0 +1,116 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Transfers `amount` of native tokens from the contract to `to`.
+///
+/// Unlike a bare `Self::env().transfer(..)` followed by `unwrap`, a rejected transfer
+/// doesn't trap the contract. The error is returned to the caller, so it can fall back
+/// to another flow, for example credit the amount to an escrow to be withdrawn later.
+pub fn safe_transfer_native(to: AccountId, amount: Balance) -> Result<(), TransferError> {
+    ink::env::transfer::<ink::env::DefaultEnvironment>(to, amount).map_err(TransferError::from)
+}
+
+#[derive(scale::Decode, scale::Encode, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum TransferError {
+    /// The contract doesn't have enough free balance or the recipient rejected the transfer.
+    TransferFailed,
+    /// The transfer would leave the contract below the existential deposit.
+    BelowSubsistenceThreshold,
+}
+
+impl From<ink::env::Error> for TransferError {
+    fn from(error: ink::env::Error) -> Self {
+        match error {
+            ink::env::Error::BelowSubsistenceThreshold => TransferError::BelowSubsistenceThreshold,
+            _ => TransferError::TransferFailed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::env::{
+        test,
+        DefaultEnvironment,
+    };
+
+    #[ink::test]
+    fn safe_transfer_native_works() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let contract = test::callee::<DefaultEnvironment>();
+        let contract_balance = test::get_account_balance::<DefaultEnvironment>(contract).unwrap();
+        let bob_balance = test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+
+        assert_eq!(safe_transfer_native(accounts.bob, 100), Ok(()));
+
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(contract).unwrap(),
+            contract_balance - 100
+        );
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap(),
+            bob_balance + 100
+        );
+    }
+
+    #[ink::test]
+    fn safe_transfer_native_returns_error_instead_of_trapping() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let contract = test::callee::<DefaultEnvironment>();
+        test::set_account_balance::<DefaultEnvironment>(contract, 10);
+        let bob_balance = test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+
+        assert_eq!(
+            safe_transfer_native(accounts.bob, 100),
+            Err(TransferError::TransferFailed)
+        );
+
+        // The contract keeps running and can fall back to another flow
+        assert_eq!(test::get_account_balance::<DefaultEnvironment>(contract).unwrap(), 10);
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap(),
+            bob_balance
+        );
+    }
+
+    #[test]
+    fn environment_errors_are_mapped() {
+        assert_eq!(
+            TransferError::from(ink::env::Error::BelowSubsistenceThreshold),
+            TransferError::BelowSubsistenceThreshold
+        );
+        assert_eq!(
+            TransferError::from(ink::env::Error::TransferFailed),
+            TransferError::TransferFailed
+        );
+        assert_eq!(
+            TransferError::from(ink::env::Error::CalleeTrapped),
+            TransferError::TransferFailed
+        );
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod mock_view {
+    #[ink(storage)]
+    pub struct MockView {
+        pub value: u32,
+        pub trap: bool,
+    }
+
+    impl MockView {
+        #[ink(constructor)]
+        pub fn new(value: u32, trap: bool) -> Self {
+            Self { value, trap }
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            if self.trap {
+                panic!("MockView trapped")
+            }
+            self.value
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u32) {
+            self.value = value;
+        }
+    }
+}
@@ -75,6 +75,7 @@ mod access_control {
     // And will reduce the chance to have overlapping roles.
     const MINTER: RoleType = ink::selector_id!("MINTER");
     const PAUSER: RoleType = ink::selector_id!("PAUSER");
+    const SUPER_ADMIN: RoleType = ink::selector_id!("SUPER_ADMIN");
 
     #[derive(Default, Storage)]
     #[ink(storage)]
@@ -111,6 +112,13 @@ mod access_control {
             access_control::Internal::_init_with_admin(&mut instance, Some(admin));
             instance
         }
+
+        #[ink(constructor)]
+        pub fn new_with_admin_role(admin: AccountId, admin_role: RoleType) -> Self {
+            let mut instance = Self::default();
+            access_control::Internal::_init_with_admin_role(&mut instance, Some(admin), admin_role);
+            instance
+        }
     }
 
     fn assert_role_admin_change_event(
@@ -381,4 +389,46 @@ mod access_control {
             Err(AccessControlError::MissingRole)
         );
     }
+
+    #[ink::test]
+    fn should_init_with_custom_admin_role() {
+        let accounts = setup();
+        let access_control = AccessControlStruct::new_with_admin_role(accounts.alice, SUPER_ADMIN);
+
+        assert!(AccessControl::has_role(
+            &access_control,
+            SUPER_ADMIN,
+            Some(accounts.alice)
+        ));
+        assert!(!AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+        assert_eq!(AccessControl::get_role_admin(&access_control, SUPER_ADMIN), SUPER_ADMIN);
+        assert_eq!(AccessControl::get_role_admin(&access_control, PAUSER), SUPER_ADMIN);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_role_granted_event(&emitted_events[0], SUPER_ADMIN, Some(accounts.alice), None);
+    }
+
+    #[ink::test]
+    fn custom_admin_role_governs_other_roles() {
+        let accounts = setup();
+        change_caller(accounts.alice);
+        let mut access_control = AccessControlStruct::new_with_admin_role(accounts.alice, SUPER_ADMIN);
+
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert!(AccessControl::revoke_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+
+        access_control::Internal::_setup_role(&mut access_control, DEFAULT_ADMIN_ROLE, Some(accounts.eve));
+        change_caller(accounts.eve);
+
+        assert_eq!(
+            AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.eve)),
+            Err(AccessControlError::MissingRole)
+        );
+    }
 }
@@ -0,0 +1,149 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "access_control", feature = "ownable"))]
+#[openbrush::implementation(Ownable, AccessControl, AccessControlBootstrap)]
+#[openbrush::contract]
+mod access_control_bootstrap {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous: Option<AccountId>,
+        #[ink(topic)]
+        new: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: RoleType,
+        #[ink(topic)]
+        grantee: Option<AccountId>,
+        #[ink(topic)]
+        grantor: Option<AccountId>,
+    }
+
+    const MINTER: RoleType = ink::selector_id!("MINTER");
+    const PAUSER: RoleType = ink::selector_id!("PAUSER");
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct BootstrapStruct {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    type Event = <BootstrapStruct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(ownable::Internal)]
+    fn _emit_ownership_transferred_event(&self, previous: Option<AccountId>, new: Option<AccountId>) {
+        self.env().emit_event(OwnershipTransferred { previous, new })
+    }
+
+    #[overrider(access_control::Internal)]
+    fn _emit_role_granted(&mut self, role: u32, grantee: Option<AccountId>, grantor: Option<AccountId>) {
+        self.env().emit_event(RoleGranted { role, grantee, grantor })
+    }
+
+    impl BootstrapStruct {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId, admins: Vec<AccountId>, roles: Vec<(RoleType, AccountId)>) -> Self {
+            let mut instance = Self::default();
+            bootstrap::Internal::_bootstrap_access(&mut instance, owner, admins, roles);
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn bootstrap_sets_owner_and_roles() {
+        let accounts = accounts();
+        let contract = BootstrapStruct::new(
+            accounts.alice,
+            vec![accounts.alice, accounts.bob],
+            vec![(MINTER, accounts.charlie), (PAUSER, accounts.django)],
+        );
+
+        assert_eq!(Ownable::owner(&contract), Some(accounts.alice));
+
+        let admin_role = access_control::DEFAULT_ADMIN_ROLE;
+        assert!(AccessControl::has_role(&contract, admin_role, Some(accounts.alice)));
+        assert!(AccessControl::has_role(&contract, admin_role, Some(accounts.bob)));
+        assert!(AccessControl::has_role(&contract, MINTER, Some(accounts.charlie)));
+        assert!(AccessControl::has_role(&contract, PAUSER, Some(accounts.django)));
+
+        assert!(!AccessControl::has_role(&contract, admin_role, Some(accounts.charlie)));
+        assert!(!AccessControl::has_role(&contract, MINTER, Some(accounts.django)));
+    }
+
+    #[ink::test]
+    fn bootstrap_emits_events() {
+        let accounts = accounts();
+        let admin_role = access_control::DEFAULT_ADMIN_ROLE;
+        BootstrapStruct::new(
+            accounts.alice,
+            vec![accounts.bob],
+            vec![(MINTER, accounts.charlie), (PAUSER, accounts.django)],
+        );
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 4);
+
+        if let Event::OwnershipTransferred(OwnershipTransferred { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(previous, None);
+            assert_eq!(new, Some(accounts.alice));
+        } else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferred event")
+        }
+
+        let expected_grants = [
+            (admin_role, accounts.bob),
+            (MINTER, accounts.charlie),
+            (PAUSER, accounts.django),
+        ];
+        for (event, (expected_role, expected_grantee)) in emitted_events[1..].iter().zip(expected_grants) {
+            if let Event::RoleGranted(RoleGranted { role, grantee, grantor }) =
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            {
+                assert_eq!(role, expected_role);
+                assert_eq!(grantee, Some(expected_grantee));
+                assert_eq!(grantor, None);
+            } else {
+                panic!("encountered unexpected event kind: expected a RoleGranted event")
+            }
+        }
+    }
+}
@@ -133,4 +133,56 @@ mod access_control_enumerable {
             None
         )
     }
+
+    #[ink::test]
+    fn init_admin_if_empty_grants_admin_on_fresh_contract() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::default();
+
+        enumerable::Internal::_init_admin_if_empty(&mut access_control, Some(accounts.alice));
+
+        assert!(AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&access_control, DEFAULT_ADMIN_ROLE),
+            1
+        );
+    }
+
+    #[ink::test]
+    fn init_admin_if_empty_is_noop_when_admin_exists() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        enumerable::Internal::_init_admin_if_empty(&mut access_control, Some(accounts.bob));
+
+        assert!(!AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.bob)
+        ));
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&access_control, DEFAULT_ADMIN_ROLE),
+            1
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&access_control, DEFAULT_ADMIN_ROLE, 0),
+            Some(accounts.alice)
+        );
+    }
+
+    #[ink::test]
+    fn admin_initialized_if_empty_can_govern() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::default();
+        enumerable::Internal::_init_admin_if_empty(&mut access_control, Some(accounts.alice));
+
+        assert!(AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+        assert!(AccessControl::revoke_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert!(!AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
 }
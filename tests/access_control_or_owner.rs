@@ -0,0 +1,96 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "access_control", feature = "ownable"))]
+#[openbrush::implementation(AccessControl, Ownable)]
+#[openbrush::contract]
+mod access_control_or_owner {
+    use openbrush::{
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MINTER: RoleType = ink::selector_id!("MINTER");
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        value: u32,
+    }
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId, minter: AccountId) -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, owner);
+            access_control::Internal::_init_with_admin(&mut instance, Some(owner));
+            access_control::Internal::_setup_role(&mut instance, MINTER, Some(minter));
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(access_control::only_role_or_owner(MINTER))]
+        pub fn restricted(&mut self) -> Result<(), AccessControlError> {
+            self.value += 1;
+            Ok(())
+        }
+    }
+
+    #[ink::test]
+    fn owner_without_role_can_call() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, accounts.bob);
+        assert!(!AccessControl::has_role(&instance, MINTER, Some(accounts.alice)));
+
+        change_caller(accounts.alice);
+        assert!(instance.restricted().is_ok());
+        assert_eq!(instance.value, 1);
+    }
+
+    #[ink::test]
+    fn role_holder_not_owner_can_call() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, accounts.bob);
+        assert_ne!(Ownable::owner(&instance), Some(accounts.bob));
+
+        change_caller(accounts.bob);
+        assert!(instance.restricted().is_ok());
+        assert_eq!(instance.value, 1);
+    }
+
+    #[ink::test]
+    fn unrelated_account_is_rejected() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, accounts.bob);
+
+        change_caller(accounts.charlie);
+        assert_eq!(instance.restricted(), Err(AccessControlError::MissingRole));
+        assert_eq!(instance.value, 0);
+    }
+}
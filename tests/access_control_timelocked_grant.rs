@@ -0,0 +1,187 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "access_control")]
+#[openbrush::implementation(AccessControl, AccessControlTimelockedGrant)]
+#[openbrush::contract]
+mod access_control_timelocked_grant {
+    use ::ink::env::DefaultEnvironment;
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MINTER: RoleType = ink::selector_id!("MINTER");
+
+    const DELAY: Timestamp = 1000;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        timelocked_grant: timelocked_grant::Data,
+    }
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId, delay: Timestamp) -> Self {
+            let mut instance = Self::default();
+
+            access_control::Internal::_init_with_admin(&mut instance, Some(admin));
+            timelocked_grant::Internal::_set_grant_delay(&mut instance, delay);
+
+            instance
+        }
+    }
+
+    fn set_time(timestamp: Timestamp) {
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+    }
+
+    #[ink::test]
+    fn schedule_grant_works() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+        set_time(100);
+
+        assert!(AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+
+        assert_eq!(
+            AccessControlTimelockedGrant::grant_ready_at(&access_control, MINTER, Some(accounts.bob)),
+            Some(100 + DELAY)
+        );
+        assert!(!AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
+
+    #[ink::test]
+    fn schedule_grant_requires_admin() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+
+    #[ink::test]
+    fn execute_grant_fails_before_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+        set_time(100);
+        assert!(AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+
+        set_time(100 + DELAY - 1);
+        assert_eq!(
+            AccessControlTimelockedGrant::execute_grant(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::GrantNotReady)
+        );
+        assert!(!AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
+
+    #[ink::test]
+    fn execute_grant_works_after_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+        set_time(100);
+        assert!(AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+
+        set_time(100 + DELAY);
+        assert!(AccessControlTimelockedGrant::execute_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+
+        assert!(AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControlTimelockedGrant::grant_ready_at(&access_control, MINTER, Some(accounts.bob)),
+            None
+        );
+    }
+
+    #[ink::test]
+    fn execute_grant_fails_if_not_scheduled() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            AccessControlTimelockedGrant::execute_grant(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::GrantNotScheduled)
+        );
+    }
+
+    #[ink::test]
+    fn cancel_grant_prevents_grant() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+        set_time(100);
+        assert!(AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+
+        assert!(AccessControlTimelockedGrant::cancel_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControlTimelockedGrant::grant_ready_at(&access_control, MINTER, Some(accounts.bob)),
+            None
+        );
+
+        set_time(100 + DELAY);
+        assert_eq!(
+            AccessControlTimelockedGrant::execute_grant(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::GrantNotScheduled)
+        );
+        assert!(!AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
+
+    #[ink::test]
+    fn grant_role_can_not_skip_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, DELAY);
+        set_time(100);
+
+        assert_eq!(
+            AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::GrantNotScheduled)
+        );
+
+        assert!(AccessControlTimelockedGrant::schedule_grant(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)),
+            Err(AccessControlError::GrantNotReady)
+        );
+        assert!(!AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+
+        set_time(100 + DELAY);
+        assert!(AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
+
+    #[ink::test]
+    fn grant_role_works_without_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice, 0);
+
+        assert!(AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::has_role(&access_control, MINTER, Some(accounts.bob)));
+    }
+}
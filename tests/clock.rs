@@ -0,0 +1,146 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Cooldown, PSP22Permit, Nonces)]
+#[openbrush::contract]
+mod clock {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const COOLDOWN_SECS: u64 = 60;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        cooldown: cooldown::Data,
+        #[storage_field]
+        nonces: nonces::Data,
+        #[storage_field]
+        permit: permit::Data,
+        // The mocked time, the block timestamp is used if it is not set
+        mock_now: Option<Timestamp>,
+    }
+
+    #[overrider(Clock)]
+    fn now(&self) -> Timestamp {
+        self.mock_now.unwrap_or_else(|| ClockImpl::now(self))
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        cooldown::Internal::_check_cooldown(self, from, to)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            cooldown::Internal::_set_cooldown_secs(&mut instance, COOLDOWN_SECS);
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+
+        pub fn advance_time(&mut self, millis: Timestamp) {
+            self.mock_now = Some(Clock::now(self) + millis);
+        }
+    }
+
+    #[ink::test]
+    fn now_is_block_timestamp_by_default() {
+        set_block_timestamp::<DefaultEnvironment>(1_000);
+        let psp22 = PSP22Struct::new(100);
+
+        assert_eq!(Clock::now(&psp22), 1_000);
+    }
+
+    #[ink::test]
+    fn cooldown_uses_mocked_time() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        psp22.mock_now = Some(1_000);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+        assert_eq!(PSP22Cooldown::last_transfer_time(&psp22, accounts.alice), Some(1_000));
+
+        psp22.advance_time(COOLDOWN_SECS * 1000 - 1);
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.charlie, 10, vec![]),
+            Err(PSP22Error::Custom(String::from("Cooldown")))
+        );
+
+        psp22.advance_time(1);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 10, vec![]).is_ok());
+
+        // The blocks didn't advance
+        assert_eq!(ink::env::block_timestamp::<DefaultEnvironment>(), 0);
+    }
+
+    #[ink::test]
+    fn permit_deadline_uses_mocked_time() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        psp22.mock_now = Some(5_000);
+
+        // The deadline is checked before the signature, so a valid deadline fails on the signature
+        assert_eq!(
+            PSP22Permit::permit(
+                &mut psp22,
+                accounts.alice,
+                accounts.bob,
+                10,
+                5_000,
+                Signature::ECDSA([0; 65])
+            ),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+
+        psp22.advance_time(1);
+        assert_eq!(
+            PSP22Permit::permit(
+                &mut psp22,
+                accounts.alice,
+                accounts.bob,
+                10,
+                5_000,
+                Signature::ECDSA([0; 65])
+            ),
+            Err(PSP22Error::PermitExpired)
+        );
+    }
+}
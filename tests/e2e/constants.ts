@@ -5,5 +5,7 @@ export const consts = {
 export const Roles = {
   DefaultAdminRole: 0,
   Minter: 0xfd9ab216,
-  Manager: 0x73a5ca6d
+  Manager: 0x73a5ca6d,
+  Proposer: 0xec2f41cc,
+  Executor: 0x5b5d2553
 }
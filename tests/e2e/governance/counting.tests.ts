@@ -9,6 +9,7 @@ import ContractReceiver from '../../../typechain-generated/contracts/mock_receiv
 import {GovernorHelper} from './helper'
 import BN from 'bn.js'
 import {VoteType} from '../../../typechain-generated/types-arguments/my_governor'
+import {ProposalState} from '../../../typechain-generated/types-returns/my_governor'
 import {number} from '@noble/hashes/_assert'
 
 describe('Counting', function () {
@@ -145,4 +146,62 @@ describe('Counting', function () {
       await api.disconnect()
     })
   })
+
+  describe('abstain votes toward quorum', function () {
+    // The quorum is 50% of the total supply of 30 votes, so 15 votes are needed
+    const NUMRATOR = 50
+
+    it('count by default', async () => {
+      const {
+        api,
+        contractGovernance
+      } = await setup(30, 10, 10, 0, NUMRATOR)
+
+      expect((await contractGovernance.query.abstainCountsTowardQuorum()).value.ok!).to.be.true
+
+      await api.disconnect()
+    })
+
+    it('proposal succeeds when the quorum is reached through abstain votes', async () => {
+      const {
+        api,
+        alice,
+        deployer,
+        helper
+      } = await setup(30, 10, 10, 0, NUMRATOR)
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+      await expect(helper.castVote(alice, VoteType.for)).to.eventually.be.fulfilled
+      await expect(helper.castVote(deployer, VoteType.abstain)).to.eventually.be.fulfilled
+      await helper.waitForDeadline(1)
+
+      await expect(helper.state()).to.eventually.be.equals(ProposalState.succeeded)
+
+      await api.disconnect()
+    })
+
+    it('proposal is defeated when abstain votes do not count', async () => {
+      const {
+        api,
+        alice,
+        deployer,
+        contractGovernance,
+        helper
+      } = await setup(30, 10, 10, 0, NUMRATOR)
+
+      await expect(contractGovernance.tx.setAbstainCountsTowardQuorum(false)).to.eventually.be.fulfilled
+      expect((await contractGovernance.query.abstainCountsTowardQuorum()).value.ok!).to.be.false
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+      await expect(helper.castVote(alice, VoteType.for)).to.eventually.be.fulfilled
+      await expect(helper.castVote(deployer, VoteType.abstain)).to.eventually.be.fulfilled
+      await helper.waitForDeadline(1)
+
+      await expect(helper.state()).to.eventually.be.equals(ProposalState.defeated)
+
+      await api.disconnect()
+    })
+  })
 })
\ No newline at end of file
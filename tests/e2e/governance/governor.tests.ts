@@ -1,4 +1,5 @@
 import {ApiPromise} from '@polkadot/api'
+import {Keyring} from '@polkadot/keyring'
 import {getMessageByName, getSelectorByName, getSigners, SS58ToHex} from '../helpers'
 import ConstructorsGovernance from '../../../typechain-generated/constructors/my_governor'
 import ContractGovernance from '../../../typechain-generated/contracts/my_governor'
@@ -117,6 +118,18 @@ describe('Governor', function () {
 
         await api.disconnect()
       })
+
+      it('if proposer votes are below proposal threshold', async function () {
+        const {
+          api,
+          alice,
+          helper
+        } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 11)
+
+        await expect(helper.propose(alice)).to.eventually.be.rejected
+
+        await api.disconnect()
+      })
     })
 
     describe('on vote', function () {
@@ -184,12 +197,47 @@ describe('Governor', function () {
     })
 
     describe('on vote by signature', function () {
-      it('if signature does not match signer', async function () {
-        //
+      it('if signature is tampered', async function () {
+        const {
+          api,
+          alice,
+          helper
+        } = await setup()
+
+        const voter = new Keyring({type: 'ecdsa'}).addFromUri('//Dave')
+
+        await expect(helper.propose()).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+
+        // corrupt the recovery id, so the signer can't be recovered
+        const signature = helper.signVote(voter, VoteType.for)
+        signature[64] = 0xff
+
+        await expect(helper.castVoteBySig(alice, VoteType.for, signature)).to.eventually.be.rejected
+        expect(await helper.hasVoted(voter)).to.be.false
+
+        await api.disconnect()
       })
 
-      it('if vote nonce is incorrect', async function () {
-        //
+      it('if signature is replayed', async function () {
+        const {
+          api,
+          alice,
+          bob,
+          helper
+        } = await setup()
+
+        const voter = new Keyring({type: 'ecdsa'}).addFromUri('//Dave')
+
+        await expect(helper.propose()).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+
+        const signature = helper.signVote(voter, VoteType.for)
+
+        await expect(helper.castVoteBySig(alice, VoteType.for, signature)).to.eventually.be.fulfilled
+        await expect(helper.castVoteBySig(bob, VoteType.for, signature)).to.eventually.be.rejected
+
+        await api.disconnect()
       })
     })
 
@@ -317,6 +365,73 @@ describe('Governor', function () {
     })
   })
 
+  describe('vote with reason', function () {
+    it('emits the reason in VoteCast event', async function () {
+      const {
+        api,
+        alice,
+        helper
+      } = await setup()
+
+      await expect(helper.propose()).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+
+      const result = await helper.castVoteWithReason(alice, VoteType.for, 'I like it')
+      const event = result?.events?.find(e => e.name === 'VoteCast')
+
+      expect(event?.args.reason).to.be.eq('I like it')
+      expect(event?.args.voter).to.be.eq(alice.address)
+      expect(await helper.hasVoted(alice)).to.be.true
+
+      await api.disconnect()
+    })
+  })
+
+  describe('vote by signature', function () {
+    it('counts the vote for the recovered signer', async function () {
+      const {
+        api,
+        alice,
+        helper
+      } = await setup()
+
+      const voter = new Keyring({type: 'ecdsa'}).addFromUri('//Dave')
+
+      await expect(helper.propose()).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+
+      const signature = helper.signVote(voter, VoteType.for)
+
+      await expect(helper.castVoteBySig(alice, VoteType.for, signature)).to.eventually.be.fulfilled
+      expect(await helper.hasVoted(voter)).to.be.true
+      expect(await helper.hasVoted(alice)).to.be.false
+
+      await api.disconnect()
+    })
+
+    it('does not count a signature made for another governor', async function () {
+      const {
+        api,
+        alice,
+        contractAddressVotes,
+        helper
+      } = await setup()
+
+      const voter = new Keyring({type: 'ecdsa'}).addFromUri('//Dave')
+
+      await expect(helper.propose()).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+
+      // The signed message contains the address of another contract, so another account is recovered
+      const signature = helper.signVote(voter, VoteType.for, undefined, contractAddressVotes)
+
+      await helper.castVoteBySig(alice, VoteType.for, signature)
+      expect(await helper.hasVoted(voter)).to.be.false
+
+      await api.disconnect()
+    })
+  })
+
   describe('state', function () {
     it('Unset', async function () {
       const {
@@ -403,6 +518,55 @@ describe('Governor', function () {
     })
   })
 
+  describe('proposal id', function () {
+    it('is rebuilt from the same params on execute', async function () {
+      const {
+        api,
+        deployer,
+        helper
+      } = await setup()
+
+      const proposalId = await helper.getProposalId()
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+      expect(await helper.hashProposal()).to.be.deep.eq(proposalId)
+
+      await helper.waitForSnapshot()
+      await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+      await helper.waitForDeadline(1)
+
+      await expect(helper.execute()).to.eventually.be.fulfilled
+      expect(await helper.state()).to.be.eq(ProposalState.executed)
+
+      await api.disconnect()
+    })
+
+    it('differs for altered params, which are not found on execute', async function () {
+      const {
+        api,
+        deployer,
+        contractGovernance,
+        helper
+      } = await setup()
+
+      const proposalId = await helper.getProposalId()
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+      await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+      await helper.waitForDeadline(1)
+
+      const alteredId = await helper.hashProposal('<altered description>')
+      expect(alteredId).to.not.be.deep.eq(proposalId)
+      expect((await contractGovernance.query.state(alteredId as unknown as number[])).value.ok!.ok).to.be.eq(undefined)
+
+      await expect(helper.execute(undefined, '<altered description>')).to.eventually.be.rejected
+      expect(await helper.state()).to.be.eq(ProposalState.succeeded)
+
+      await api.disconnect()
+    })
+  })
+
   describe('cancel', function () {
     describe('public', function () {
       it('before proposal', async function () {
@@ -517,6 +681,195 @@ describe('Governor', function () {
         await api.disconnect()
       })
     })
+
+    describe('guardian', function () {
+      it('can cancel pending proposal', async function () {
+        const {
+          api,
+          alice,
+          deployer,
+          contractGovernance,
+          helper
+        } = await setup()
+
+        await contractGovernance.tx.setGuardian(alice.address)
+        expect((await contractGovernance.query.guardian()).value.ok!).to.be.eq(alice.address)
+
+        await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+        await expect(helper.cancel(alice)).to.eventually.be.fulfilled
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.canceled)
+
+        await api.disconnect()
+      })
+
+      it('can cancel proposal after vote started', async function () {
+        const {
+          api,
+          alice,
+          deployer,
+          contractGovernance,
+          helper
+        } = await setup()
+
+        await contractGovernance.tx.setGuardian(alice.address)
+
+        await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+        await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+        await expect(helper.cancel(alice)).to.eventually.be.fulfilled
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.canceled)
+
+        await api.disconnect()
+      })
+
+      it('rejects non-guardian and non-proposer', async function () {
+        const {
+          api,
+          alice,
+          bob,
+          deployer,
+          contractGovernance,
+          helper
+        } = await setup()
+
+        await contractGovernance.tx.setGuardian(alice.address)
+
+        await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+        await expect(helper.cancel(bob)).to.eventually.be.rejected
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.pending)
+
+        await api.disconnect()
+      })
+
+      it('canceled proposal can not be executed', async function () {
+        const {
+          api,
+          alice,
+          deployer,
+          contractGovernance,
+          helper
+        } = await setup()
+
+        await contractGovernance.tx.setGuardian(alice.address)
+
+        await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+        await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+        await helper.waitForDeadline(1)
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.succeeded)
+
+        await expect(helper.cancel(alice)).to.eventually.be.fulfilled
+        await expect(helper.execute()).to.eventually.be.rejected
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.canceled)
+
+        await api.disconnect()
+      })
+    })
+
+    describe('proposer below threshold', function () {
+      it('can cancel proposal after vote started', async function () {
+        const {
+          api,
+          alice,
+          bob,
+          contractVotes,
+          helper
+        } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 10)
+
+        await expect(helper.propose(alice)).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+
+        await contractVotes.withSigner(alice).tx.transfer(bob.address, 5, [])
+        await helper.increaseBlockTimestamp(1)
+
+        await expect(helper.cancel(alice)).to.eventually.be.fulfilled
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.canceled)
+
+        await api.disconnect()
+      })
+
+      it('can not cancel proposal after vote started while above threshold', async function () {
+        const {
+          api,
+          alice,
+          helper
+        } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 10)
+
+        await expect(helper.propose(alice)).to.eventually.be.fulfilled
+        await helper.waitForSnapshot(1)
+
+        await expect(helper.cancel(alice)).to.eventually.be.rejected
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.active)
+
+        await api.disconnect()
+      })
+
+      it('only the proposer can cancel', async function () {
+        const {
+          api,
+          alice,
+          bob,
+          contractVotes,
+          helper
+        } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 10)
+
+        await expect(helper.propose(alice)).to.eventually.be.fulfilled
+        await helper.waitForSnapshot()
+
+        await contractVotes.withSigner(alice).tx.transfer(bob.address, 5, [])
+        await helper.increaseBlockTimestamp(1)
+
+        await expect(helper.cancel(bob)).to.eventually.be.rejected
+        await expect(helper.state()).to.eventually.be.equals(ProposalState.active)
+
+        await api.disconnect()
+      })
+    })
+  })
+
+  describe('proposal threshold', function () {
+    it('proposer with votes equal to threshold can propose', async function () {
+      const {
+        api,
+        alice,
+        helper
+      } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 10)
+
+      await expect(helper.propose(alice)).to.eventually.be.fulfilled
+
+      await api.disconnect()
+    })
+
+    it('proposer with votes above threshold can propose', async function () {
+      const {
+        api,
+        deployer,
+        helper
+      } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 10)
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+
+      await api.disconnect()
+    })
+
+    it('threshold is checked against votes at propose time', async function () {
+      const {
+        api,
+        alice,
+        deployer,
+        contractVotes,
+        helper
+      } = await setup(TOTAL_SUPPLY, VOTING_DELAY, VOTING_PERIOD, 20)
+
+      await expect(helper.propose(alice)).to.eventually.be.rejected
+
+      await helper.delegate(contractVotes, deployer, alice, 10)
+      await helper.increaseBlockTimestamp(1)
+
+      await expect(helper.propose(alice)).to.eventually.be.fulfilled
+
+      await api.disconnect()
+    })
   })
 
   describe('proposal length', function () {
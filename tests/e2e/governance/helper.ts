@@ -2,8 +2,9 @@ import BN from 'bn.js'
 import {Transaction, VoteType} from '../../../typechain-generated/types-arguments/my_governor'
 import ContractGovernance from '../../../typechain-generated/contracts/my_governor'
 import {KeyringPair} from '@polkadot/keyring/types'
-import {blake2AsU8a} from '@polkadot/util-crypto'
+import {decodeAddress, keccakAsU8a} from '@polkadot/util-crypto'
 import ContractVotes from '../../../typechain-generated/contracts/my_psp22_votes'
+import ContractTimelock from '../../../typechain-generated/contracts/my_timelock_controller'
 import {hexToNumbers} from '../helpers'
 
 export class GovernorHelper {
@@ -117,6 +118,45 @@ export class GovernorHelper {
     await this.governor?.withSigner(voter).tx.castVote(this.proposalId as unknown as number[], vote, null, null)
   }
 
+  async castVoteWithReason(voter: KeyringPair, vote: VoteType, reason: string) {
+    if (this.proposal === undefined || this.description === undefined){
+      throw new Error('Proposal not set')
+    }
+
+    if(this.proposalId === undefined) {
+      this.proposalId = await this.getProposalId()
+    }
+
+    return await this.governor?.withSigner(voter).tx.castVoteWithReason(this.proposalId as unknown as number[], vote, reason)
+  }
+
+  signVote(signer: KeyringPair, vote: VoteType, proposalId?: number[], governor = this.governor!.address) {
+    if(proposalId === undefined) {
+      proposalId = this.proposalId
+    }
+
+    if(proposalId === undefined) {
+      throw new Error('Proposal id not set')
+    }
+
+    // SCALE encoding of `(governor, proposal_id, support)`, the ecdsa pair hashes it with blake2 before signing
+    const message = new Uint8Array([...decodeAddress(governor), ...proposalId, Object.values(VoteType).indexOf(vote)])
+
+    return Array.from(signer.sign(message))
+  }
+
+  async castVoteBySig(sender: KeyringPair, vote: VoteType, signature: number[]) {
+    if (this.proposal === undefined || this.description === undefined){
+      throw new Error('Proposal not set')
+    }
+
+    if(this.proposalId === undefined) {
+      this.proposalId = await this.getProposalId()
+    }
+
+    await this.governor?.withSigner(sender).tx.castVoteBySig(this.proposalId as unknown as number[], vote, {ecdsa: signature})
+  }
+
   async waitForDeadline(offset = 0) {
     if (this.proposal === undefined || this.description === undefined){
       throw new Error('Proposal not set')
@@ -134,7 +174,19 @@ export class GovernorHelper {
     await this.token?.tx.setBlockTimestamp(proposalDeadline + offset)
   }
 
-  async execute(proposer?: KeyringPair) {
+  descriptionHash(description = this.description!) {
+    return keccakAsU8a(description) as unknown as number[]
+  }
+
+  async hashProposal(description = this.description!) {
+    if (this.proposal === undefined) {
+      throw new Error('Proposal not set')
+    }
+
+    return (await this.governor?.query.hashProposal([this.proposal!], this.descriptionHash(description)))?.value.ok!.ok
+  }
+
+  async execute(proposer?: KeyringPair, description = this.description!) {
     if (this.proposal === undefined || this.description === undefined){
       throw new Error('Proposal not set')
     }
@@ -143,7 +195,7 @@ export class GovernorHelper {
       this.proposalId = await this.getProposalId()
     }
 
-    const descriptionHash = blake2AsU8a(this.description!) as unknown as number[]
+    const descriptionHash = this.descriptionHash(description)
 
     if (proposer) {
       await this.governor?.withSigner(proposer).tx.execute([this.proposal!], descriptionHash)
@@ -153,6 +205,25 @@ export class GovernorHelper {
     }
   }
 
+  async queue(proposer?: KeyringPair) {
+    if (this.proposal === undefined || this.description === undefined){
+      throw new Error('Proposal not set')
+    }
+
+    if(this.proposalId === undefined) {
+      this.proposalId = await this.getProposalId()
+    }
+
+    const descriptionHash = this.descriptionHash()
+
+    if (proposer) {
+      await this.governor?.withSigner(proposer).tx.queue([this.proposal!], descriptionHash)
+    }
+    else {
+      await this.governor?.tx.queue([this.proposal!], descriptionHash)
+    }
+  }
+
   async cancel(proposer?: KeyringPair) {
     if (this.proposal === undefined || this.description === undefined){
       throw new Error('Proposal not set')
@@ -162,7 +233,7 @@ export class GovernorHelper {
       this.proposalId = await this.getProposalId()
     }
 
-    const descriptionHash = blake2AsU8a(this.description!) as unknown as number[]
+    const descriptionHash = this.descriptionHash()
 
     if (proposer) {
       await this.governor?.withSigner(proposer).tx.cancel([this.proposal!], descriptionHash)
@@ -172,6 +243,17 @@ export class GovernorHelper {
     }
   }
 
+  async isQueuedInTimelock(timelock: ContractTimelock): Promise<boolean> {
+    if (this.proposal === undefined || this.description === undefined){
+      throw new Error('Proposal not set')
+    }
+
+    const descriptionHash = this.descriptionHash()
+    const operationId = (await timelock.query.hashOperationBatch([this.proposal!], null, descriptionHash)).value.ok!
+
+    return (await timelock.query.isOperationPending(operationId)).value.ok!
+  }
+
   async state() {
     if (this.proposal === undefined || this.description === undefined){
       throw new Error('Proposal not set')
@@ -184,6 +266,18 @@ export class GovernorHelper {
     return (await this.governor?.query.state(this.proposalId as unknown as number[]))?.value.ok?.ok
   }
 
+  async proposalEta() {
+    if (this.proposal === undefined || this.description === undefined){
+      throw new Error('Proposal not set')
+    }
+
+    if(this.proposalId === undefined) {
+      this.proposalId = await this.getProposalId()
+    }
+
+    return (await this.governor?.query.proposalEta(this.proposalId as unknown as number[]))?.value.ok
+  }
+
   async hasVoted(voter: KeyringPair) {
     if (this.proposal === undefined || this.description === undefined){
       throw new Error('Proposal not set')
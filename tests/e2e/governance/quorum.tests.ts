@@ -184,5 +184,50 @@ describe('QuorumImpl', () => {
 
       await api.disconnect()
     })
+
+    it('applies the quorum numerator active at the proposal snapshot', async function () {
+      const {api, alice, deployer, contractGovernance, contractVotes, contractReceiver, helper} = await setup()
+
+      // Proposal that sets the quorum to 1% of the total supply (1000 votes)
+      const updateHelper = new GovernorHelper(contractGovernance, contractVotes)
+      const updateParams = helper.paramsToInput(getMessageByName(contractGovernance.abi.messages, 'update_quorum_numerator').toU8a([1]))
+      updateHelper.addProposal(contractGovernance.address, updateParams.selector, updateParams.data, '<update description>')
+
+      // Both proposals are created before the change of the quorum numerator
+      await expect(updateHelper.propose()).to.eventually.be.fulfilled
+      await expect(helper.propose()).to.eventually.be.fulfilled
+
+      await updateHelper.waitForSnapshot()
+
+      await expect(updateHelper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+      // 10 votes are enough while the quorum numerator is 0
+      await expect(helper.castVote(alice, VoteType.for)).to.eventually.be.fulfilled
+
+      await updateHelper.waitForDeadline(1)
+
+      await expect(updateHelper.execute()).to.eventually.be.fulfilled
+      expect((await contractGovernance.query.quorumNumerator()).value.ok!.toNumber()).to.be.equal(1)
+
+      // The proposal created before the change uses the old quorum numerator
+      await expect(helper.execute()).to.eventually.be.fulfilled
+
+      // The proposal created after the change uses the new quorum numerator
+      const newHelper = new GovernorHelper(contractGovernance, contractVotes)
+      const callParams = helper.paramsToInput(getMessageByName(contractReceiver.abi.messages, 'mock_function').toU8a([]))
+      newHelper.addProposal(contractReceiver.address, callParams.selector, callParams.data, '<new description>')
+
+      await expect(newHelper.propose()).to.eventually.be.fulfilled
+
+      await newHelper.waitForSnapshot()
+
+      // 10 votes are not enough to reach the quorum of 1000 votes
+      await expect(newHelper.castVote(alice, VoteType.for)).to.eventually.be.fulfilled
+
+      await newHelper.waitForDeadline(1)
+
+      await expect(newHelper.execute()).to.eventually.be.rejected
+
+      await api.disconnect()
+    })
   })
 })
@@ -0,0 +1,212 @@
+import {ApiPromise} from '@polkadot/api'
+import {getMessageByName, getSigners} from '../helpers'
+import {Roles} from '../constants'
+import ConstructorsGovernance from '../../../typechain-generated/constructors/my_governor'
+import ContractGovernance from '../../../typechain-generated/contracts/my_governor'
+
+import ConstructorsReceiver from '../../../typechain-generated/constructors/mock_receiver'
+import ContractReceiver from '../../../typechain-generated/contracts/mock_receiver'
+
+import ConstructorsTimelock from '../../../typechain-generated/constructors/my_timelock_controller'
+import ContractTimelock from '../../../typechain-generated/contracts/my_timelock_controller'
+
+import {VoteType} from '../../../typechain-generated/types-arguments/my_governor'
+
+import ConstructorsVotes from '../../../typechain-generated/constructors/my_psp22_votes'
+import ContractVotes from '../../../typechain-generated/contracts/my_psp22_votes'
+import {expect} from 'chai'
+import {ProposalState} from '../../../typechain-generated/types-returns/my_governor'
+import {GovernorHelper} from './helper'
+
+const  MIN_DELAY = 2000
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms))
+
+describe('GovernorTimelock', function () {
+
+  async function setup(
+    totalSupply = 100000,
+    votingDelay = 10,
+    votingPeriod = 10,
+    proposalThreshold = 0,
+    numrator = 0
+  ){
+    const api = await ApiPromise.create()
+
+    const signers = getSigners()
+    const deployer = signers[0]
+    const alice = signers[1]
+
+    const contractFactoryVotes = new ConstructorsVotes(api, deployer)
+    const contractAddressVotes = (await contractFactoryVotes.new(totalSupply)).address
+    const contractVotes = new ContractVotes(contractAddressVotes, deployer, api)
+
+    const contractFactoryTimelock = new ConstructorsTimelock(api, deployer)
+    const contractAddressTimelock = (await contractFactoryTimelock.new(MIN_DELAY, [], [])).address
+    const contractTimelock = new ContractTimelock(contractAddressTimelock, deployer, api)
+
+    const contractFactoryGovernance = new ConstructorsGovernance(api, deployer)
+    const contractAddressGovernance = (await contractFactoryGovernance.newWithTimelock(contractAddressVotes, votingDelay, votingPeriod, proposalThreshold, numrator, contractAddressTimelock)).address
+    const contractGovernance = new ContractGovernance(contractAddressGovernance, deployer, api)
+
+    // The governor schedules and executes the proposals in the timelock
+    await contractTimelock.tx.grantRole(Roles.Proposer, contractAddressGovernance)
+    await contractTimelock.tx.grantRole(Roles.Executor, contractAddressGovernance)
+
+    await contractVotes.tx.setBlockTimestamp((await contractGovernance.query.blockTimestamp()).value.ok!)
+
+    const contractFactoryReceiver = new ConstructorsReceiver(api, deployer)
+    const contractAddressReceiver = (await contractFactoryReceiver.new()).address
+    const contractReceiver = new ContractReceiver(contractAddressReceiver, deployer, api)
+
+    const helper = new GovernorHelper(contractGovernance, contractVotes)
+
+    await helper.delegate(contractVotes, deployer, alice, 10)
+    await helper.delegate(contractVotes, deployer, deployer, 10)
+
+    const callParams = helper.paramsToInput(getMessageByName(contractReceiver.abi.messages, 'mock_function').toU8a([]))
+
+    helper.addProposal(
+      contractAddressReceiver,
+      callParams.selector,
+      callParams.data,
+      '<description>'
+    )
+
+    return {
+      api,
+      alice,
+      deployer,
+      contractGovernance,
+      contractTimelock,
+      contractAddressTimelock,
+      contractReceiver,
+      helper
+    }
+  }
+
+  async function succeededProposal() {
+    const context = await setup()
+    const {deployer, alice, helper} = context
+
+    await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+    await helper.waitForSnapshot()
+    await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+    await expect(helper.castVote(alice, VoteType.for)).to.eventually.be.fulfilled
+    await helper.waitForDeadline(1)
+
+    return context
+  }
+
+  it('deployment check', async function () {
+    const {
+      api,
+      contractGovernance,
+      contractAddressTimelock
+    } = await setup()
+
+    expect((await contractGovernance.query.timelock()).value.ok!).to.be.eq(contractAddressTimelock)
+
+    await api.disconnect()
+  })
+
+  it('runs a proposal through voting, queueing and execution after the delay', async function () {
+    this.timeout(60000)
+
+    const {
+      api,
+      contractReceiver,
+      helper
+    } = await succeededProposal()
+
+    await expect(helper.state()).to.eventually.be.equals(ProposalState.succeeded)
+
+    await expect(helper.queue()).to.eventually.be.fulfilled
+    await expect(helper.state()).to.eventually.be.equals(ProposalState.queued)
+
+    expect(await helper.proposalEta()).to.be.gt(0)
+
+    // The timelock delay hasn't passed yet
+    await expect(helper.execute()).to.eventually.be.rejected
+    await expect(helper.state()).to.eventually.be.equals(ProposalState.queued)
+
+    await sleep(MIN_DELAY + 1000)
+
+    await expect(helper.execute()).to.eventually.be.fulfilled
+    await expect(helper.state()).to.eventually.be.equals(ProposalState.executed)
+
+    // `mock_function` was called once by the timelock, so the dry run returns 2
+    expect((await contractReceiver.query.mockFunction()).value.ok!).to.be.eq(2)
+
+    await api.disconnect()
+  })
+
+  it('guardian cancels a queued proposal in the timelock', async function () {
+    this.timeout(60000)
+
+    const {
+      api,
+      alice,
+      contractGovernance,
+      contractTimelock,
+      helper
+    } = await succeededProposal()
+
+    await contractGovernance.tx.setGuardian(alice.address)
+
+    await expect(helper.queue()).to.eventually.be.fulfilled
+    expect(await helper.isQueuedInTimelock(contractTimelock)).to.be.true
+
+    await expect(helper.cancel(alice)).to.eventually.be.fulfilled
+    await expect(helper.state()).to.eventually.be.equals(ProposalState.canceled)
+    expect(await helper.isQueuedInTimelock(contractTimelock)).to.be.false
+
+    await sleep(MIN_DELAY + 1000)
+
+    await expect(helper.execute()).to.eventually.be.rejected
+
+    await api.disconnect()
+  })
+
+  describe('should revert', function () {
+    it('on execute if proposal is not queued', async function () {
+      const {
+        api,
+        helper
+      } = await succeededProposal()
+
+      await expect(helper.execute()).to.eventually.be.rejected
+      await expect(helper.state()).to.eventually.be.equals(ProposalState.succeeded)
+
+      await api.disconnect()
+    })
+
+    it('on queue if voting is not over', async function () {
+      const {
+        api,
+        deployer,
+        helper
+      } = await setup()
+
+      await expect(helper.propose(deployer)).to.eventually.be.fulfilled
+      await helper.waitForSnapshot()
+      await expect(helper.castVote(deployer, VoteType.for)).to.eventually.be.fulfilled
+
+      await expect(helper.queue()).to.eventually.be.rejected
+
+      await api.disconnect()
+    })
+
+    it('on queue if proposal is already queued', async function () {
+      const {
+        api,
+        helper
+      } = await succeededProposal()
+
+      await expect(helper.queue()).to.eventually.be.fulfilled
+      await expect(helper.queue()).to.eventually.be.rejected
+
+      await api.disconnect()
+    })
+  })
+})
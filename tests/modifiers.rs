@@ -0,0 +1,137 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[openbrush::contract]
+mod modifiers {
+    use ink::prelude::vec::Vec;
+    use openbrush::{
+        modifier_definition,
+        modifiers,
+        traits::String,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        log: Vec<String>,
+    }
+
+    impl Contract {
+        fn record(&mut self, entry: &str) {
+            self.log.push(String::from(entry));
+        }
+    }
+
+    #[modifier_definition]
+    fn a<F, R>(instance: &mut Contract, body: F) -> R
+    where
+        F: FnOnce(&mut Contract) -> R,
+    {
+        instance.record("A-before");
+        let result = body(instance);
+        instance.record("A-after");
+        result
+    }
+
+    #[modifier_definition]
+    fn b<F, R>(instance: &mut Contract, body: F) -> R
+    where
+        F: FnOnce(&mut Contract) -> R,
+    {
+        instance.record("B-before");
+        let result = body(instance);
+        instance.record("B-after");
+        result
+    }
+
+    #[modifier_definition]
+    fn c<F, R>(instance: &mut Contract, body: F) -> R
+    where
+        F: FnOnce(&mut Contract) -> R,
+    {
+        instance.record("C-before");
+        let result = body(instance);
+        instance.record("C-after");
+        result
+    }
+
+    #[modifier_definition]
+    fn tagged<F, R>(instance: &mut Contract, body: F, tag: String) -> R
+    where
+        F: FnOnce(&mut Contract) -> R,
+    {
+        instance.record(&(tag.clone() + "-before"));
+        let result = body(instance);
+        instance.record(&(tag + "-after"));
+        result
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        #[modifiers(a, b, c)]
+        pub fn ordered(&mut self) -> u8 {
+            self.record("body");
+            42
+        }
+
+        #[ink(message)]
+        #[modifiers(a, tagged(String::from("B")), c)]
+        pub fn ordered_with_arguments(&mut self) -> u8 {
+            self.record("body");
+            42
+        }
+
+        #[ink(message)]
+        pub fn log(&self) -> Vec<String> {
+            self.log.clone()
+        }
+    }
+
+    fn expected_log() -> Vec<String> {
+        [
+            "A-before", "B-before", "C-before", "body", "C-after", "B-after", "A-after",
+        ]
+        .iter()
+        .map(|entry| String::from(*entry))
+        .collect()
+    }
+
+    #[ink::test]
+    fn modifiers_are_executed_in_order() {
+        let mut instance = Contract::new();
+
+        assert_eq!(instance.ordered(), 42);
+        assert_eq!(instance.log(), expected_log());
+    }
+
+    #[ink::test]
+    fn modifiers_with_arguments_are_executed_in_order() {
+        let mut instance = Contract::new();
+
+        assert_eq!(instance.ordered_with_arguments(), 42);
+        assert_eq!(instance.log(), expected_log());
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "multicall")]
+#[openbrush::implementation(Multicall)]
+#[openbrush::contract]
+mod multicall_allowlist {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    const GET: [u8; 4] = ink::selector_bytes!("get");
+    const SET: [u8; 4] = ink::selector_bytes!("set");
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MulticallStruct {
+        #[storage_field]
+        multicall: multicall::Data,
+    }
+
+    impl MulticallStruct {
+        #[ink(constructor)]
+        pub fn new(callee: AccountId) -> Self {
+            let mut instance = Self::default();
+            multicall::Internal::_set_call_allowed(&mut instance, callee, GET, true);
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn set_call_allowed_works() {
+        let accounts = accounts();
+        let mut multicall = MulticallStruct::new(accounts.bob);
+
+        assert!(multicall::Internal::_is_call_allowed(&multicall, &accounts.bob, &GET));
+        assert!(!multicall::Internal::_is_call_allowed(&multicall, &accounts.bob, &SET));
+        assert!(!multicall::Internal::_is_call_allowed(
+            &multicall,
+            &accounts.charlie,
+            &GET
+        ));
+
+        multicall::Internal::_set_call_allowed(&mut multicall, accounts.bob, GET, false);
+        assert!(!multicall::Internal::_is_call_allowed(&multicall, &accounts.bob, &GET));
+    }
+
+    #[ink::test]
+    fn multicall_rejects_not_allowed_calls() {
+        let accounts = accounts();
+        let multicall = MulticallStruct::new(accounts.bob);
+
+        let mut set = SET.to_vec();
+        set.extend(scale::Encode::encode(&7u32));
+
+        // Neither call is performed, otherwise the off-chain environment would panic
+        assert_eq!(
+            Multicall::multicall(&multicall, vec![(accounts.bob, set), (accounts.charlie, GET.to_vec())]),
+            vec![Err(()), Err(())]
+        );
+    }
+}
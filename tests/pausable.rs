@@ -32,7 +32,11 @@ mod pausable {
         env::test::DefaultAccounts,
     };
     use openbrush::{
-        test_utils::accounts,
+        storage::Mapping,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
         traits::Storage,
     };
 
@@ -54,6 +58,7 @@ mod pausable {
         #[storage_field]
         pause: Data,
         flipped: bool,
+        deposits: Mapping<AccountId, Balance>,
     }
 
     impl MyFlipper {
@@ -70,6 +75,37 @@ mod pausable {
 
             Ok(previous)
         }
+
+        #[ink(message, payable)]
+        #[openbrush::modifiers(when_not_paused)]
+        pub fn deposit(&mut self) -> Result<(), PausableError> {
+            let caller = self.env().caller();
+            let deposited = self.deposits.get(&caller).unwrap_or_default();
+            self.deposits
+                .insert(&caller, &(deposited + self.env().transferred_value()));
+            Ok(())
+        }
+
+        /// Lets the caller pull their own funds while the contract is paused.
+        #[ink(message)]
+        #[openbrush::modifiers(when_paused)]
+        pub fn emergency_withdraw(&mut self) -> Result<Balance, PausableError> {
+            let caller = self.env().caller();
+            let deposited = self.deposits.get(&caller).unwrap_or_default();
+            self.deposits.remove(&caller);
+            self.env()
+                .transfer(caller, deposited)
+                .expect("the contract holds all deposits");
+            Ok(deposited)
+        }
+
+        pub fn scoped_transfer(&mut self) -> Result<(), PausableError> {
+            pausable::Internal::_ensure_not_paused(self, TRANSFER_SCOPE)
+        }
+
+        pub fn scoped_mint(&mut self) -> Result<(), PausableError> {
+            pausable::Internal::_ensure_not_paused(self, MINT_SCOPE)
+        }
     }
 
     #[overrider(pausable::Internal)]
@@ -181,4 +217,103 @@ mod pausable {
         let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
         assert_unpaused_event(&emitted_events[0], accounts.alice);
     }
+
+    #[ink::test]
+    fn pause_scope_works() {
+        let mut inst = MyFlipper::new();
+
+        assert!(pausable::Internal::_pause_scope(&mut inst, TRANSFER_SCOPE).is_ok());
+        assert!(pausable::Internal::_scope_paused(&inst, TRANSFER_SCOPE));
+        assert!(!pausable::Internal::_scope_paused(&inst, MINT_SCOPE));
+        assert!(!pausable::Internal::_paused(&inst));
+
+        assert_eq!(Err(PausableError::Paused), inst.scoped_transfer());
+        assert_eq!(Ok(()), inst.scoped_mint());
+    }
+
+    #[ink::test]
+    fn double_pause_scope_fails() {
+        let mut inst = MyFlipper::new();
+
+        assert!(pausable::Internal::_pause_scope(&mut inst, TRANSFER_SCOPE).is_ok());
+        assert_eq!(
+            Err(PausableError::Paused),
+            pausable::Internal::_pause_scope(&mut inst, TRANSFER_SCOPE)
+        );
+    }
+
+    #[ink::test]
+    fn unpause_scope_works() {
+        let mut inst = MyFlipper::new();
+
+        assert!(pausable::Internal::_pause_scope(&mut inst, TRANSFER_SCOPE).is_ok());
+        assert!(pausable::Internal::_pause_scope(&mut inst, MINT_SCOPE).is_ok());
+        assert!(pausable::Internal::_unpause_scope(&mut inst, TRANSFER_SCOPE).is_ok());
+
+        assert_eq!(Ok(()), inst.scoped_transfer());
+        assert_eq!(Err(PausableError::Paused), inst.scoped_mint());
+    }
+
+    #[ink::test]
+    fn unpause_scope_fails() {
+        let mut inst = MyFlipper::new();
+
+        assert_eq!(
+            Err(PausableError::NotPaused),
+            pausable::Internal::_unpause_scope(&mut inst, TRANSFER_SCOPE)
+        );
+    }
+
+    #[ink::test]
+    fn global_pause_blocks_all_scopes() {
+        let mut inst = MyFlipper::new();
+
+        assert!(pausable::Internal::_pause(&mut inst).is_ok());
+
+        assert_eq!(Err(PausableError::Paused), inst.scoped_transfer());
+        assert_eq!(Err(PausableError::Paused), inst.scoped_mint());
+    }
+
+    #[ink::test]
+    fn emergency_withdraw_works_when_paused() {
+        let accounts = setup();
+        ink::env::test::set_callee::<DefaultEnvironment>(accounts.charlie);
+        let mut inst = MyFlipper::new();
+
+        change_caller(accounts.bob);
+        ink::env::test::set_value_transferred::<DefaultEnvironment>(100);
+        ink::env::test::set_account_balance::<DefaultEnvironment>(accounts.charlie, 100);
+        assert_eq!(Ok(()), inst.deposit());
+        ink::env::test::set_value_transferred::<DefaultEnvironment>(0);
+
+        assert!(pausable::Internal::_pause(&mut inst).is_ok());
+        assert_eq!(Err(PausableError::Paused), inst.deposit());
+
+        let bob_balance = ink::env::test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+        assert_eq!(Ok(100), inst.emergency_withdraw());
+        assert_eq!(
+            ink::env::test::get_account_balance::<DefaultEnvironment>(accounts.bob),
+            Ok(bob_balance + 100)
+        );
+        // the deposit can't be withdrawn twice
+        assert_eq!(Ok(0), inst.emergency_withdraw());
+    }
+
+    #[ink::test]
+    fn emergency_withdraw_fails_when_not_paused() {
+        let accounts = setup();
+        ink::env::test::set_callee::<DefaultEnvironment>(accounts.charlie);
+        let mut inst = MyFlipper::new();
+
+        change_caller(accounts.bob);
+        ink::env::test::set_value_transferred::<DefaultEnvironment>(100);
+        ink::env::test::set_account_balance::<DefaultEnvironment>(accounts.charlie, 100);
+        assert_eq!(Ok(()), inst.deposit());
+
+        assert_eq!(Err(PausableError::NotPaused), inst.emergency_withdraw());
+
+        assert!(pausable::Internal::_pause(&mut inst).is_ok());
+        assert!(pausable::Internal::_unpause(&mut inst).is_ok());
+        assert_eq!(Err(PausableError::NotPaused), inst.emergency_withdraw());
+    }
 }
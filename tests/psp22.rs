@@ -189,6 +189,25 @@ mod psp22_test {
         assert_transfer_event(&emitted_events[0], None, Some(AccountId::from([0x01; 32])), 100);
     }
 
+    /// Recorded events are decoded into the contract's event type.
+    #[ink::test]
+    fn decode_events_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, Vec::<u8>::new()).is_ok());
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 2);
+
+        if let Event::Transfer(Transfer { from, to, value }) = &events[1] {
+            assert_eq!(*from, Some(accounts.alice));
+            assert_eq!(*to, Some(accounts.bob));
+            assert_eq!(*value, 10);
+        } else {
+            panic!("encountered unexpected event kind: expected a Transfer event")
+        }
+    }
+
     /// The total supply was applied.
     #[ink::test]
     fn total_supply_works() {
@@ -241,6 +260,39 @@ mod psp22_test {
         );
     }
 
+    #[ink::test]
+    fn transfer_all_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(PSP22::transfer_all(&mut psp22, accounts.bob, Vec::<u8>::new()), Ok(100));
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 0);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 100);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        assert_transfer_event(
+            &emitted_events[1],
+            Some(AccountId::from([0x01; 32])),
+            Some(AccountId::from([0x02; 32])),
+            100,
+        );
+    }
+
+    #[ink::test]
+    fn transfer_all_without_balance_is_noop() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        change_caller(accounts.bob);
+
+        assert_eq!(PSP22::transfer_all(&mut psp22, accounts.eve, Vec::<u8>::new()), Ok(0));
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.eve), 0);
+        assert_eq!(ink::env::test::recorded_events().count(), 1);
+    }
+
     #[ink::test]
     fn invalid_transfer_should_fail() {
         // Constructor works.
@@ -271,6 +323,147 @@ mod psp22_test {
         );
     }
 
+    #[ink::test]
+    fn approve_non_zero_to_non_zero_works_by_default() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 20).is_ok());
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 20);
+    }
+
+    #[ink::test]
+    fn strict_approvals_allow_zero_to_non_zero() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        psp22::Internal::_set_strict_approvals(&mut psp22, true);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn strict_approvals_allow_non_zero_to_zero() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        psp22::Internal::_set_strict_approvals(&mut psp22, true);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 0).is_ok());
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 0);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 20).is_ok());
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 20);
+    }
+
+    #[ink::test]
+    fn strict_approvals_reject_non_zero_to_non_zero() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        psp22::Internal::_set_strict_approvals(&mut psp22, true);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert_eq!(
+            PSP22::approve(&mut psp22, accounts.bob, 20),
+            Err(PSP22Error::Custom(String::from("NonZeroToNonZero")))
+        );
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn approve_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 10);
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 2);
+
+        if let Event::Approval(Approval { owner, spender, value }) = &events[1] {
+            assert_eq!(*owner, accounts.alice);
+            assert_eq!(*spender, accounts.bob);
+            assert_eq!(*value, 10);
+        } else {
+            panic!("encountered unexpected event kind: expected an Approval event")
+        }
+    }
+
+    #[ink::test]
+    fn approve_zero_address_should_fail() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        let zero_address = AccountId::from([0x0; 32]);
+
+        assert_eq!(
+            PSP22::approve(&mut psp22, zero_address, 10),
+            Err(PSP22Error::ZeroRecipientAddress)
+        );
+        assert_eq!(
+            PSP22::increase_allowance(&mut psp22, zero_address, 10),
+            Err(PSP22Error::ZeroRecipientAddress)
+        );
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, zero_address), 0);
+    }
+
+    #[ink::test]
+    fn approve_from_zero_address_should_fail() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        let zero_address = AccountId::from([0x0; 32]);
+
+        assert_eq!(
+            psp22::Internal::_approve_from_to(&mut psp22, zero_address, accounts.bob, 10),
+            Err(PSP22Error::ZeroSenderAddress)
+        );
+        assert_eq!(PSP22::allowance(&psp22, zero_address, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_to_contract_is_rejected() {
+        let accounts = accounts();
+        // Charlie is the address of the contract.
+        ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+        let mut psp22 = PSP22Struct::new(100);
+        psp22::Internal::_set_reject_transfers_to_contract(&mut psp22, true);
+
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.charlie, 10, Vec::<u8>::new()),
+            Err(PSP22Error::Custom(String::from("TransferToContract")))
+        );
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22::transfer_from(&mut psp22, accounts.alice, accounts.charlie, 10, Vec::<u8>::new()),
+            Err(PSP22Error::Custom(String::from("TransferToContract")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 0);
+
+        // Minting to the contract is not affected.
+        assert!(psp22::Internal::_mint_to(&mut psp22, accounts.charlie, 10).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 10);
+    }
+
+    #[ink::test]
+    fn transfer_works_when_transfers_to_contract_are_rejected() {
+        let accounts = accounts();
+        // Charlie is the address of the contract.
+        ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+        let mut psp22 = PSP22Struct::new(100);
+        psp22::Internal::_set_reject_transfers_to_contract(&mut psp22, true);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 10);
+
+        // Without the guard tokens can be sent to the contract.
+        psp22::Internal::_set_reject_transfers_to_contract(&mut psp22, false);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 10);
+    }
+
     #[ink::test]
     fn transfer_from_works() {
         // Constructor works.
@@ -304,6 +497,76 @@ mod psp22_test {
         );
     }
 
+    #[ink::test]
+    fn transfer_from_does_not_spend_infinite_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        // Alice approves Bob for an infinite amount of tokens.
+        assert!(PSP22::approve(&mut psp22, accounts.bob, Balance::MAX).is_ok());
+        change_caller(accounts.bob);
+
+        assert!(PSP22::transfer_from(&mut psp22, accounts.alice, accounts.eve, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.eve), 10);
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), Balance::MAX);
+        // Mint, Approval and Transfer events only, the allowance wasn't updated.
+        assert_eq!(ink::env::test::recorded_events().count(), 3);
+    }
+
+    #[ink::test]
+    fn transfer_from_spends_finite_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        // Alice approves Bob for almost an infinite amount of tokens.
+        assert!(PSP22::approve(&mut psp22, accounts.bob, Balance::MAX - 1).is_ok());
+        change_caller(accounts.bob);
+
+        assert!(PSP22::transfer_from(&mut psp22, accounts.alice, accounts.eve, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(
+            PSP22::allowance(&psp22, accounts.alice, accounts.bob),
+            Balance::MAX - 11
+        );
+    }
+
+    #[ink::test]
+    fn transfer_from_emits_approval_with_remaining_allowance() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 30).is_ok());
+        change_caller(accounts.bob);
+
+        assert!(PSP22::transfer_from(&mut psp22, accounts.alice, accounts.eve, 10, Vec::<u8>::new()).is_ok());
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 4);
+        if let Event::Approval(Approval { owner, spender, value }) = &events[2] {
+            assert_eq!(*owner, accounts.alice);
+            assert_eq!(*spender, accounts.bob);
+            assert_eq!(*value, 20);
+        } else {
+            panic!("encountered unexpected event kind: expected an Approval event")
+        }
+        assert!(matches!(events[3], Event::Transfer(_)));
+    }
+
+    #[ink::test]
+    fn transfer_from_with_infinite_allowance_does_not_emit_approval() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(PSP22::approve(&mut psp22, accounts.bob, Balance::MAX).is_ok());
+        change_caller(accounts.bob);
+
+        assert!(PSP22::transfer_from(&mut psp22, accounts.alice, accounts.eve, 10, Vec::<u8>::new()).is_ok());
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], Event::Approval(_)));
+        assert!(matches!(events[2], Event::Transfer(_)));
+    }
+
     #[ink::test]
     fn allowance_must_not_change_on_failed_transfer() {
         let mut psp22 = PSP22Struct::new(100);
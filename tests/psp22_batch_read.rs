@@ -0,0 +1,109 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22BatchRead)]
+#[openbrush::contract]
+mod psp22_batch_read {
+    use openbrush::{
+        contracts::psp22::extensions::batch_read::*,
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    /// A simple PSP-22 contract.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn balances_of_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, Vec::<u8>::new()).is_ok());
+
+        // The result is aligned with the input, accounts without tokens have zero balance.
+        assert_eq!(
+            PSP22BatchRead::balances_of(
+                &psp22,
+                vec![accounts.bob, accounts.charlie, accounts.alice, accounts.bob]
+            ),
+            Ok(vec![10, 0, 90, 10])
+        );
+        assert_eq!(PSP22BatchRead::balances_of(&psp22, vec![]), Ok(vec![]));
+    }
+
+    #[ink::test]
+    fn allowances_of_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 10).is_ok());
+        assert!(PSP22::approve(&mut psp22, accounts.charlie, 20).is_ok());
+
+        // The result is aligned with the input, missing allowances are zero.
+        assert_eq!(
+            PSP22BatchRead::allowances_of(
+                &psp22,
+                vec![
+                    (accounts.alice, accounts.charlie),
+                    (accounts.bob, accounts.alice),
+                    (accounts.alice, accounts.bob),
+                ]
+            ),
+            Ok(vec![20, 0, 10])
+        );
+    }
+
+    #[ink::test]
+    fn batch_read_rejects_too_many_entries() {
+        let accounts = accounts();
+        let psp22 = PSP22Struct::new(100);
+
+        assert_eq!(
+            PSP22BatchRead::balances_of(&psp22, vec![accounts.alice; MAX_BATCH_READ_LEN]).map(|b| b.len()),
+            Ok(MAX_BATCH_READ_LEN)
+        );
+        assert_eq!(
+            PSP22BatchRead::balances_of(&psp22, vec![accounts.alice; MAX_BATCH_READ_LEN + 1]),
+            Err(PSP22Error::Custom(String::from("BatchTooLarge")))
+        );
+        assert_eq!(
+            PSP22BatchRead::allowances_of(&psp22, vec![(accounts.alice, accounts.bob); MAX_BATCH_READ_LEN + 1]),
+            Err(PSP22Error::Custom(String::from("BatchTooLarge")))
+        );
+    }
+}
@@ -46,12 +46,23 @@ mod psp22_burnable {
         value: Balance,
     }
 
+    /// Event emitted when tokens are burned with a memo.
+    #[ink(event)]
+    pub struct BurnWithMemo {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+        memo: String,
+    }
+
     /// A simple PSP-20 contract.
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct PSP22Struct {
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        burnable: burnable::Data,
         // field for testing _before_token_transfer
         return_err_on_before: bool,
         // field for testing _after_token_transfer
@@ -69,6 +80,11 @@ mod psp22_burnable {
         });
     }
 
+    #[overrider(burnable::Internal)]
+    fn _emit_burn_with_memo_event(&self, account: AccountId, amount: Balance, memo: String) {
+        self.env().emit_event(BurnWithMemo { account, amount, memo });
+    }
+
     #[overrider(psp22::Internal)]
     fn _before_token_transfer(
         &mut self,
@@ -122,10 +138,13 @@ mod psp22_burnable {
         let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
             .expect("encountered invalid contract event data buffer");
 
-        let Event::Transfer(Transfer { from, to, value }) = decoded_event;
-        assert_eq!(from, expected_from, "encountered invalid Transfer.from");
-        assert_eq!(to, expected_to, "encountered invalid Transfer.to");
-        assert_eq!(value, expected_value, "encountered invalid Trasfer.value");
+        if let Event::Transfer(Transfer { from, to, value }) = decoded_event {
+            assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+            assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+            assert_eq!(value, expected_value, "encountered invalid Trasfer.value");
+        } else {
+            panic!("encountered unexpected event kind: expected a Transfer event")
+        }
 
         let expected_topics = vec![
             encoded_into_hash(&PrefixedValue {
@@ -199,6 +218,48 @@ mod psp22_burnable {
         );
     }
 
+    #[ink::test]
+    fn burn_with_memo_emits_transfer_and_memo_events() {
+        let initial_amount = 100;
+        let mut psp22 = PSP22Struct::new(initial_amount);
+        let accounts = accounts();
+        let amount_to_burn = 10;
+
+        assert!(PSP22Burnable::burn_with_memo(&mut psp22, amount_to_burn, String::from("fee settlement")).is_ok());
+        assert_eq!(
+            PSP22::balance_of(&psp22, accounts.alice),
+            initial_amount - amount_to_burn
+        );
+        assert_eq!(PSP22::total_supply(&psp22), initial_amount - amount_to_burn);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        // Transfer to zero is emitted as for the plain burn.
+        assert_transfer_event(&emitted_events[1], Some(accounts.alice), None, amount_to_burn);
+
+        let decoded_event = <Event as scale::Decode>::decode(&mut &emitted_events[2].data[..])
+            .expect("encountered invalid contract event data buffer");
+        if let Event::BurnWithMemo(BurnWithMemo { account, amount, memo }) = decoded_event {
+            assert_eq!(account, accounts.alice);
+            assert_eq!(amount, amount_to_burn);
+            assert_eq!(memo, String::from("fee settlement"));
+        } else {
+            panic!("encountered unexpected event kind: expected a BurnWithMemo event")
+        }
+    }
+
+    #[ink::test]
+    fn burn_with_memo_fails_without_balance() {
+        let mut psp22 = PSP22Struct::new(10);
+
+        assert_eq!(
+            PSP22Burnable::burn_with_memo(&mut psp22, 100, String::from("memo")),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        // Only the Transfer event of the instantiation is emitted.
+        assert_eq!(ink::env::test::recorded_events().count(), 1);
+    }
+
     #[ink::test]
     fn total_supply_decreases_after_burning() {
         let mut psp22 = PSP22Struct::new(100);
@@ -287,4 +348,114 @@ mod psp22_burnable {
             Err(PSP22Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn approve_burn_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(PSP22Burnable::approve_burn(&mut psp22, accounts.bob, 30).is_ok());
+
+        assert_eq!(PSP22Burnable::burn_allowance(&psp22, accounts.alice, accounts.bob), 30);
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn burn_from_approved_within_burn_allowance_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(PSP22Burnable::approve_burn(&mut psp22, accounts.bob, 30).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP22Burnable::burn_from_approved(&mut psp22, accounts.alice, 20).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 80);
+        assert_eq!(PSP22::total_supply(&psp22), 80);
+        assert_eq!(PSP22Burnable::burn_allowance(&psp22, accounts.alice, accounts.bob), 10);
+        assert_eq!(
+            PSP22Burnable::burn_from_approved(&mut psp22, accounts.alice, 11),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+    }
+
+    #[ink::test]
+    fn burn_from_approved_with_transfer_allowance_only_fails() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 30).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Burnable::burn_from_approved(&mut psp22, accounts.alice, 10),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+        assert_eq!(PSP22::allowance(&psp22, accounts.alice, accounts.bob), 30);
+    }
+
+    #[ink::test]
+    fn burn_down_to_min_supply_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        burnable::Internal::_set_min_supply(&mut psp22, 60);
+
+        assert!(PSP22Burnable::burn(&mut psp22, accounts.alice, 30).is_ok());
+        assert!(PSP22Burnable::burn(&mut psp22, accounts.alice, 10).is_ok());
+
+        assert_eq!(PSP22::total_supply(&psp22), 60);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 60);
+    }
+
+    #[ink::test]
+    fn burn_below_min_supply_fails() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        burnable::Internal::_set_min_supply(&mut psp22, 60);
+
+        assert_eq!(
+            PSP22Burnable::burn(&mut psp22, accounts.alice, 41),
+            Err(PSP22Error::Custom(String::from("BelowMinSupply")))
+        );
+        assert_eq!(
+            PSP22Burnable::burn_with_memo(&mut psp22, 41, String::from("memo")),
+            Err(PSP22Error::Custom(String::from("BelowMinSupply")))
+        );
+
+        assert!(PSP22Burnable::approve_burn(&mut psp22, accounts.bob, 50).is_ok());
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Burnable::burn_from_approved(&mut psp22, accounts.alice, 41),
+            Err(PSP22Error::Custom(String::from("BelowMinSupply")))
+        );
+
+        assert_eq!(PSP22::total_supply(&psp22), 100);
+        assert_eq!(PSP22Burnable::burn_allowance(&psp22, accounts.alice, accounts.bob), 50);
+    }
+
+    #[ink::test]
+    fn mint_above_min_supply_works() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        burnable::Internal::_set_min_supply(&mut psp22, 100);
+
+        assert!(psp22::Internal::_mint_to(&mut psp22, accounts.bob, 50).is_ok());
+        assert_eq!(PSP22::total_supply(&psp22), 150);
+
+        // Only the minted tokens can be burned
+        assert!(PSP22Burnable::burn(&mut psp22, accounts.bob, 50).is_ok());
+        assert_eq!(
+            PSP22Burnable::burn(&mut psp22, accounts.alice, 1),
+            Err(PSP22Error::Custom(String::from("BelowMinSupply")))
+        );
+    }
+
+    #[ink::test]
+    fn zero_min_supply_allows_burning_everything() {
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert!(PSP22Burnable::burn(&mut psp22, accounts.alice, 100).is_ok());
+        assert_eq!(PSP22::total_supply(&psp22), 0);
+    }
 }
@@ -37,6 +37,8 @@ mod psp22_capped {
         #[storage_field]
         psp22: psp22::Data,
         #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
         cap: capped::Data,
     }
 
@@ -98,4 +100,20 @@ mod psp22_capped {
         );
         assert_eq!(PSP22::balance_of(&mut token, accounts.alice), alice_balance);
     }
+
+    #[ink::test]
+    fn lazy_fields_are_loaded_after_upgrade() {
+        let token = PSP22Struct::new();
+        assert_eq!(PSP22Capped::cap(&token), CAP);
+
+        // The storage layout of the new code starts from empty handles of the same lazy fields
+        let cap = capped::Data::default();
+        assert_eq!(cap._ensure_loaded(), Ok(()));
+        assert_eq!(cap.cap.get(), Some(CAP));
+
+        // `supply` is stored by minting, but the settings of `psp22::Data` were never set
+        let psp22 = psp22::Data::default();
+        assert_eq!(psp22._ensure_loaded(), Err("strict_approvals"));
+        assert_eq!(psp22.supply.get(), Some(1));
+    }
 }
@@ -0,0 +1,97 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Circulating)]
+#[openbrush::contract]
+mod psp22_circulating {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        circulating: circulating::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = accounts();
+        let psp22 = PSP22Struct::new(100);
+
+        assert_eq!(PSP22Circulating::circulating_supply(&psp22), 100);
+        assert!(!PSP22Circulating::is_excluded(&psp22, accounts.alice));
+    }
+
+    #[ink::test]
+    fn excluding_account_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 30, vec![]).is_ok());
+
+        circulating::Internal::_set_excluded(&mut psp22, accounts.bob, true);
+
+        assert!(PSP22Circulating::is_excluded(&psp22, accounts.bob));
+        assert_eq!(PSP22Circulating::circulating_supply(&psp22), 70);
+        assert_eq!(PSP22::total_supply(&psp22), 100);
+    }
+
+    #[ink::test]
+    fn circulating_supply_follows_excluded_balance() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        circulating::Internal::_set_excluded(&mut psp22, accounts.bob, true);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 30, vec![]).is_ok());
+        assert_eq!(PSP22Circulating::circulating_supply(&psp22), 70);
+
+        // Excluding the same account twice doesn't count its balance twice
+        circulating::Internal::_set_excluded(&mut psp22, accounts.bob, true);
+        assert_eq!(PSP22Circulating::circulating_supply(&psp22), 70);
+    }
+
+    #[ink::test]
+    fn including_account_back_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 30, vec![]).is_ok());
+        circulating::Internal::_set_excluded(&mut psp22, accounts.bob, true);
+
+        circulating::Internal::_set_excluded(&mut psp22, accounts.bob, false);
+
+        assert!(!PSP22Circulating::is_excluded(&psp22, accounts.bob));
+        assert_eq!(PSP22Circulating::circulating_supply(&psp22), 100);
+    }
+}
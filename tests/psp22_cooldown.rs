@@ -0,0 +1,139 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Cooldown)]
+#[openbrush::contract]
+mod psp22_cooldown {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const COOLDOWN_SECS: u64 = 60;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        cooldown: cooldown::Data,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        cooldown::Internal::_check_cooldown(self, from, to)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            cooldown::Internal::_set_cooldown_secs(&mut instance, COOLDOWN_SECS);
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = accounts();
+        let psp22 = PSP22Struct::new(100);
+
+        assert_eq!(PSP22Cooldown::cooldown_secs(&psp22), COOLDOWN_SECS);
+        // Minting doesn't start the cooldown
+        assert_eq!(PSP22Cooldown::last_transfer_time(&psp22, accounts.alice), None);
+    }
+
+    #[ink::test]
+    fn second_transfer_within_cooldown_fails() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1_000);
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+        assert_eq!(PSP22Cooldown::last_transfer_time(&psp22, accounts.alice), Some(1_000));
+
+        set_block_timestamp::<DefaultEnvironment>(1_000 + COOLDOWN_SECS * 1000 - 1);
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.charlie, 10, vec![]),
+            Err(PSP22Error::Custom(String::from("Cooldown")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn second_transfer_after_cooldown_works() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1_000);
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+
+        set_block_timestamp::<DefaultEnvironment>(1_000 + COOLDOWN_SECS * 1000);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 10, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 10);
+        assert_eq!(
+            PSP22Cooldown::last_transfer_time(&psp22, accounts.alice),
+            Some(1_000 + COOLDOWN_SECS * 1000)
+        );
+    }
+
+    #[ink::test]
+    fn cooldown_is_tracked_per_sender() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1_000);
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 5, vec![]).is_ok());
+    }
+
+    #[ink::test]
+    fn burn_within_cooldown_works() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1_000);
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+
+        assert!(psp22::Internal::_burn_from(&mut psp22, accounts.alice, 10).is_ok());
+        assert_eq!(PSP22::total_supply(&psp22), 90);
+    }
+}
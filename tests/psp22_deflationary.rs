@@ -0,0 +1,322 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Deflationary)]
+#[openbrush::contract]
+mod psp22_deflationary {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+            decode_events,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        deflationary: deflationary::Data,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
+        self.env().emit_event(Transfer {
+            from,
+            to,
+            value: amount,
+        });
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _transfer_from_to(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        deflationary::Internal::_transfer_with_burn(self, from, to, amount, data)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, burn_bps: u16) -> Self {
+            let mut instance = Self::default();
+            deflationary::Internal::_set_burn_bps(&mut instance, burn_bps).expect("Should set burn bps");
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+
+        #[ink(constructor)]
+        pub fn new_with_pair(
+            total_supply: Balance,
+            burn_bps: u16,
+            pair: AccountId,
+            buy_fee_bps: u16,
+            sell_fee_bps: u16,
+        ) -> Self {
+            let mut instance = Self::new(total_supply, burn_bps);
+            deflationary::Internal::_set_pair(&mut instance, pair, true);
+            deflationary::Internal::_set_buy_fee_bps(&mut instance, buy_fee_bps).expect("Should set buy fee bps");
+            deflationary::Internal::_set_sell_fee_bps(&mut instance, sell_fee_bps).expect("Should set sell fee bps");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let psp22 = PSP22Struct::new(10_000, 250);
+
+        assert_eq!(PSP22Deflationary::burn_bps(&psp22), 250);
+    }
+
+    #[ink::test]
+    fn set_burn_bps_above_max_fails() {
+        let mut psp22 = PSP22Struct::new(10_000, 250);
+
+        assert_eq!(
+            deflationary::Internal::_set_burn_bps(&mut psp22, MAX_BPS + 1),
+            Err(PSP22Error::Custom(String::from("InvalidBurnBps")))
+        );
+        assert_eq!(PSP22Deflationary::burn_bps(&psp22), 250);
+    }
+
+    #[ink::test]
+    fn transfer_burns_fraction() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 250);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        let burned = 25;
+        let net = PSP22::balance_of(&psp22, accounts.bob);
+        assert_eq!(net, 975);
+        assert_eq!(burned + net, 1_000);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 9_000);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000 - burned);
+    }
+
+    #[ink::test]
+    fn transfer_from_burns_fraction() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 100);
+        assert!(PSP22::approve(&mut psp22, accounts.bob, 500).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP22::transfer_from(&mut psp22, accounts.alice, accounts.charlie, 500, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 495);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 9_500);
+        assert_eq!(PSP22::total_supply(&psp22), 9_995);
+    }
+
+    #[ink::test]
+    fn transfer_emits_net_and_burn_events() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 250);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 3);
+        let Event::Transfer(Transfer { from, to, value }) = &events[1];
+        assert_eq!((*from, *to, *value), (Some(accounts.alice), Some(accounts.bob), 975));
+        let Event::Transfer(Transfer { from, to, value }) = &events[2];
+        assert_eq!((*from, *to, *value), (Some(accounts.alice), None, 25));
+    }
+
+    #[ink::test]
+    fn transfer_without_burn_bps_burns_nothing() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 0);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 1_000);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000);
+        assert_eq!(decode_events::<Event>().len(), 2);
+    }
+
+    #[ink::test]
+    fn transfer_more_than_balance_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100, 250);
+
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.bob, 101, vec![]),
+            Err(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(PSP22::total_supply(&psp22), 100);
+    }
+
+    #[ink::test]
+    fn burn_amount_does_not_overflow() {
+        let psp22 = PSP22Struct::new(0, MAX_BPS);
+
+        assert_eq!(deflationary::Internal::_burn_amount(&psp22, Balance::MAX), Balance::MAX);
+    }
+
+    #[ink::test]
+    fn set_fee_bps_above_max_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+
+        assert_eq!(
+            deflationary::Internal::_set_buy_fee_bps(&mut psp22, MAX_BPS + 1),
+            Err(PSP22Error::Custom(String::from("InvalidFeeBps")))
+        );
+        assert_eq!(
+            deflationary::Internal::_set_sell_fee_bps(&mut psp22, MAX_BPS + 1),
+            Err(PSP22Error::Custom(String::from("InvalidFeeBps")))
+        );
+        assert_eq!(PSP22Deflationary::buy_fee_bps(&psp22), 300);
+        assert_eq!(PSP22Deflationary::sell_fee_bps(&psp22), 500);
+    }
+
+    #[ink::test]
+    fn set_pair_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+
+        assert!(PSP22Deflationary::is_pair(&psp22, accounts.charlie));
+        assert!(!PSP22Deflationary::is_pair(&psp22, accounts.bob));
+
+        deflationary::Internal::_set_pair(&mut psp22, accounts.charlie, false);
+
+        assert!(!PSP22Deflationary::is_pair(&psp22, accounts.charlie));
+    }
+
+    #[ink::test]
+    fn buy_from_pair_charges_buy_fee() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 2_000, vec![]).is_ok());
+
+        change_caller(accounts.charlie);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 970);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 1_000);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000 - 30);
+    }
+
+    #[ink::test]
+    fn sell_to_pair_charges_sell_fee() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 1_000, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 950);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 9_000);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000 - 50);
+    }
+
+    #[ink::test]
+    fn transfer_between_wallets_is_untaxed() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 1_000);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000);
+    }
+
+    #[ink::test]
+    fn transfer_between_wallets_is_taxed_without_pairs() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+        deflationary::Internal::_set_pair(&mut psp22, accounts.charlie, false);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 1_000, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 975);
+        assert_eq!(PSP22::total_supply(&psp22), 10_000 - 25);
+    }
+
+    #[ink::test]
+    fn mint_is_untaxed() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 250);
+
+        assert!(psp22::Internal::_mint_to(&mut psp22, accounts.bob, 1_000).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 1_000);
+        assert_eq!(PSP22::total_supply(&psp22), 11_000);
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 2);
+        let Event::Transfer(Transfer { from, to, value }) = &events[1];
+        assert_eq!((*from, *to, *value), (None, Some(accounts.bob), 1_000));
+    }
+
+    #[ink::test]
+    fn burn_is_untaxed() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(10_000, 250);
+
+        assert!(psp22::Internal::_burn_from(&mut psp22, accounts.alice, 1_000).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 9_000);
+        assert_eq!(PSP22::total_supply(&psp22), 9_000);
+
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 2);
+        let Event::Transfer(Transfer { from, to, value }) = &events[1];
+        assert_eq!((*from, *to, *value), (Some(accounts.alice), None, 1_000));
+    }
+
+    #[ink::test]
+    fn mint_and_burn_on_pair_are_untaxed() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new_with_pair(10_000, 250, accounts.charlie, 300, 500);
+
+        assert!(psp22::Internal::_mint_to(&mut psp22, accounts.charlie, 1_000).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 1_000);
+        assert_eq!(PSP22::total_supply(&psp22), 11_000);
+
+        assert!(psp22::Internal::_burn_from(&mut psp22, accounts.charlie, 400).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 600);
+        assert_eq!(PSP22::total_supply(&psp22), 10_600);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::implementation(PSP22, PSP22Mintable, Ownable)]
+#[openbrush::contract]
+mod psp22_finish_minting {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    #[overrider(mintable::Internal)]
+    fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error> {
+        if Ownable::owner(self) != Some(*caller) {
+            return Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        }
+        Ok(())
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn mint_before_finish_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        assert!(!PSP22Mintable::minting_finished(&psp22));
+        assert!(PSP22Mintable::mint(&mut psp22, accounts.bob, 100).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 100);
+    }
+
+    #[ink::test]
+    fn mint_after_finish_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+        assert!(PSP22Mintable::mint(&mut psp22, accounts.bob, 100).is_ok());
+
+        assert!(PSP22Mintable::finish_minting(&mut psp22).is_ok());
+
+        assert!(PSP22Mintable::minting_finished(&psp22));
+        assert_eq!(
+            PSP22Mintable::mint(&mut psp22, accounts.bob, 100),
+            Err(PSP22Error::Custom(String::from("MintingFinished")))
+        );
+        assert_eq!(
+            PSP22Mintable::mint_to_many(&mut psp22, vec![(accounts.bob, 100)]),
+            Err(PSP22Error::Custom(String::from("MintingFinished")))
+        );
+        assert_eq!(PSP22::total_supply(&psp22), 100);
+    }
+
+    #[ink::test]
+    fn finish_minting_is_irreversible() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+        assert!(PSP22Mintable::finish_minting(&mut psp22).is_ok());
+
+        // Finishing again keeps minting disabled
+        assert!(PSP22Mintable::finish_minting(&mut psp22).is_ok());
+        assert!(PSP22Mintable::minting_finished(&psp22));
+        assert_eq!(
+            PSP22Mintable::mint(&mut psp22, accounts.bob, 1),
+            Err(PSP22Error::Custom(String::from("MintingFinished")))
+        );
+    }
+
+    #[ink::test]
+    fn finish_minting_by_not_owner_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        change_caller(accounts.bob);
+
+        assert_eq!(
+            PSP22Mintable::finish_minting(&mut psp22),
+            Err(PSP22Error::from(OwnableError::CallerIsNotOwner))
+        );
+        assert!(!PSP22Mintable::minting_finished(&psp22));
+    }
+}
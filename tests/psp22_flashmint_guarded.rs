@@ -0,0 +1,107 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "reentrancy_guard"))]
+#[openbrush::implementation(PSP22, Flashmint(reentrant_guard))]
+#[openbrush::contract]
+mod psp22_flashmint_guarded {
+    use ink::codegen::Env;
+    use openbrush::{
+        contracts::reentrancy_guard,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22FlashMintStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        // field for testing a malicious borrower
+        reenter_on_flashloan: bool,
+    }
+
+    // we remove cross contract call in test,
+    // the malicious borrower calls `flashloan` again during `on_flashloan`
+    #[overrider(flashmint::Internal)]
+    fn _on_flashloan(
+        &mut self,
+        receiver_account: AccountId,
+        token: AccountId,
+        _fee: Balance,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), FlashLenderError> {
+        if self.reenter_on_flashloan {
+            return FlashLender::flashloan(self, receiver_account, token, amount, data)
+        }
+        Ok(())
+    }
+
+    impl PSP22FlashMintStruct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).is_ok());
+            instance
+        }
+
+        pub fn set_reenter_on_flashloan(&mut self, reenter: bool) {
+            self.reenter_on_flashloan = reenter;
+        }
+    }
+
+    #[ink::test]
+    fn flashloan_works_with_guard() {
+        let total_supply = 1000;
+        let mut instance = PSP22FlashMintStruct::new(total_supply);
+
+        let receiver = AccountId::from([0x1; 32]);
+        let token = instance.env().account_id();
+        let loan_amount = 100;
+
+        assert!(PSP22::approve(&mut instance, token, loan_amount).is_ok());
+        assert!(FlashLender::flashloan(&mut instance, receiver, token, loan_amount, Vec::<u8>::new()).is_ok());
+        // The guard is released after the call.
+        assert!(FlashLender::flashloan(&mut instance, receiver, token, 0, Vec::<u8>::new()).is_ok());
+    }
+
+    #[ink::test]
+    fn reentrant_flashloan_fails() {
+        let total_supply = 1000;
+        let mut instance = PSP22FlashMintStruct::new(total_supply);
+        instance.set_reenter_on_flashloan(true);
+
+        let receiver = AccountId::from([0x1; 32]);
+        let token = instance.env().account_id();
+        let loan_amount = 100;
+
+        assert!(PSP22::approve(&mut instance, token, loan_amount * 2).is_ok());
+        assert_eq!(
+            FlashLender::flashloan(&mut instance, receiver, token, loan_amount, Vec::<u8>::new()),
+            Err(FlashLenderError::Custom(String::from("RG::ReentrantCall")))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Inflation)]
+#[openbrush::contract]
+mod psp22_inflation {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        inflation: inflation::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(rate_per_second: Balance) -> Self {
+            let mut instance = Self::default();
+            inflation::Internal::_init_inflation(&mut instance, rate_per_second);
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        let instance = PSP22Struct::new(10);
+
+        assert_eq!(PSP22Inflation::rate_per_second(&instance), 10);
+        assert_eq!(PSP22Inflation::last_mint_time(&instance), 1000);
+        assert_eq!(PSP22::total_supply(&instance), 0);
+    }
+
+    #[ink::test]
+    fn mint_inflation_mints_rate_per_elapsed_second() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        let mut instance = PSP22Struct::new(10);
+
+        // 5 seconds elapsed
+        set_block_timestamp::<DefaultEnvironment>(6000);
+        assert!(PSP22Inflation::mint_inflation(&mut instance, accounts.bob).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 50);
+        assert_eq!(PSP22Inflation::last_mint_time(&instance), 6000);
+
+        // 3 more seconds elapsed
+        set_block_timestamp::<DefaultEnvironment>(9000);
+        assert!(PSP22Inflation::mint_inflation(&mut instance, accounts.charlie).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, accounts.charlie), 30);
+        assert_eq!(PSP22::total_supply(&instance), 80);
+    }
+
+    #[ink::test]
+    fn mint_inflation_keeps_remainder_of_second() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        let mut instance = PSP22Struct::new(10);
+
+        // 2.5 seconds elapsed, only 2 of them are minted
+        set_block_timestamp::<DefaultEnvironment>(3500);
+        assert!(PSP22Inflation::mint_inflation(&mut instance, accounts.bob).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 20);
+        assert_eq!(PSP22Inflation::last_mint_time(&instance), 3000);
+
+        // the remaining half of a second is minted with the next one
+        set_block_timestamp::<DefaultEnvironment>(4000);
+        assert!(PSP22Inflation::mint_inflation(&mut instance, accounts.bob).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 30);
+    }
+
+    #[ink::test]
+    fn mint_inflation_fails_without_elapsed_time() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        let mut instance = PSP22Struct::new(10);
+
+        assert_eq!(
+            PSP22Inflation::mint_inflation(&mut instance, accounts.bob),
+            Err(PSP22Error::Custom(String::from("NoTimeElapsed")))
+        );
+
+        set_block_timestamp::<DefaultEnvironment>(1999);
+        assert_eq!(
+            PSP22Inflation::mint_inflation(&mut instance, accounts.bob),
+            Err(PSP22Error::Custom(String::from("NoTimeElapsed")))
+        );
+        assert_eq!(PSP22::total_supply(&instance), 0);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22MaxTx)]
+#[openbrush::contract]
+mod psp22_max_tx {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const MAX_TX_AMOUNT: Balance = 10;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        max_tx: max_tx::Data,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        max_tx::Internal::_check_max_tx(self, from, to, amount)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            max_tx::Internal::_set_max_tx(&mut instance, MAX_TX_AMOUNT);
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn mint_above_max_tx_works() {
+        let accounts = accounts();
+        let psp22 = PSP22Struct::new(100);
+
+        assert_eq!(PSP22MaxTx::max_tx_amount(&psp22), MAX_TX_AMOUNT);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn transfer_at_max_tx_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, MAX_TX_AMOUNT, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), MAX_TX_AMOUNT);
+    }
+
+    #[ink::test]
+    fn transfer_above_max_tx_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.bob, MAX_TX_AMOUNT + 1, vec![]),
+            Err(PSP22Error::Custom(String::from("MaxTxExceeded")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_above_max_tx_works_when_disabled() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        max_tx::Internal::_set_max_tx(&mut psp22, 0);
+
+        assert_eq!(PSP22MaxTx::max_tx_amount(&psp22), 0);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 50, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 50);
+    }
+
+    #[ink::test]
+    fn burn_above_max_tx_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(psp22::Internal::_burn_from(&mut psp22, accounts.alice, 50).is_ok());
+        assert_eq!(PSP22::total_supply(&psp22), 50);
+    }
+}
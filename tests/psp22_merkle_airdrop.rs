@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22MerkleAirdrop)]
+#[openbrush::contract]
+mod psp22_merkle_airdrop {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+        utils::crypto::hash::keccak256,
+    };
+    use scale::Encode;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        merkle_airdrop: merkle_airdrop::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(merkle_root: [u8; 32]) -> Self {
+            let mut instance = Self::default();
+            merkle_airdrop::Internal::_set_merkle_root(&mut instance, merkle_root);
+            instance
+        }
+    }
+
+    fn leaf(index: u32, account: AccountId, amount: Balance) -> [u8; 32] {
+        keccak256(&(index, account, amount).encode())
+    }
+
+    fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak256(&[a, b].concat())
+        } else {
+            keccak256(&[b, a].concat())
+        }
+    }
+
+    /// Builds the tree for `[(0, alice, 100), (1, bob, 200), (2, charlie, 300), (3, django, 400)]`
+    /// and returns its root with the proofs for alice and bob.
+    fn tree() -> ([u8; 32], Vec<[u8; 32]>, Vec<[u8; 32]>) {
+        let accounts = accounts();
+        let leaves = [
+            leaf(0, accounts.alice, 100),
+            leaf(1, accounts.bob, 200),
+            leaf(2, accounts.charlie, 300),
+            leaf(3, accounts.django, 400),
+        ];
+        let left = node(leaves[0], leaves[1]);
+        let right = node(leaves[2], leaves[3]);
+
+        (node(left, right), vec![leaves[1], right], vec![leaves[0], right])
+    }
+
+    #[ink::test]
+    fn claim_works() {
+        let accounts = accounts();
+        let (root, alice_proof, bob_proof) = tree();
+        let mut psp22 = PSP22Struct::new(root);
+
+        assert_eq!(PSP22MerkleAirdrop::merkle_root(&psp22), root);
+        assert!(PSP22MerkleAirdrop::claim(&mut psp22, 0, 100, alice_proof).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP22MerkleAirdrop::claim(&mut psp22, 1, 200, bob_proof).is_ok());
+
+        assert!(PSP22MerkleAirdrop::is_claimed(&psp22, 0));
+        assert!(PSP22MerkleAirdrop::is_claimed(&psp22, 1));
+        assert!(!PSP22MerkleAirdrop::is_claimed(&psp22, 2));
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 200);
+        assert_eq!(PSP22::total_supply(&psp22), 300);
+    }
+
+    #[ink::test]
+    fn double_claim_fails() {
+        let accounts = accounts();
+        let (root, alice_proof, _) = tree();
+        let mut psp22 = PSP22Struct::new(root);
+        assert!(PSP22MerkleAirdrop::claim(&mut psp22, 0, 100, alice_proof.clone()).is_ok());
+
+        assert_eq!(
+            PSP22MerkleAirdrop::claim(&mut psp22, 0, 100, alice_proof),
+            Err(PSP22Error::Custom(String::from("AlreadyClaimed")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn claim_with_wrong_amount_fails() {
+        let accounts = accounts();
+        let (root, alice_proof, _) = tree();
+        let mut psp22 = PSP22Struct::new(root);
+
+        assert_eq!(
+            PSP22MerkleAirdrop::claim(&mut psp22, 0, 1000, alice_proof),
+            Err(PSP22Error::Custom(String::from("InvalidProof")))
+        );
+        assert!(!PSP22MerkleAirdrop::is_claimed(&psp22, 0));
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn claim_by_other_account_fails() {
+        let accounts = accounts();
+        let (root, alice_proof, _) = tree();
+        let mut psp22 = PSP22Struct::new(root);
+
+        change_caller(accounts.charlie);
+
+        assert_eq!(
+            PSP22MerkleAirdrop::claim(&mut psp22, 0, 100, alice_proof),
+            Err(PSP22Error::Custom(String::from("InvalidProof")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 0);
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "access_control"))]
+#[openbrush::implementation(PSP22, PSP22Capped, PSP22Mintable, AccessControl)]
+#[openbrush::contract]
+mod psp22_mint_to_many {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    const CAP: Balance = 1000;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        mintable: mintable::Data,
+        #[storage_field]
+        cap: capped::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    type Event = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(psp22::Internal)]
+    fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
+        self.env().emit_event(Transfer {
+            from,
+            to,
+            value: amount,
+        });
+    }
+
+    #[overrider(mintable::Internal)]
+    fn _before_mint_to_many(&mut self, total: &Balance) -> Result<(), PSP22Error> {
+        if capped::Internal::_is_cap_exceeded(self, total) {
+            return Err(PSP22Error::Custom(String::from("Cap exceeded")))
+        }
+        Ok(())
+    }
+
+    #[overrider(mintable::Internal)]
+    fn _check_minter(&self, caller: &AccountId) -> Result<(), PSP22Error> {
+        access_control::Internal::_check_role(self, MINTER, Some(*caller))?;
+        Ok(())
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+
+            assert!(capped::Internal::_init_cap(&mut instance, CAP).is_ok());
+            access_control::Internal::_init_with_caller(&mut instance);
+            access_control::Internal::_setup_role(&mut instance, MINTER, Some(Self::env().caller()));
+
+            instance
+        }
+    }
+
+    fn assert_mint_event(event: &ink::env::test::EmittedEvent, expected_to: AccountId, expected_value: Balance) {
+        if let Event::Transfer(Transfer { from, to, value }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(from, None, "encountered invalid Transfer.from");
+            assert_eq!(to, Some(expected_to), "encountered invalid Transfer.to");
+            assert_eq!(value, expected_value, "encountered invalid Transfer.value");
+        } else {
+            panic!("encountered unexpected event kind: expected a Transfer event")
+        }
+    }
+
+    #[ink::test]
+    fn mint_to_many_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        assert!(PSP22Mintable::mint_to_many(
+            &mut psp22,
+            vec![(accounts.bob, 100), (accounts.charlie, 200), (accounts.bob, 50)]
+        )
+        .is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 150);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 200);
+        assert_eq!(PSP22::total_supply(&psp22), 350);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_mint_event(&emitted_events[0], accounts.bob, 100);
+        assert_mint_event(&emitted_events[1], accounts.charlie, 200);
+        assert_mint_event(&emitted_events[2], accounts.bob, 50);
+    }
+
+    #[ink::test]
+    fn mint_to_many_exceeding_cap_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        assert_eq!(
+            PSP22Mintable::mint_to_many(&mut psp22, vec![(accounts.bob, 600), (accounts.charlie, 401)]),
+            Err(PSP22Error::Custom(String::from("Cap exceeded")))
+        );
+
+        // Nothing is minted, even for the recipients that fit under the cap
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.charlie), 0);
+        assert_eq!(PSP22::total_supply(&psp22), 0);
+    }
+
+    #[ink::test]
+    fn mint_to_many_overflow_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        assert_eq!(
+            PSP22Mintable::mint_to_many(&mut psp22, vec![(accounts.bob, 1), (accounts.charlie, Balance::MAX)]),
+            Err(PSP22Error::Custom(String::from("Overflow")))
+        );
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+        assert_eq!(PSP22::total_supply(&psp22), 0);
+    }
+
+    #[ink::test]
+    fn mint_to_many_requires_minter_role() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new();
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Mintable::mint_to_many(&mut psp22, vec![(accounts.bob, 100)]),
+            Err(PSP22Error::Custom(String::from("AC::MissingRole")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+    }
+}
@@ -51,6 +51,8 @@ mod psp22_mintable {
     pub struct PSP22Struct {
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        mintable: mintable::Data,
         // field for testing _before_token_transfer
         return_err_on_before: bool,
         // field for testing _after_token_transfer
@@ -249,4 +251,32 @@ mod psp22_mintable {
             Err(PSP22Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn mint_to_many_without_minter_fails() {
+        // Constructor works.
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        // Nobody is a minter until the contract overrides `_check_minter`
+        assert_eq!(
+            PSP22Mintable::mint_to_many(&mut psp22, vec![(accounts.bob, 10)]),
+            Err(PSP22Error::Custom(String::from("NotMinter")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+        assert_eq!(PSP22::total_supply(&psp22), 100);
+    }
+
+    #[ink::test]
+    fn finish_minting_without_minter_fails() {
+        // Constructor works.
+        let mut psp22 = PSP22Struct::new(100);
+        let accounts = accounts();
+        // Nobody can finish minting until the contract overrides `_check_minter`
+        assert_eq!(
+            PSP22Mintable::finish_minting(&mut psp22),
+            Err(PSP22Error::Custom(String::from("NotMinter")))
+        );
+        assert!(!PSP22Mintable::minting_finished(&psp22));
+        assert!(PSP22Mintable::mint(&mut psp22, accounts.alice, 10).is_ok());
+    }
 }
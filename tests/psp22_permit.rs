@@ -0,0 +1,257 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Permit, Nonces)]
+#[openbrush::contract]
+mod psp22_permit {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        env::{
+            test::{
+                advance_block,
+                set_block_timestamp,
+            },
+            DefaultEnvironment,
+        },
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    /// Event emitted when a permit of `owner` with `nonce` is consumed.
+    #[ink(event)]
+    pub struct PermitUsed {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+        nonce: u64,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        nonces: nonces::Data,
+        #[storage_field]
+        permit: permit::Data,
+        // field for testing the block-based clock
+        block_number_clock: bool,
+    }
+
+    type Event = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(psp22::Internal)]
+    fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+        self.env().emit_event(Approval {
+            owner,
+            spender,
+            value: amount,
+        });
+    }
+
+    #[overrider(permit::Internal)]
+    fn _emit_permit_used_event(&self, owner: AccountId, spender: AccountId, value: Balance, nonce: u64) {
+        self.env().emit_event(PermitUsed {
+            owner,
+            spender,
+            value,
+            nonce,
+        });
+    }
+
+    // A fixed domain separator keeps the signature in `PERMIT_SIGNATURE` valid
+    #[overrider(permit::Internal)]
+    fn _domain_separator(&mut self) -> [u8; 32] {
+        [0x11; 32]
+    }
+
+    #[overrider(permit::Internal)]
+    fn _clock(&self) -> u64 {
+        if self.block_number_clock {
+            self.env().block_number() as u64
+        } else {
+            permit::InternalImpl::_clock(self)
+        }
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(block_number_clock: bool) -> Self {
+            Self {
+                block_number_clock,
+                ..Default::default()
+            }
+        }
+    }
+
+    fn permit(instance: &mut PSP22Struct, deadline: u64) -> Result<(), PSP22Error> {
+        let accounts = accounts();
+        // The deadline is checked before the signature, so a valid deadline fails on the signature
+        PSP22Permit::permit(
+            instance,
+            accounts.alice,
+            accounts.bob,
+            10,
+            deadline,
+            Signature::ECDSA([0; 65]),
+        )
+    }
+
+    const OWNER: [u8; 32] = [
+        0xfa, 0xfb, 0x3b, 0xc8, 0x28, 0x2b, 0x8f, 0xaa, 0x42, 0xf5, 0x29, 0x3f, 0x8b, 0xc8, 0xe7, 0xe3, 0x5f, 0x8e,
+        0x92, 0x19, 0xf8, 0xe3, 0xd8, 0x48, 0xda, 0x87, 0x4b, 0x25, 0xc7, 0x23, 0x37, 0xef,
+    ];
+
+    const SPENDER: [u8; 32] = [0x22; 32];
+
+    // Signature of `OWNER` under EIP-712 for the permit of 10 tokens to `SPENDER`
+    // with the deadline `u64::MAX` and the nonce 0
+    const PERMIT_SIGNATURE: [u8; 65] = [
+        0x46, 0x6c, 0x27, 0x62, 0xa6, 0x94, 0xc9, 0x1d, 0x73, 0x7e, 0xbc, 0x16, 0x18, 0x56, 0xbf, 0x02, 0x0f, 0xaa,
+        0xdb, 0x88, 0xc4, 0x22, 0x16, 0xa5, 0xb4, 0x39, 0x57, 0xcf, 0x76, 0xa8, 0x13, 0xa1, 0x33, 0xb0, 0xde, 0xee,
+        0xc4, 0x82, 0x79, 0xde, 0x81, 0xe2, 0x35, 0xf8, 0xfe, 0x3d, 0x4c, 0x48, 0xbc, 0x05, 0x32, 0xe8, 0xba, 0xc6,
+        0x90, 0x9e, 0x08, 0xeb, 0x9b, 0xbd, 0xaf, 0xa8, 0x9d, 0xe6, 0x01,
+    ];
+
+    #[ink::test]
+    fn permit_emits_approval_and_permit_used_events() {
+        let mut instance = PSP22Struct::new(false);
+        let owner = AccountId::from(OWNER);
+        let spender = AccountId::from(SPENDER);
+
+        assert!(PSP22Permit::permit(
+            &mut instance,
+            owner,
+            spender,
+            10,
+            u64::MAX,
+            Signature::ECDSA(PERMIT_SIGNATURE)
+        )
+        .is_ok());
+        assert_eq!(PSP22::allowance(&instance, owner, spender), 10);
+        assert_eq!(Nonces::nonces(&instance, owner), 1);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+
+        let decoded_events = emitted_events
+            .iter()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect::<Vec<_>>();
+
+        if let Event::Approval(Approval {
+            owner: approval_owner,
+            spender: approval_spender,
+            value,
+        }) = &decoded_events[0]
+        {
+            assert_eq!(*approval_owner, owner);
+            assert_eq!(*approval_spender, spender);
+            assert_eq!(*value, 10);
+        } else {
+            panic!("encountered unexpected event kind: expected an Approval event")
+        }
+
+        if let Event::PermitUsed(PermitUsed {
+            owner: permit_owner,
+            spender: permit_spender,
+            value,
+            nonce,
+        }) = &decoded_events[1]
+        {
+            assert_eq!(*permit_owner, owner);
+            assert_eq!(*permit_spender, spender);
+            assert_eq!(*value, 10);
+            // The consumed nonce
+            assert_eq!(*nonce, 0);
+        } else {
+            panic!("encountered unexpected event kind: expected a PermitUsed event")
+        }
+    }
+
+    #[ink::test]
+    fn permit_with_invalid_signature_emits_no_events() {
+        let mut instance = PSP22Struct::new(false);
+
+        assert_eq!(
+            PSP22Permit::permit(
+                &mut instance,
+                AccountId::from(OWNER),
+                AccountId::from(SPENDER),
+                11,
+                u64::MAX,
+                Signature::ECDSA(PERMIT_SIGNATURE)
+            ),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+        assert_eq!(ink::env::test::recorded_events().count(), 0);
+    }
+
+    #[ink::test]
+    fn permit_with_timestamp_clock_rejects_expired_deadline() {
+        let mut instance = PSP22Struct::new(false);
+        set_block_timestamp::<DefaultEnvironment>(1000);
+
+        assert_eq!(permit(&mut instance, 999), Err(PSP22Error::PermitExpired));
+        assert_eq!(permit(&mut instance, 1000), Err(PSP22Error::PermitInvalidSignature));
+    }
+
+    #[ink::test]
+    fn permit_with_block_number_clock_rejects_expired_deadline() {
+        let mut instance = PSP22Struct::new(true);
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        for _ in 0..5 {
+            advance_block::<DefaultEnvironment>();
+        }
+        let block_number = ink::env::block_number::<DefaultEnvironment>() as u64;
+
+        assert_eq!(permit(&mut instance, block_number - 1), Err(PSP22Error::PermitExpired));
+        assert_eq!(
+            permit(&mut instance, block_number),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+        // The deadline is expired by the timestamp, but not by the block number
+        assert_eq!(permit(&mut instance, 999), Err(PSP22Error::PermitInvalidSignature));
+    }
+}
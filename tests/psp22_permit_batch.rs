@@ -0,0 +1,191 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Permit, Nonces)]
+#[openbrush::contract]
+mod psp22_permit_batch {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        nonces: nonces::Data,
+        #[storage_field]
+        permit: permit::Data,
+    }
+
+    // Accepts the signatures created by `sign` instead of real ECDSA signatures
+    #[overrider(permit::Internal)]
+    fn _verify_permit(
+        &mut self,
+        _owner: AccountId,
+        _spender: AccountId,
+        _amount: Balance,
+        _deadline: u64,
+        nonce: u64,
+        signature: &Signature,
+    ) -> Result<(), PSP22Error> {
+        if signature == &sign(nonce) {
+            Ok(())
+        } else {
+            Err(PSP22Error::PermitInvalidSignature)
+        }
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn sign(nonce: u64) -> Signature {
+        Signature::ECDSA([nonce as u8 + 1; 65])
+    }
+
+    fn permit_args(owner: AccountId, spender: AccountId, value: Balance, signature: Signature) -> PermitArgs {
+        PermitArgs {
+            owner,
+            spender,
+            value,
+            deadline: 1000,
+            signature,
+        }
+    }
+
+    #[ink::test]
+    fn permit_batch_applies_all_permits() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![
+                    permit_args(accounts.alice, accounts.bob, 10, sign(0)),
+                    permit_args(accounts.charlie, accounts.bob, 20, sign(0)),
+                    permit_args(accounts.alice, accounts.eve, 30, sign(1)),
+                ]
+            ),
+            Ok(())
+        );
+
+        assert_eq!(PSP22::allowance(&instance, accounts.alice, accounts.bob), 10);
+        assert_eq!(PSP22::allowance(&instance, accounts.charlie, accounts.bob), 20);
+        assert_eq!(PSP22::allowance(&instance, accounts.alice, accounts.eve), 30);
+    }
+
+    #[ink::test]
+    fn permit_batch_consumes_nonce_per_permit() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![
+                    permit_args(accounts.alice, accounts.bob, 10, sign(0)),
+                    permit_args(accounts.alice, accounts.eve, 20, sign(1)),
+                    permit_args(accounts.alice, accounts.django, 30, sign(2)),
+                    permit_args(accounts.charlie, accounts.bob, 40, sign(0)),
+                ]
+            ),
+            Ok(())
+        );
+
+        assert_eq!(Nonces::nonces(&instance, accounts.alice), 3);
+        assert_eq!(Nonces::nonces(&instance, accounts.charlie), 1);
+        assert_eq!(Nonces::nonces(&instance, accounts.bob), 0);
+
+        // The next batch continues from the consumed nonces
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![permit_args(accounts.alice, accounts.bob, 50, sign(3))]
+            ),
+            Ok(())
+        );
+        assert_eq!(Nonces::nonces(&instance, accounts.alice), 4);
+        assert_eq!(PSP22::allowance(&instance, accounts.alice, accounts.bob), 50);
+    }
+
+    #[ink::test]
+    fn permit_batch_with_invalid_signature_fails() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![
+                    permit_args(accounts.alice, accounts.bob, 10, sign(0)),
+                    permit_args(accounts.charlie, accounts.bob, 20, Signature::ECDSA([0; 65])),
+                ]
+            ),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+
+        // A permit signed with an already used nonce of the batch is invalid as well
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![
+                    permit_args(accounts.alice, accounts.bob, 10, sign(0)),
+                    permit_args(accounts.alice, accounts.eve, 20, sign(0)),
+                ]
+            ),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+
+        // None of the permits is applied
+        assert_eq!(PSP22::allowance(&instance, accounts.alice, accounts.bob), 0);
+        assert_eq!(PSP22::allowance(&instance, accounts.charlie, accounts.bob), 0);
+        assert_eq!(Nonces::nonces(&instance, accounts.alice), 0);
+        assert_eq!(Nonces::nonces(&instance, accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn permit_batch_with_expired_permit_fails() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new();
+        set_block_timestamp::<DefaultEnvironment>(1001);
+
+        assert_eq!(
+            PSP22Permit::permit_batch(
+                &mut instance,
+                vec![permit_args(accounts.alice, accounts.bob, 10, sign(0))]
+            ),
+            Err(PSP22Error::PermitExpired)
+        );
+        assert_eq!(Nonces::nonces(&instance, accounts.alice), 0);
+    }
+}
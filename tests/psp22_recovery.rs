@@ -0,0 +1,155 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::implementation(PSP22, PSP22Recovery, Ownable)]
+#[openbrush::contract]
+mod psp22_recovery {
+    use ink::codegen::Env;
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        // Tokens held by the contract on behalf of its users
+        staked: Balance,
+    }
+
+    #[overrider(recovery::Internal)]
+    fn _protected_balance(&self, token: &AccountId) -> Balance {
+        if *token == Self::env().account_id() {
+            self.staked
+        } else {
+            0
+        }
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = Self::env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn recover_self_works() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+
+        // Tokens are sent to the token contract by mistake.
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, this), 30);
+
+        assert!(PSP22Recovery::recover_self(&mut instance, accounts.bob).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, this), 0);
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 30);
+        assert_eq!(PSP22::balance_of(&instance, accounts.alice), 70);
+        assert_eq!(PSP22::total_supply(&instance), 100);
+    }
+
+    #[ink::test]
+    fn recover_self_fails_for_non_owner() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Recovery::recover_self(&mut instance, accounts.bob),
+            Err(PSP22Error::Custom(String::from("O::CallerIsNotOwner")))
+        );
+        assert_eq!(PSP22::balance_of(&instance, this), 30);
+    }
+
+    #[ink::test]
+    fn recover_self_keeps_protected_balance() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+        instance.staked = 20;
+
+        assert!(PSP22Recovery::recover_self(&mut instance, accounts.bob).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, this), 20);
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn rescue_token_works_for_own_token() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+
+        assert!(PSP22Recovery::rescue_token(&mut instance, this, accounts.bob, 25).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, this), 5);
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 25);
+    }
+
+    #[ink::test]
+    fn rescue_token_fails_for_protected_balance() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+        instance.staked = 20;
+
+        assert_eq!(
+            PSP22Recovery::rescue_token(&mut instance, this, accounts.bob, 11),
+            Err(PSP22Error::Custom(String::from("ProtectedBalance")))
+        );
+        assert!(PSP22Recovery::rescue_token(&mut instance, this, accounts.bob, 10).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, this), 20);
+    }
+
+    #[ink::test]
+    fn rescue_token_fails_for_non_owner() {
+        let accounts = accounts();
+        let mut instance = PSP22Struct::new(100);
+        let this = instance.env().account_id();
+        assert!(PSP22::transfer(&mut instance, this, 30, Vec::<u8>::new()).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Recovery::rescue_token(&mut instance, this, accounts.bob, 30),
+            Err(PSP22Error::Custom(String::from("O::CallerIsNotOwner")))
+        );
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Restricted)]
+#[openbrush::contract]
+mod psp22_restricted {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        restricted: restricted::Data,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        restricted::Internal::_check_restricted(self, from, to)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn mint_to_not_allowed_account_works() {
+        let accounts = accounts();
+        let psp22 = PSP22Struct::new(100);
+
+        assert!(!PSP22Restricted::is_allowed(&psp22, accounts.alice));
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn transfer_to_allowed_account_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        restricted::Internal::_set_allowed(&mut psp22, accounts.bob, true);
+
+        assert!(PSP22Restricted::is_allowed(&psp22, accounts.bob));
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn transfer_to_not_allowed_account_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]),
+            Err(PSP22Error::Custom(String::from("ReceiverNotAllowed")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn transfer_to_disallowed_account_fails() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+        restricted::Internal::_set_allowed(&mut psp22, accounts.bob, true);
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+
+        restricted::Internal::_set_allowed(&mut psp22, accounts.bob, false);
+
+        assert!(!PSP22Restricted::is_allowed(&psp22, accounts.bob));
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]),
+            Err(PSP22Error::Custom(String::from("ReceiverNotAllowed")))
+        );
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn burn_from_not_allowed_account_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(psp22::Internal::_burn_from(&mut psp22, accounts.alice, 10).is_ok());
+        assert_eq!(PSP22::total_supply(&psp22), 90);
+    }
+}
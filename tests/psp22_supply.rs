@@ -0,0 +1,116 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22)]
+#[openbrush::contract]
+mod psp22_supply {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    const REBATE: Balance = 5;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        rebate: bool,
+    }
+
+    /// Mints a rebate to the sender of every transfer or burn, if it is enabled.
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if let Some(from) = from {
+            if self.rebate {
+                psp22::Internal::_mint_to(self, *from, REBATE)?;
+            }
+        }
+        Ok(())
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(rebate: bool) -> Self {
+            Self {
+                rebate,
+                ..Default::default()
+            }
+        }
+
+        /// Mints `minted` tokens, burns `burned` of them and returns the total supply in one call.
+        #[ink(message)]
+        pub fn mint_burn_read(
+            &mut self,
+            account: AccountId,
+            minted: Balance,
+            burned: Balance,
+        ) -> Result<Balance, PSP22Error> {
+            psp22::Internal::_mint_to(self, account, minted)?;
+            psp22::Internal::_burn_from(self, account, burned)?;
+            Ok(PSP22::total_supply(self))
+        }
+    }
+
+    #[ink::test]
+    fn mint_then_burn_then_read_works() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(false);
+
+        assert_eq!(psp22.mint_burn_read(accounts.alice, 100, 30), Ok(70));
+        assert_eq!(psp22.mint_burn_read(accounts.bob, 50, 50), Ok(70));
+
+        assert_eq!(PSP22::total_supply(&psp22), 70);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 70);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn burn_reads_balance_updated_by_hook() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(true);
+
+        assert_eq!(psp22.mint_burn_read(accounts.alice, 100, 30), Ok(100 - 30 + REBATE));
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100 - 30 + REBATE);
+        assert_eq!(PSP22::total_supply(&psp22), PSP22::balance_of(&psp22, accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_reads_balance_updated_by_hook() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(true);
+        assert!(psp22::Internal::_mint_to(&mut psp22, accounts.alice, 100).is_ok());
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 40, vec![]).is_ok());
+
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100 - 40 + REBATE);
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 40);
+        assert_eq!(PSP22::total_supply(&psp22), 100 + REBATE);
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "governance"))]
+#[openbrush::implementation(PSP22, PSP22Votes, Nonces)]
+#[openbrush::contract]
+mod psp22_votes {
+    use ink::env::DefaultEnvironment;
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        votes: votes::Data,
+        #[storage_field]
+        nonces: nonces::Data,
+    }
+
+    impl TimestampProvider for Contract {}
+
+    #[overrider(psp22::Internal)]
+    fn _after_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        PSP22VotesInternal::_checkpoint_balances(self, from, to)
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    fn advance_block() -> BlockNumber {
+        ink::env::test::advance_block::<DefaultEnvironment>();
+        ink::env::block_number::<DefaultEnvironment>()
+    }
+
+    #[ink::test]
+    fn balance_of_at_block_tracks_transfers() {
+        let accounts = accounts();
+        let start = ink::env::block_number::<DefaultEnvironment>();
+        let mut psp22 = Contract::new(100);
+
+        let first = advance_block();
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 30, vec![]).is_ok());
+
+        let second = advance_block();
+        change_caller(accounts.bob);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 10, vec![]).is_ok());
+
+        let third = advance_block();
+        change_caller(accounts.alice);
+        assert!(PSP22::transfer(&mut psp22, accounts.charlie, 20, vec![]).is_ok());
+
+        advance_block();
+
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.alice, start), 100);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.bob, start), 0);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.charlie, start), 0);
+
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.alice, first), 70);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.bob, first), 30);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.charlie, first), 0);
+
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.alice, second), 70);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.bob, second), 20);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.charlie, second), 10);
+
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.alice, third), 50);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.bob, third), 20);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.charlie, third), 30);
+    }
+
+    #[ink::test]
+    fn balance_of_at_block_keeps_last_transfer_in_block() {
+        let accounts = accounts();
+        let mut psp22 = Contract::new(100);
+
+        let block = advance_block();
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 30, vec![]).is_ok());
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 20, vec![]).is_ok());
+
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.alice, block), 50);
+        assert_eq!(PSP22Votes::balance_of_at_block(&psp22, accounts.bob, block), 50);
+        assert_eq!(
+            PSP22Votes::balance_of_at_block(&psp22, accounts.alice, block),
+            PSP22::balance_of(&psp22, accounts.alice)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22ZeroTransfer)]
+#[openbrush::contract]
+mod psp22_zero_transfer {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        zero_transfer: zero_transfer::Data,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        zero_transfer::Internal::_check_zero_transfer(self, from, to, amount)
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn zero_transfer_works_by_default() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        assert!(!PSP22ZeroTransfer::rejects_zero_transfers(&psp22));
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 0, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn zero_transfer_fails_when_rejected() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        zero_transfer::Internal::_set_reject_zero_transfers(&mut psp22, true);
+
+        assert!(PSP22ZeroTransfer::rejects_zero_transfers(&psp22));
+        assert_eq!(
+            PSP22::transfer(&mut psp22, accounts.bob, 0, vec![]),
+            Err(PSP22Error::Custom(String::from("ZeroTransfer")))
+        );
+        assert_eq!(
+            psp22::Internal::_mint_to(&mut psp22, accounts.bob, 0),
+            Err(PSP22Error::Custom(String::from("ZeroTransfer")))
+        );
+
+        // Non-zero transfers are not affected
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 10, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&psp22, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn zero_transfer_works_when_rejecting_is_disabled() {
+        let accounts = accounts();
+        let mut psp22 = PSP22Struct::new(100);
+
+        zero_transfer::Internal::_set_reject_zero_transfers(&mut psp22, true);
+        zero_transfer::Internal::_set_reject_zero_transfers(&mut psp22, false);
+
+        assert!(PSP22::transfer(&mut psp22, accounts.bob, 0, vec![]).is_ok());
+    }
+}
@@ -21,7 +21,7 @@
 
 #[cfg(feature = "psp34")]
 #[openbrush::implementation(PSP34)]
-#[openbrush::contract]
+#[openbrush::contract(test_exports)]
 mod psp34 {
     use ink::{
         codegen::{
@@ -35,6 +35,7 @@ mod psp34 {
         test_utils::{
             accounts,
             change_caller,
+            decode_events,
         },
         traits::{
             Storage,
@@ -187,7 +188,7 @@ mod psp34 {
         // Bob cannot transfer not owned tokens.
         assert_eq!(
             PSP34::transfer(&mut nft, accounts.eve, Id::U8(1u8), vec![]),
-            Err(PSP34Error::NotApproved)
+            Err(PSP34Error::NotOwner)
         );
     }
 
@@ -242,6 +243,74 @@ mod psp34 {
         );
     }
 
+    #[ink::test]
+    fn approve_batch_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let ids = vec![Id::U8(1u8), Id::U8(2u8), Id::U8(3u8)];
+        for id in ids.iter() {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, id.clone()).is_ok());
+        }
+
+        assert!(PSP34::approve_batch(&mut nft, accounts.bob, ids.clone(), true).is_ok());
+
+        for id in ids.iter() {
+            assert!(PSP34::allowance(&nft, accounts.alice, accounts.bob, Some(id.clone())));
+        }
+        let events = decode_events::<Event>();
+        assert_eq!(events.len(), 6);
+        for (event, id) in events[3..].iter().zip(ids.iter()) {
+            if let Event::Approval(Approval {
+                from,
+                to,
+                id: approved_id,
+                approved,
+            }) = event
+            {
+                assert_eq!(*from, accounts.alice);
+                assert_eq!(*to, accounts.bob);
+                assert_eq!(approved_id, &Some(id.clone()));
+                assert!(*approved);
+            } else {
+                panic!("encountered unexpected event kind: expected an Approval event")
+            }
+        }
+
+        // Each approval is effective
+        change_caller(accounts.bob);
+        for id in ids {
+            assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.eve, id, vec![]).is_ok());
+        }
+        assert_eq!(PSP34::balance_of(&nft, accounts.eve), 3);
+    }
+
+    #[ink::test]
+    fn approve_batch_with_not_owned_token_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(2u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.charlie, Id::U8(3u8)).is_ok());
+
+        assert_eq!(
+            PSP34::approve_batch(
+                &mut nft,
+                accounts.bob,
+                vec![Id::U8(1u8), Id::U8(2u8), Id::U8(3u8)],
+                true
+            ),
+            Err(PSP34Error::NotApproved)
+        );
+        assert_eq!(
+            PSP34::approve_batch(&mut nft, accounts.bob, vec![Id::U8(1u8), Id::U8(4u8)], true),
+            Err(PSP34Error::TokenNotExists)
+        );
+
+        // None of the tokens was approved
+        assert!(!PSP34::allowance(&nft, accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        assert!(!PSP34::allowance(&nft, accounts.alice, accounts.bob, Some(Id::U8(2u8))));
+    }
+
     #[ink::test]
     fn approved_transfer_works() {
         let accounts = accounts();
@@ -256,7 +325,7 @@ mod psp34 {
         // Get contract address.
         change_caller(accounts.bob);
         // Bob transfers token Id 1 from Alice to Eve.
-        assert!(PSP34::transfer(&mut nft, accounts.eve, Id::U8(1u8), vec![]).is_ok());
+        assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.eve, Id::U8(1u8), vec![]).is_ok());
         // TokenId 3 is owned by Eve.
         assert_eq!(PSP34::owner_of(&mut nft, Id::U8(1u8)), Some(accounts.eve));
         // Alice has one token left
@@ -284,6 +353,71 @@ mod psp34 {
         assert_eq!(PSP34::total_supply(&mut nft,), 3)
     }
 
+    #[ink::test]
+    fn owners_of_works() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(3u8)).is_ok());
+
+        // The result is aligned with the input, non-existent tokens have no owner.
+        assert_eq!(
+            PSP34::owners_of(&nft, vec![Id::U8(3u8), Id::U8(2u8), Id::U8(1u8), Id::U8(3u8)]),
+            Ok(vec![Some(accounts.bob), None, Some(accounts.alice), Some(accounts.bob)])
+        );
+        assert_eq!(PSP34::owners_of(&nft, vec![]), Ok(vec![]));
+    }
+
+    #[ink::test]
+    fn owners_of_rejects_too_many_ids() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert_eq!(
+            PSP34::owners_of(&nft, vec![Id::U8(1u8); MAX_OWNERS_OF_IDS]),
+            Ok(vec![Some(accounts.alice); MAX_OWNERS_OF_IDS])
+        );
+        assert_eq!(
+            PSP34::owners_of(&nft, vec![Id::U8(1u8); MAX_OWNERS_OF_IDS + 1]),
+            Err(PSP34Error::Custom(String::from("BatchTooLarge")))
+        );
+    }
+
+    #[ink::test]
+    fn exists_works() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        // Minted token exists and has an owner
+        assert!(PSP34::exists(&nft, Id::U8(1u8)));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+
+        // Never minted token doesn't exist
+        assert!(!PSP34::exists(&nft, Id::U8(2u8)));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), None);
+
+        // Burned token doesn't exist anymore
+        assert!(psp34::Internal::_burn_from(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(!PSP34::exists(&nft, Id::U8(1u8)));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), None);
+    }
+
+    #[ink::test]
+    fn exists_works_for_zero_address_owner() {
+        let zero_address = AccountId::from([0x0; 32]);
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, zero_address, Id::U8(1u8)).is_ok());
+
+        assert!(PSP34::exists(&nft, Id::U8(1u8)));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(zero_address));
+    }
+
     #[ink::test]
     fn approved_for_all_works() {
         let accounts = accounts();
@@ -300,13 +434,13 @@ mod psp34 {
 
         change_caller(accounts.bob);
         // Bob transfers token Id 1 from Alice to Eve.
-        assert!(PSP34::transfer(&mut nft, accounts.eve, Id::U8(1u8), vec![]).is_ok());
+        assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.eve, Id::U8(1u8), vec![]).is_ok());
         // TokenId 1 is owned by Eve.
         assert_eq!(PSP34::owner_of(&mut nft, Id::U8(1u8)), Some(accounts.eve));
         // Alice owns 1 token.
         assert_eq!(PSP34::balance_of(&mut nft, accounts.alice), 1);
         // Bob transfers token Id 2 from Alice to Eve.
-        assert!(PSP34::transfer(&mut nft, accounts.eve, Id::U8(2u8), vec![]).is_ok());
+        assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.eve, Id::U8(2u8), vec![]).is_ok());
         // Bob does not owns tokens.
         assert_eq!(PSP34::balance_of(&mut nft, accounts.bob), 0);
         // Eve owns 2 tokens.
@@ -362,13 +496,72 @@ mod psp34 {
         assert_eq!(PSP34::balance_of(&mut nft, accounts.eve), 0);
         // Get contract address.
         change_caller(accounts.bob);
-        // Eve is not an approved operator by Alice.
+        // Bob is not an approved operator by Alice.
         assert_eq!(
-            PSP34::transfer(&mut nft, accounts.frank, Id::U8(1u8), vec![]),
+            PSP34::transfer_from(&mut nft, accounts.alice, accounts.frank, Id::U8(1u8), vec![]),
             Err(PSP34Error::NotApproved)
         );
     }
 
+    #[ink::test]
+    fn approved_operator_cannot_use_transfer() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        // Approve all tokens for Bob on behalf of Alice.
+        assert!(PSP34::approve(&mut nft, accounts.bob, None, true).is_ok());
+
+        change_caller(accounts.bob);
+        // Bob must use `transfer_from` to move the token of Alice.
+        assert_eq!(
+            PSP34::transfer(&mut nft, accounts.eve, Id::U8(1u8), vec![]),
+            Err(PSP34Error::NotOwner)
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn owner_transfer_from_works() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        // Alice doesn't need an approval to transfer her own token.
+        assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.bob, Id::U8(1u8), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.bob));
+
+        let events = decode_events::<Event>();
+        if let Event::Transfer(Transfer { from, to, id }) = &events[1] {
+            assert_eq!(*from, Some(accounts.alice));
+            assert_eq!(*to, Some(accounts.bob));
+            assert_eq!(*id, Id::U8(1u8));
+        } else {
+            panic!("encountered unexpected event kind: expected a Transfer event")
+        }
+    }
+
+    #[ink::test]
+    fn transfer_from_not_owner_should_fail() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(PSP34::approve(&mut nft, accounts.bob, None, true).is_ok());
+
+        change_caller(accounts.bob);
+        // Token Id 1 is owned by Alice, not by Charlie.
+        assert_eq!(
+            PSP34::transfer_from(&mut nft, accounts.charlie, accounts.eve, Id::U8(1u8), vec![]),
+            Err(PSP34Error::NotOwner)
+        );
+        assert_eq!(
+            PSP34::transfer_from(&mut nft, accounts.alice, accounts.eve, Id::U8(2u8), vec![]),
+            Err(PSP34Error::TokenNotExists)
+        );
+    }
+
     #[ink::test]
     fn before_token_transfer_should_fail_transfer() {
         let accounts = accounts();
@@ -408,4 +601,55 @@ mod psp34 {
             Err(PSP34Error::Custom(String::from("Error on _after_token_transfer")))
         );
     }
+
+    #[ink::test]
+    fn transfer_clears_token_approvals() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        // Approve token Id 1 for Bob and all tokens for Eve on behalf of Alice.
+        assert!(PSP34::approve(&mut nft, accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+        assert!(PSP34::approve(&mut nft, accounts.eve, None, true).is_ok());
+        // Eve transfers token Id 1 from Alice to Charlie and Charlie sends it back.
+        change_caller(accounts.eve);
+        assert!(PSP34::transfer_from(&mut nft, accounts.alice, accounts.charlie, Id::U8(1u8), vec![]).is_ok());
+        change_caller(accounts.charlie);
+        assert!(PSP34::transfer(&mut nft, accounts.alice, Id::U8(1u8), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+        // The old approval of Bob doesn't authorize him anymore.
+        assert!(!PSP34::allowance(&nft, accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP34::transfer_from(&mut nft, accounts.alice, accounts.bob, Id::U8(1u8), vec![]),
+            Err(PSP34Error::NotApproved)
+        );
+        // The approval for all tokens is kept.
+        assert!(PSP34::allowance(&nft, accounts.alice, accounts.eve, None));
+    }
+
+    #[ink::test]
+    fn burn_clears_token_approvals() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(PSP34::approve(&mut nft, accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+        // The token is burned and minted again to Alice.
+        assert!(psp34::Internal::_burn_from(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        // The old approval of Bob doesn't authorize him anymore.
+        assert!(!PSP34::allowance(&nft, accounts.alice, accounts.bob, Some(Id::U8(1u8))));
+    }
+
+    #[ink::test]
+    fn internal_methods_are_exported_for_tests() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        // Internal methods are callable directly on the contract.
+        assert!(nft._mint_to(accounts.alice, Id::U8(1u8)).is_ok());
+        assert_eq!(nft._owner_of(&Id::U8(1u8)), Some(accounts.alice));
+        assert_eq!(nft._mint_to(accounts.bob, Id::U8(1u8)), Err(PSP34Error::TokenExists));
+    }
 }
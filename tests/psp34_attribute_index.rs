@@ -0,0 +1,115 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Metadata, PSP34AttributeIndex)]
+#[openbrush::contract]
+mod psp34_attribute_index {
+    use openbrush::traits::{
+        Storage,
+        String,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        attribute_index: attribute_index::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        fn set_attribute(&mut self, id: u8, key: &str, value: &str) {
+            metadata::Internal::_set_attribute(self, Id::U8(id), String::from(key), String::from(value));
+        }
+
+        fn tokens(&self, key: &str, value: &str) -> Vec<Id> {
+            PSP34AttributeIndex::tokens_with_attribute(self, String::from(key), String::from(value), 0, 10)
+        }
+    }
+
+    #[ink::test]
+    fn tokens_with_attribute_works() {
+        let mut nft = PSP34Struct::new();
+        nft.set_attribute(1, "COLOR", "RED");
+        nft.set_attribute(2, "COLOR", "BLUE");
+        nft.set_attribute(3, "COLOR", "RED");
+        nft.set_attribute(3, "SIZE", "XL");
+
+        assert_eq!(nft.tokens("COLOR", "RED"), vec![Id::U8(1), Id::U8(3)]);
+        assert_eq!(nft.tokens("COLOR", "BLUE"), vec![Id::U8(2)]);
+        assert_eq!(nft.tokens("SIZE", "XL"), vec![Id::U8(3)]);
+        assert_eq!(nft.tokens("SIZE", "RED"), vec![]);
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, Id::U8(3), String::from("COLOR")),
+            Some(String::from("RED"))
+        );
+    }
+
+    #[ink::test]
+    fn tokens_with_attribute_paginates() {
+        let mut nft = PSP34Struct::new();
+        for id in 0..5 {
+            nft.set_attribute(id, "COLOR", "RED");
+        }
+
+        assert_eq!(
+            PSP34AttributeIndex::tokens_with_attribute(&nft, String::from("COLOR"), String::from("RED"), 1, 2),
+            vec![Id::U8(1), Id::U8(2)]
+        );
+        assert_eq!(
+            PSP34AttributeIndex::tokens_with_attribute(&nft, String::from("COLOR"), String::from("RED"), 3, 10),
+            vec![Id::U8(3), Id::U8(4)]
+        );
+        assert_eq!(
+            PSP34AttributeIndex::tokens_with_attribute(&nft, String::from("COLOR"), String::from("RED"), 5, 10),
+            vec![]
+        );
+    }
+
+    #[ink::test]
+    fn changing_attribute_moves_token_between_buckets() {
+        let mut nft = PSP34Struct::new();
+        nft.set_attribute(1, "COLOR", "RED");
+        nft.set_attribute(2, "COLOR", "RED");
+
+        nft.set_attribute(1, "COLOR", "BLUE");
+
+        assert_eq!(nft.tokens("COLOR", "RED"), vec![Id::U8(2)]);
+        assert_eq!(nft.tokens("COLOR", "BLUE"), vec![Id::U8(1)]);
+
+        // Setting the same value again doesn't duplicate the token in the bucket
+        nft.set_attribute(1, "COLOR", "BLUE");
+        assert_eq!(nft.tokens("COLOR", "BLUE"), vec![Id::U8(1)]);
+
+        nft.set_attribute(1, "COLOR", "RED");
+        assert_eq!(nft.tokens("COLOR", "RED"), vec![Id::U8(2), Id::U8(1)]);
+        assert_eq!(nft.tokens("COLOR", "BLUE"), vec![]);
+    }
+}
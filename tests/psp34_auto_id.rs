@@ -0,0 +1,100 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34AutoId)]
+#[openbrush::contract]
+mod psp34_auto_id {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        auto_id: auto_id::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn mint_next_mints_sequential_ids() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        assert_eq!(PSP34AutoId::next_id(&nft), 0);
+        assert_eq!(PSP34AutoId::mint_next(&mut nft, accounts.alice), Ok(Id::U128(0)));
+        assert_eq!(PSP34AutoId::mint_next(&mut nft, accounts.bob), Ok(Id::U128(1)));
+        assert_eq!(PSP34AutoId::mint_next(&mut nft, accounts.alice), Ok(Id::U128(2)));
+
+        assert_eq!(PSP34AutoId::next_id(&nft), 3);
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 2);
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), 1);
+    }
+
+    #[ink::test]
+    fn returned_id_matches_owner() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        for owner in [accounts.alice, accounts.bob, accounts.charlie] {
+            let id = PSP34AutoId::mint_next(&mut nft, owner).unwrap();
+            assert_eq!(PSP34::owner_of(&nft, id), Some(owner));
+        }
+    }
+
+    #[ink::test]
+    fn mint_next_continues_from_set_next_id() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        auto_id::Internal::_set_next_id(&mut nft, 100);
+
+        assert_eq!(PSP34AutoId::mint_next(&mut nft, accounts.alice), Ok(Id::U128(100)));
+        assert_eq!(PSP34AutoId::next_id(&nft), 101);
+    }
+
+    #[ink::test]
+    fn mint_next_fails_on_overflow() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        auto_id::Internal::_set_next_id(&mut nft, u128::MAX);
+
+        assert_eq!(
+            PSP34AutoId::mint_next(&mut nft, accounts.alice),
+            Err(PSP34Error::Custom(String::from("Overflow")))
+        );
+        assert_eq!(PSP34::total_supply(&nft), 0);
+        assert_eq!(PSP34AutoId::next_id(&nft), u128::MAX);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp34", feature = "ownable"))]
+#[openbrush::implementation(PSP34, PSP34Capped, Ownable)]
+#[openbrush::contract]
+mod psp34_capped {
+    use openbrush::{
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        capped: capped::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(cap: u128, reserved: u128) -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            capped::Internal::_init_cap(&mut instance, cap).expect("Should init cap");
+            capped::Internal::_set_reserved(&mut instance, reserved).expect("Should set reserved");
+            instance
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+            capped::Internal::_mint_public(self, to, id)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn mint_reserved(&mut self, to: AccountId, id: Id) -> Result<(), PSP34Error> {
+            capped::Internal::_mint_reserved(self, to, id)
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let nft = PSP34Struct::new(3, 1);
+
+        assert_eq!(PSP34Capped::cap(&nft), 3);
+        assert_eq!(PSP34Capped::reserved(&nft), 1);
+    }
+
+    #[ink::test]
+    fn set_reserved_above_cap_fails() {
+        let mut nft = PSP34Struct::new(3, 1);
+
+        assert_eq!(
+            capped::Internal::_set_reserved(&mut nft, 4),
+            Err(PSP34Error::Custom(String::from("ReservedExceedsCap")))
+        );
+        assert_eq!(PSP34Capped::reserved(&nft), 1);
+    }
+
+    #[ink::test]
+    fn public_mint_is_limited_to_unreserved_part_of_cap() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(3, 1);
+        change_caller(accounts.bob);
+
+        assert!(nft.mint(accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(nft.mint(accounts.bob, Id::U8(2u8)).is_ok());
+        assert_eq!(
+            nft.mint(accounts.bob, Id::U8(3u8)),
+            Err(PSP34Error::Custom(String::from("CapExceeded")))
+        );
+        assert_eq!(PSP34::total_supply(&nft), 2);
+    }
+
+    #[ink::test]
+    fn owner_mints_into_reserve() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(3, 1);
+        assert!(nft.mint(accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(nft.mint(accounts.bob, Id::U8(2u8)).is_ok());
+
+        assert!(nft.mint_reserved(accounts.alice, Id::U8(3u8)).is_ok());
+
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(3u8)), Some(accounts.alice));
+        assert_eq!(PSP34::total_supply(&nft), 3);
+    }
+
+    #[ink::test]
+    fn reserved_mint_does_not_reduce_public_part_of_cap() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(3, 1);
+        assert!(nft.mint_reserved(accounts.alice, Id::U8(1u8)).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(nft.mint(accounts.bob, Id::U8(2u8)).is_ok());
+        assert!(nft.mint(accounts.bob, Id::U8(3u8)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 3);
+    }
+
+    #[ink::test]
+    fn non_owner_cannot_mint_into_reserve() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(3, 1);
+        change_caller(accounts.bob);
+
+        assert_eq!(
+            nft.mint_reserved(accounts.bob, Id::U8(1u8)),
+            Err(PSP34Error::Custom(String::from("O::CallerIsNotOwner")))
+        );
+    }
+
+    #[ink::test]
+    fn owner_mint_past_cap_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(3, 1);
+        assert!(nft.mint(accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(nft.mint(accounts.bob, Id::U8(2u8)).is_ok());
+        assert!(nft.mint_reserved(accounts.alice, Id::U8(3u8)).is_ok());
+
+        assert_eq!(
+            nft.mint_reserved(accounts.alice, Id::U8(4u8)),
+            Err(PSP34Error::Custom(String::from("CapExceeded")))
+        );
+        assert_eq!(PSP34::total_supply(&nft), 3);
+    }
+}
@@ -176,4 +176,128 @@ mod psp34_enumerable {
             Err(PSP34Error::TokenNotExists)
         );
     }
+
+    #[ink::test]
+    fn enumerable_burn_middle_token_keeps_indices_consistent() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        for id in 1..=3 {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(id)).is_ok());
+        }
+        // Destroy the middle token.
+        assert!(PSP34Burnable::burn(&mut nft, accounts.alice, Id::U8(2u8)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 2);
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 2);
+
+        let mut all_tokens = Vec::new();
+        let mut alice_tokens = Vec::new();
+        for index in 0..2u128 {
+            all_tokens.push(PSP34Enumerable::token_by_index(&nft, index).unwrap());
+            alice_tokens.push(PSP34Enumerable::owners_token_by_index(&nft, accounts.alice, index).unwrap());
+        }
+        // the last token took the place of the burned one
+        assert_eq!(all_tokens, vec![Id::U8(1u8), Id::U8(3u8)]);
+        assert_eq!(alice_tokens, vec![Id::U8(1u8), Id::U8(3u8)]);
+        // there is nothing past the new end
+        assert_eq!(
+            PSP34Enumerable::token_by_index(&nft, 2u128),
+            Err(PSP34Error::TokenNotExists)
+        );
+        assert_eq!(
+            PSP34Enumerable::owners_token_by_index(&nft, accounts.alice, 2u128),
+            Err(PSP34Error::TokenNotExists)
+        );
+
+        // the moved token can still be burned
+        assert!(PSP34Burnable::burn(&mut nft, accounts.alice, Id::U8(3u8)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 1);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 0u128), Ok(Id::U8(1u8)));
+        assert_eq!(
+            PSP34Enumerable::token_by_index(&nft, 1u128),
+            Err(PSP34Error::TokenNotExists)
+        );
+    }
+
+    #[ink::test]
+    fn enumerable_burn_last_minted_token_works() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        for id in 1..=3 {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(id)).is_ok());
+        }
+        // Destroy the last minted token.
+        assert!(PSP34Burnable::burn(&mut nft, accounts.alice, Id::U8(3u8)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 2);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 0u128), Ok(Id::U8(1u8)));
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 1u128), Ok(Id::U8(2u8)));
+        assert_eq!(
+            PSP34Enumerable::token_by_index(&nft, 2u128),
+            Err(PSP34Error::TokenNotExists)
+        );
+
+        // a new token takes the freed index
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(4u8)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 2u128), Ok(Id::U8(4u8)));
+    }
+
+    fn owners_tokens(nft: &PSP34Struct, owner: AccountId) -> Vec<Id> {
+        let balance = PSP34::balance_of(nft, owner) as u128;
+        // there is nothing past the end of the owner's index
+        assert_eq!(
+            PSP34Enumerable::owners_token_by_index(nft, owner, balance),
+            Err(PSP34Error::TokenNotExists)
+        );
+        (0..balance)
+            .map(|index| PSP34Enumerable::owners_token_by_index(nft, owner, index).unwrap())
+            .collect()
+    }
+
+    #[ink::test]
+    fn enumerable_repeated_transfers_keep_owner_indices_consistent() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        for id in 1..=3 {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(id)).is_ok());
+        }
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(4u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.charlie, Id::U8(5u8)).is_ok());
+
+        // The first transfer moves the token out of the middle of Alice's index,
+        // the following ones move the last entry of the index.
+        for _ in 0..2 {
+            // Alice -> Bob
+            change_caller(accounts.alice);
+            assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]).is_ok());
+            assert_eq!(owners_tokens(&nft, accounts.alice), vec![Id::U8(3u8), Id::U8(2u8)]);
+            assert_eq!(owners_tokens(&nft, accounts.bob), vec![Id::U8(4u8), Id::U8(1u8)]);
+            assert_eq!(owners_tokens(&nft, accounts.charlie), vec![Id::U8(5u8)]);
+
+            // Bob -> Charlie
+            change_caller(accounts.bob);
+            assert!(PSP34::transfer(&mut nft, accounts.charlie, Id::U8(1u8), vec![]).is_ok());
+            assert_eq!(owners_tokens(&nft, accounts.alice), vec![Id::U8(3u8), Id::U8(2u8)]);
+            assert_eq!(owners_tokens(&nft, accounts.bob), vec![Id::U8(4u8)]);
+            assert_eq!(owners_tokens(&nft, accounts.charlie), vec![Id::U8(5u8), Id::U8(1u8)]);
+
+            // Charlie -> Alice
+            change_caller(accounts.charlie);
+            assert!(PSP34::transfer(&mut nft, accounts.alice, Id::U8(1u8), vec![]).is_ok());
+            assert_eq!(
+                owners_tokens(&nft, accounts.alice),
+                vec![Id::U8(3u8), Id::U8(2u8), Id::U8(1u8)]
+            );
+            assert_eq!(owners_tokens(&nft, accounts.bob), vec![Id::U8(4u8)]);
+            assert_eq!(owners_tokens(&nft, accounts.charlie), vec![Id::U8(5u8)]);
+        }
+
+        // Transfers don't touch the global index.
+        assert_eq!(PSP34::total_supply(&nft), 5);
+        for id in 1..=5u8 {
+            assert_eq!(PSP34Enumerable::token_by_index(&nft, (id - 1) as u128), Ok(Id::U8(id)));
+        }
+    }
 }
@@ -0,0 +1,195 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Mintable, PSP34Burnable, PSP34Enumerable)]
+#[openbrush::contract]
+mod psp34_enumerable_sequential {
+    use openbrush::{
+        contracts::psp34::Id,
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        enumerable: enumerable::Data,
+        // field for testing the balances manager without the hook
+        skip_check: bool,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        if self.skip_check {
+            return Ok(())
+        }
+        enumerable::Internal::_check_sequential_supply(self, from, to, id)
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(first_id: u128) -> Self {
+            let mut instance = Self::default();
+            enumerable::Internal::_init_sequential_supply(&mut instance, first_id).unwrap();
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn sequential_mint_derives_total_supply() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(1);
+
+        for id in 1..=3u128 {
+            assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(id)).is_ok());
+        }
+
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 3);
+        // The list of all tokens isn't stored
+        assert_eq!(nft.enumerable.balances.count(&None), 0);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 0), Ok(Id::U128(1)));
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 2), Ok(Id::U128(3)));
+        assert_eq!(
+            PSP34Enumerable::token_by_index(&nft, 3),
+            Err(PSP34Error::TokenNotExists)
+        );
+        assert_eq!(
+            PSP34Enumerable::owners_token_by_index(&nft, accounts.alice, 1),
+            Ok(Id::U128(2))
+        );
+    }
+
+    #[ink::test]
+    fn non_sequential_mint_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(1);
+
+        assert_eq!(
+            PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(2)),
+            Err(PSP34Error::Custom(String::from("NonSequentialId")))
+        );
+        assert_eq!(
+            PSP34Mintable::mint(&mut nft, accounts.alice, Id::U8(1)),
+            Err(PSP34Error::Custom(String::from("NonSequentialId")))
+        );
+        assert_eq!(PSP34::total_supply(&nft), 0);
+    }
+
+    #[ink::test]
+    fn burn_at_ends_of_range_decreases_total_supply() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(1);
+
+        for id in 1..=4u128 {
+            assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(id)).is_ok());
+        }
+
+        assert!(PSP34Burnable::burn(&mut nft, accounts.alice, Id::U128(1)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 0), Ok(Id::U128(2)));
+
+        assert!(PSP34Burnable::burn(&mut nft, accounts.alice, Id::U128(4)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 2);
+        assert_eq!(
+            PSP34Enumerable::token_by_index(&nft, 2),
+            Err(PSP34Error::TokenNotExists)
+        );
+
+        // Minting continues after the last id of the range
+        assert!(PSP34Mintable::mint(&mut nft, accounts.bob, Id::U128(4)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 2), Ok(Id::U128(4)));
+    }
+
+    #[ink::test]
+    fn burn_in_middle_of_range_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(1);
+
+        for id in 1..=3u128 {
+            assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(id)).is_ok());
+        }
+
+        assert_eq!(
+            PSP34Burnable::burn(&mut nft, accounts.alice, Id::U128(2)),
+            Err(PSP34Error::Custom(String::from("NonContiguousBurn")))
+        );
+        assert_eq!(PSP34::total_supply(&nft), 3);
+    }
+
+    #[ink::test]
+    fn init_after_mint_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::default();
+
+        assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(1)).is_ok());
+        assert_eq!(
+            enumerable::Internal::_init_sequential_supply(&mut nft, 2),
+            Err(PSP34Error::Custom(String::from("TokensExist")))
+        );
+    }
+
+    #[ink::test]
+    fn balances_manager_checks_ids_without_hook() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(1);
+        nft.skip_check = true;
+
+        for id in 1..=3u128 {
+            assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(id)).is_ok());
+        }
+
+        assert_eq!(
+            PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(5)),
+            Err(PSP34Error::Custom(String::from("NonSequentialId")))
+        );
+        assert_eq!(
+            PSP34Mintable::mint(&mut nft, accounts.alice, Id::U8(4)),
+            Err(PSP34Error::Custom(String::from("NonSequentialId")))
+        );
+        assert_eq!(
+            PSP34Burnable::burn(&mut nft, accounts.alice, Id::U128(2)),
+            Err(PSP34Error::Custom(String::from("NonContiguousBurn")))
+        );
+
+        // The range is not corrupted by the rejected ids
+        assert_eq!(PSP34::total_supply(&nft), 3);
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 3);
+        assert_eq!(PSP34::owner_of(&nft, Id::U128(5)), None);
+        assert_eq!(PSP34::owner_of(&nft, Id::U128(2)), Some(accounts.alice));
+        assert_eq!(PSP34Enumerable::token_by_index(&nft, 2), Ok(Id::U128(3)));
+        assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U128(4)).is_ok());
+        assert_eq!(PSP34::total_supply(&nft), 4);
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Evolve)]
+#[openbrush::contract]
+mod psp34_evolve {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        #[ink(topic)]
+        id: Id,
+    }
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+    }
+
+    type Event = <PSP34Struct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(psp34::Internal)]
+    fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
+        self.env().emit_event(Transfer { from, to, id });
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn assert_transfer_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_from: Option<AccountId>,
+        expected_to: Option<AccountId>,
+        expected_id: Id,
+    ) {
+        if let Event::Transfer(Transfer { from, to, id }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+            assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+            assert_eq!(id, expected_id, "encountered invalid Transfer.id");
+        } else {
+            panic!("encountered unexpected event kind: expected a Transfer event")
+        }
+    }
+
+    #[ink::test]
+    fn evolve_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert!(PSP34Evolve::evolve(&mut nft, Id::U8(1u8), Id::U8(2u8)).is_ok());
+
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), None);
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), Some(accounts.alice));
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 1);
+        assert_eq!(PSP34::total_supply(&nft), 1);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 3);
+        assert_transfer_event(&emitted_events[1], Some(accounts.alice), None, Id::U8(1u8));
+        assert_transfer_event(&emitted_events[2], None, Some(accounts.alice), Id::U8(2u8));
+    }
+
+    #[ink::test]
+    fn evolve_by_approved_operator_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(PSP34::approve(&mut nft, accounts.bob, Some(Id::U8(1u8)), true).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP34Evolve::evolve(&mut nft, Id::U8(1u8), Id::U8(2u8)).is_ok());
+
+        // The evolved token stays with the owner
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), Some(accounts.alice));
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn evolve_by_unauthorized_caller_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP34Evolve::evolve(&mut nft, Id::U8(1u8), Id::U8(2u8)),
+            Err(PSP34Error::NotApproved)
+        );
+
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), None);
+    }
+
+    #[ink::test]
+    fn evolve_to_existing_id_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(2u8)).is_ok());
+
+        assert_eq!(
+            PSP34Evolve::evolve(&mut nft, Id::U8(1u8), Id::U8(2u8)),
+            Err(PSP34Error::TokenExists)
+        );
+
+        // Nothing is burned
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), Some(accounts.bob));
+        assert_eq!(PSP34::total_supply(&nft), 2);
+    }
+
+    #[ink::test]
+    fn evolve_not_existing_token_fails() {
+        let mut nft = PSP34Struct::new();
+
+        assert_eq!(
+            PSP34Evolve::evolve(&mut nft, Id::U8(1u8), Id::U8(2u8)),
+            Err(PSP34Error::TokenNotExists)
+        );
+    }
+}
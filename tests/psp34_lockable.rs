@@ -0,0 +1,140 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp34", feature = "access_control"))]
+#[openbrush::implementation(PSP34, PSP34Burnable, PSP34Lockable, AccessControl)]
+#[openbrush::contract]
+mod psp34_lockable {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        lockable: lockable::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        lockable::Internal::_check_lock(self, from, id)
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(locker: AccountId) -> Self {
+            let mut instance = Self::default();
+            access_control::Internal::_init_with_caller(&mut instance);
+            access_control::Internal::_setup_role(&mut instance, LOCKER, Some(locker));
+            psp34::Internal::_mint_to(&mut instance, Self::env().caller(), Id::U8(1)).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn locked_token_cant_be_transferred() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.charlie);
+
+        change_caller(accounts.charlie);
+        assert!(PSP34Lockable::lock(&mut nft, Id::U8(1)).is_ok());
+        assert!(PSP34Lockable::is_locked(&nft, Id::U8(1)));
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            PSP34::transfer(&mut nft, accounts.bob, Id::U8(1), vec![]),
+            Err(PSP34Error::Custom(String::from("TokenLocked")))
+        );
+        assert_eq!(
+            PSP34Burnable::burn(&mut nft, accounts.alice, Id::U8(1)),
+            Err(PSP34Error::Custom(String::from("TokenLocked")))
+        );
+        // The owner keeps the token
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1)), Some(accounts.alice));
+        assert_eq!(PSP34::balance_of(&nft, accounts.alice), 1);
+    }
+
+    #[ink::test]
+    fn unlocked_token_can_be_transferred() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.charlie);
+
+        change_caller(accounts.charlie);
+        assert!(PSP34Lockable::lock(&mut nft, Id::U8(1)).is_ok());
+        assert!(PSP34Lockable::unlock(&mut nft, Id::U8(1)).is_ok());
+        assert!(!PSP34Lockable::is_locked(&nft, Id::U8(1)));
+
+        change_caller(accounts.alice);
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1)), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn lock_without_locker_role_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.charlie);
+
+        // Even the owner of the token can't lock it
+        assert_eq!(
+            PSP34Lockable::lock(&mut nft, Id::U8(1)),
+            Err(PSP34Error::Custom(String::from("AC::MissingRole")))
+        );
+
+        change_caller(accounts.charlie);
+        assert!(PSP34Lockable::lock(&mut nft, Id::U8(1)).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP34Lockable::unlock(&mut nft, Id::U8(1)),
+            Err(PSP34Error::Custom(String::from("AC::MissingRole")))
+        );
+        assert!(PSP34Lockable::is_locked(&nft, Id::U8(1)));
+    }
+
+    #[ink::test]
+    fn lock_of_nonexistent_token_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.charlie);
+
+        change_caller(accounts.charlie);
+        assert_eq!(
+            PSP34Lockable::lock(&mut nft, Id::U8(2)),
+            Err(PSP34Error::TokenNotExists)
+        );
+    }
+}
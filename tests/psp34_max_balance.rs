@@ -0,0 +1,132 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34MaxBalance)]
+#[openbrush::contract]
+mod psp34_max_balance {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const MAX_BALANCE: u32 = 2;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        max_balance: max_balance::Data,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _id: &Id,
+    ) -> Result<(), PSP34Error> {
+        max_balance::Internal::_check_max_balance(self, from, to)
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(max_balance: u32) -> Self {
+            let mut instance = Self::default();
+            max_balance::Internal::_set_max_balance(&mut instance, max_balance);
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let nft = PSP34Struct::new(MAX_BALANCE);
+
+        assert_eq!(PSP34MaxBalance::max_balance(&nft), MAX_BALANCE);
+    }
+
+    #[ink::test]
+    fn mint_up_to_max_balance_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(MAX_BALANCE);
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(2u8)).is_ok());
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), MAX_BALANCE);
+
+        assert_eq!(
+            psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(3u8)),
+            Err(PSP34Error::Custom(String::from("MaxBalanceExceeded")))
+        );
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), MAX_BALANCE);
+    }
+
+    #[ink::test]
+    fn transfer_above_max_balance_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(MAX_BALANCE);
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(2u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(3u8)).is_ok());
+
+        assert_eq!(
+            PSP34::transfer(&mut nft, accounts.bob, Id::U8(3u8), vec![]),
+            Err(PSP34Error::Custom(String::from("MaxBalanceExceeded")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(3u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_out_frees_space() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(MAX_BALANCE);
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(2u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(3u8)).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP34::transfer(&mut nft, accounts.charlie, Id::U8(1u8), vec![]).is_ok());
+
+        change_caller(accounts.alice);
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(3u8), vec![]).is_ok());
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), MAX_BALANCE);
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(3u8)), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn burn_at_max_balance_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(MAX_BALANCE);
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(2u8)).is_ok());
+
+        assert!(psp34::Internal::_burn_from(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert_eq!(PSP34::balance_of(&nft, accounts.bob), 1);
+    }
+}
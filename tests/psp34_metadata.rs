@@ -56,4 +56,55 @@ mod psp34_metadata {
             Some(String::from("VAL"))
         );
     }
+
+    #[ink::test]
+    fn attributes_enumeration_works() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("KEY"), String::from("VAL"));
+        metadata::Internal::_set_attribute(&mut nft, id.clone(), String::from("COLOR"), String::from("RED"));
+        metadata::Internal::_set_attribute(&mut nft, id.clone(), String::from("SIZE"), String::from("XL"));
+        // Attributes of other tokens are enumerated separately.
+        metadata::Internal::_set_attribute(&mut nft, Id::U8(2u8), String::from("COLOR"), String::from("BLUE"));
+
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id.clone()), 3);
+        assert_eq!(
+            PSP34Metadata::get_attribute_name(&nft, id.clone(), 0),
+            Some(String::from("KEY"))
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute_name(&nft, id.clone(), 1),
+            Some(String::from("COLOR"))
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute_name(&nft, id.clone(), 2),
+            Some(String::from("SIZE"))
+        );
+        assert_eq!(PSP34Metadata::get_attribute_name(&nft, id.clone(), 3), None);
+
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, Id::U8(2u8)), 1);
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, Id::U8(3u8)), 0);
+        assert_eq!(PSP34Metadata::get_attribute_name(&nft, Id::U8(3u8), 0), None);
+    }
+
+    #[ink::test]
+    fn overwriting_attribute_does_not_duplicate_name() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new(id.clone(), String::from("KEY"), String::from("VAL"));
+        metadata::Internal::_set_attribute(&mut nft, id.clone(), String::from("COLOR"), String::from("RED"));
+        metadata::Internal::_set_attribute(&mut nft, id.clone(), String::from("KEY"), String::from("NEW_VAL"));
+
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id.clone()), 2);
+        assert_eq!(
+            PSP34Metadata::get_attribute_name(&nft, id.clone(), 0),
+            Some(String::from("KEY"))
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute_name(&nft, id.clone(), 1),
+            Some(String::from("COLOR"))
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, id.clone(), String::from("KEY")),
+            Some(String::from("NEW_VAL"))
+        );
+    }
 }
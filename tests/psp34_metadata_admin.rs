@@ -0,0 +1,106 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp34", feature = "access_control"))]
+#[openbrush::implementation(PSP34, PSP34Metadata, PSP34MetadataAdmin, AccessControl)]
+#[openbrush::contract]
+mod psp34_metadata_admin {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            access_control::Internal::_init_with_caller(&mut instance);
+            access_control::Internal::_setup_role(&mut instance, METADATA_ADMIN, Some(Self::env().caller()));
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn set_attribute_by_metadata_admin_works() {
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new();
+
+        assert!(
+            PSP34MetadataAdmin::set_attribute(&mut nft, id.clone(), String::from("KEY"), String::from("VAL")).is_ok()
+        );
+
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, id.clone(), String::from("KEY")),
+            Some(String::from("VAL"))
+        );
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id), 1);
+    }
+
+    #[ink::test]
+    fn set_attribute_without_role_fails() {
+        let accounts = accounts();
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new();
+
+        change_caller(accounts.bob);
+
+        assert_eq!(
+            PSP34MetadataAdmin::set_attribute(&mut nft, id.clone(), String::from("KEY"), String::from("VAL")),
+            Err(PSP34Error::Custom(String::from("AC::MissingRole")))
+        );
+        assert_eq!(PSP34Metadata::get_attribute(&nft, id, String::from("KEY")), None);
+    }
+
+    #[ink::test]
+    fn set_attribute_after_grant_works() {
+        let accounts = accounts();
+        let id = Id::U8(1u8);
+        let mut nft = PSP34Struct::new();
+        assert!(AccessControl::grant_role(&mut nft, METADATA_ADMIN, Some(accounts.bob)).is_ok());
+
+        change_caller(accounts.bob);
+
+        assert!(
+            PSP34MetadataAdmin::set_attribute(&mut nft, id.clone(), String::from("KEY"), String::from("VAL")).is_ok()
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, id, String::from("KEY")),
+            Some(String::from("VAL"))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Metadata, PSP34MintableMetadata)]
+#[openbrush::contract]
+mod psp34_mintable_metadata {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        // field for testing _before_token_transfer
+        blocked: Option<AccountId>,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _id: &Id,
+    ) -> Result<(), PSP34Error> {
+        if to.is_some() && to.cloned() == self.blocked {
+            return Err(PSP34Error::Custom(String::from("Blocked")))
+        }
+        Ok(())
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    fn attributes() -> Vec<(String, String)> {
+        vec![
+            (String::from("COLOR"), String::from("RED")),
+            (String::from("SIZE"), String::from("XL")),
+        ]
+    }
+
+    #[ink::test]
+    fn mint_with_attributes_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let id = Id::U8(1);
+
+        assert!(
+            PSP34MintableMetadata::mint_with_attributes(&mut nft, accounts.alice, id.clone(), attributes()).is_ok()
+        );
+
+        assert_eq!(PSP34::owner_of(&nft, id.clone()), Some(accounts.alice));
+        assert_eq!(PSP34::total_supply(&nft), 1);
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id.clone()), 2);
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, id.clone(), String::from("COLOR")),
+            Some(String::from("RED"))
+        );
+        assert_eq!(
+            PSP34Metadata::get_attribute(&nft, id.clone(), String::from("SIZE")),
+            Some(String::from("XL"))
+        );
+    }
+
+    #[ink::test]
+    fn mint_with_attributes_fails_for_existing_id() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let id = Id::U8(1);
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, id.clone()).is_ok());
+
+        assert_eq!(
+            PSP34MintableMetadata::mint_with_attributes(&mut nft, accounts.bob, id.clone(), attributes()),
+            Err(PSP34Error::TokenExists)
+        );
+        assert_eq!(PSP34::owner_of(&nft, id.clone()), Some(accounts.alice));
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id.clone()), 0);
+        assert_eq!(PSP34Metadata::get_attribute(&nft, id, String::from("COLOR")), None);
+    }
+
+    #[ink::test]
+    fn failed_mint_leaves_no_attributes() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let id = Id::U8(1);
+        nft.blocked = Some(accounts.bob);
+
+        assert_eq!(
+            PSP34MintableMetadata::mint_with_attributes(&mut nft, accounts.bob, id.clone(), attributes()),
+            Err(PSP34Error::Custom(String::from("Blocked")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, id.clone()), None);
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id.clone()), 0);
+    }
+
+    #[ink::test]
+    fn mint_with_duplicate_attribute_fails_before_mint() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        let id = Id::U8(1);
+        let mut attributes = attributes();
+        attributes.push((String::from("COLOR"), String::from("BLUE")));
+
+        assert_eq!(
+            PSP34MintableMetadata::mint_with_attributes(&mut nft, accounts.alice, id.clone(), attributes),
+            Err(PSP34Error::Custom(String::from("DuplicateAttribute")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, id.clone()), None);
+        assert_eq!(PSP34::total_supply(&nft), 0);
+        assert_eq!(PSP34Metadata::get_attribute_count(&nft, id), 0);
+    }
+}
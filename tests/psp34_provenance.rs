@@ -0,0 +1,104 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Provenance)]
+#[openbrush::contract]
+mod psp34_provenance {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        provenance: provenance::Data,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        provenance::Internal::_record_transfer(self, to, id);
+        Ok(())
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn mint_sets_last_transferred() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new();
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert_eq!(PSP34Provenance::last_transferred(&nft, Id::U8(1u8)), Some(500));
+    }
+
+    #[ink::test]
+    fn transfer_updates_last_transferred() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]).is_ok());
+
+        assert_eq!(PSP34Provenance::last_transferred(&nft, Id::U8(1u8)), Some(1000));
+    }
+
+    #[ink::test]
+    fn last_transferred_of_nonexistent_token_is_none() {
+        let nft = PSP34Struct::new();
+
+        assert_eq!(PSP34Provenance::last_transferred(&nft, Id::U8(1u8)), None);
+    }
+
+    #[ink::test]
+    fn burn_clears_last_transferred() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert!(psp34::Internal::_burn_from(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert_eq!(PSP34Provenance::last_transferred(&nft, Id::U8(1u8)), None);
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp34", feature = "ownable"))]
+#[openbrush::implementation(PSP34, PSP34Reveal, Ownable)]
+#[openbrush::contract]
+mod psp34_reveal {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        reveal: reveal::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            reveal::Internal::_set_placeholder_uri(&mut instance, String::from("ipfs://placeholder.json"));
+            reveal::Internal::_set_base_uri(&mut instance, String::from("ipfs://collection/"));
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn token_uri_before_reveal_is_placeholder() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(2u8)).is_ok());
+
+        assert!(!PSP34Reveal::is_revealed(&nft));
+        assert_eq!(
+            PSP34Reveal::token_uri(&nft, Id::U8(1u8)),
+            Ok(String::from("ipfs://placeholder.json"))
+        );
+        assert_eq!(
+            PSP34Reveal::token_uri(&nft, Id::U8(2u8)),
+            Ok(String::from("ipfs://placeholder.json"))
+        );
+    }
+
+    #[ink::test]
+    fn token_uri_after_reveal_is_real() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U32(42)).is_ok());
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::Bytes(vec![0xab, 0x01])).is_ok());
+
+        assert!(PSP34Reveal::reveal(&mut nft).is_ok());
+
+        assert!(PSP34Reveal::is_revealed(&nft));
+        assert_eq!(
+            PSP34Reveal::token_uri(&nft, Id::U32(42)),
+            Ok(String::from("ipfs://collection/42"))
+        );
+        assert_eq!(
+            PSP34Reveal::token_uri(&nft, Id::Bytes(vec![0xab, 0x01])),
+            Ok(String::from("ipfs://collection/ab01"))
+        );
+    }
+
+    #[ink::test]
+    fn reveal_is_irreversible() {
+        let mut nft = PSP34Struct::new();
+        assert!(PSP34Reveal::reveal(&mut nft).is_ok());
+
+        assert_eq!(
+            PSP34Reveal::reveal(&mut nft),
+            Err(PSP34Error::Custom(String::from("AlreadyRevealed")))
+        );
+        assert!(PSP34Reveal::is_revealed(&nft));
+    }
+
+    #[ink::test]
+    fn reveal_by_not_owner_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        change_caller(accounts.bob);
+
+        assert_eq!(
+            PSP34Reveal::reveal(&mut nft),
+            Err(PSP34Error::Custom(String::from("O::CallerIsNotOwner")))
+        );
+        assert!(!PSP34Reveal::is_revealed(&nft));
+    }
+
+    #[ink::test]
+    fn token_uri_of_non_existing_token_fails() {
+        let nft = PSP34Struct::new();
+
+        assert_eq!(
+            PSP34Reveal::token_uri(&nft, Id::U8(1u8)),
+            Err(PSP34Error::TokenNotExists)
+        );
+    }
+}
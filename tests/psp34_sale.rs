@@ -0,0 +1,166 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Sale)]
+#[openbrush::contract]
+mod psp34_sale {
+    use ink::env::{
+        test::{
+            get_account_balance,
+            set_account_balance,
+            set_callee,
+            set_value_transferred,
+        },
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        contracts::psp34::Id,
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const PRICE: Balance = 100;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        sale: sale::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(price: Balance, recipient: Option<AccountId>, refund_overpayment: bool) -> Self {
+            let mut instance = Self::default();
+            sale::Internal::_set_price(&mut instance, price);
+            sale::Internal::_set_sale_recipient(&mut instance, recipient);
+            sale::Internal::_set_refund_overpayment(&mut instance, refund_overpayment);
+            instance
+        }
+    }
+
+    /// Deploys the contract to Django's address, so the caller doesn't pay to itself.
+    fn deploy(recipient: Option<AccountId>, refund_overpayment: bool) -> PSP34Struct {
+        set_callee::<DefaultEnvironment>(accounts().django);
+        PSP34Struct::new(PRICE, recipient, refund_overpayment)
+    }
+
+    /// Sets the value transferred by the caller and adds it to the balance of the contract.
+    fn pay(value: Balance) {
+        let contract = ink::env::account_id::<DefaultEnvironment>();
+        let balance = get_account_balance::<DefaultEnvironment>(contract).unwrap_or_default();
+        set_account_balance::<DefaultEnvironment>(contract, balance + value);
+        set_value_transferred::<DefaultEnvironment>(value);
+    }
+
+    fn balance_of(account: AccountId) -> Balance {
+        get_account_balance::<DefaultEnvironment>(account).expect("Account should exist")
+    }
+
+    #[ink::test]
+    fn mint_sale_with_exact_payment_works() {
+        let accounts = accounts();
+        let mut nft = deploy(Some(accounts.charlie), false);
+        let charlie_balance = balance_of(accounts.charlie);
+
+        assert_eq!(PSP34Sale::price(&nft), PRICE);
+        pay(PRICE);
+        assert!(PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.bob));
+        assert_eq!(PSP34::total_supply(&nft), 1);
+        // The proceeds are forwarded to the recipient
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + PRICE);
+    }
+
+    #[ink::test]
+    fn mint_sale_without_recipient_keeps_proceeds() {
+        let accounts = accounts();
+        let mut nft = deploy(None, false);
+
+        pay(PRICE);
+        let contract = ink::env::account_id::<DefaultEnvironment>();
+        let contract_balance = balance_of(contract);
+        assert!(PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert_eq!(balance_of(contract), contract_balance);
+    }
+
+    #[ink::test]
+    fn mint_sale_with_underpayment_fails() {
+        let accounts = accounts();
+        let mut nft = deploy(Some(accounts.charlie), false);
+
+        pay(PRICE - 1);
+        assert_eq!(
+            PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)),
+            Err(PSP34Error::Custom(String::from("InsufficientPayment")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), None);
+        assert_eq!(PSP34::total_supply(&nft), 0);
+    }
+
+    #[ink::test]
+    fn mint_sale_rejects_overpayment() {
+        let accounts = accounts();
+        let mut nft = deploy(Some(accounts.charlie), false);
+
+        pay(PRICE + 50);
+        assert_eq!(
+            PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)),
+            Err(PSP34Error::Custom(String::from("Overpayment")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), None);
+    }
+
+    #[ink::test]
+    fn mint_sale_refunds_overpayment() {
+        let accounts = accounts();
+        let mut nft = deploy(Some(accounts.charlie), true);
+        let alice_balance = balance_of(accounts.alice);
+        let charlie_balance = balance_of(accounts.charlie);
+
+        pay(PRICE + 50);
+        assert!(PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.bob));
+        // Alice gets back the overpayment, the recipient gets the price
+        assert_eq!(balance_of(accounts.alice), alice_balance + 50);
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + PRICE);
+    }
+
+    #[ink::test]
+    fn mint_sale_of_existing_token_fails() {
+        let accounts = accounts();
+        let mut nft = deploy(Some(accounts.charlie), false);
+
+        pay(PRICE);
+        assert!(PSP34Sale::mint_sale(&mut nft, accounts.bob, Id::U8(1u8)).is_ok());
+        pay(PRICE);
+        assert_eq!(
+            PSP34Sale::mint_sale(&mut nft, accounts.alice, Id::U8(1u8)),
+            Err(PSP34Error::TokenExists)
+        );
+    }
+}
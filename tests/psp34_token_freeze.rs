@@ -0,0 +1,127 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34TokenFreeze)]
+#[openbrush::contract]
+mod psp34_token_freeze {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        token_freeze: token_freeze::Data,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        token_freeze::Internal::_check_token_freeze(self, from, id)
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            psp34::Internal::_mint_to(&mut instance, Self::env().caller(), Id::U8(1u8)).expect("Should mint");
+            psp34::Internal::_mint_to(&mut instance, Self::env().caller(), Id::U8(2u8)).expect("Should mint");
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn mint_frozen_token_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        token_freeze::Internal::_freeze_token(&mut nft, Id::U8(3u8));
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(3u8)).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(3u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_frozen_token_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        token_freeze::Internal::_freeze_token(&mut nft, Id::U8(1u8));
+
+        assert!(PSP34TokenFreeze::is_token_frozen(&nft, Id::U8(1u8)));
+        assert_eq!(
+            PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]),
+            Err(PSP34Error::Custom(String::from("TokenFrozen")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn burn_frozen_token_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        token_freeze::Internal::_freeze_token(&mut nft, Id::U8(1u8));
+
+        assert_eq!(
+            psp34::Internal::_burn_from(&mut nft, accounts.alice, Id::U8(1u8)),
+            Err(PSP34Error::Custom(String::from("TokenFrozen")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_unfrozen_token_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        token_freeze::Internal::_freeze_token(&mut nft, Id::U8(1u8));
+
+        token_freeze::Internal::_unfreeze_token(&mut nft, Id::U8(1u8));
+
+        assert!(!PSP34TokenFreeze::is_token_frozen(&nft, Id::U8(1u8)));
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn transfer_other_token_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        token_freeze::Internal::_freeze_token(&mut nft, Id::U8(1u8));
+
+        assert!(!PSP34TokenFreeze::is_token_frozen(&nft, Id::U8(2u8)));
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(2u8), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(2u8)), Some(accounts.bob));
+    }
+}
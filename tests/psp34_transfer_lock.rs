@@ -0,0 +1,108 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34TransferLock)]
+#[openbrush::contract]
+mod psp34_transfer_lock {
+    use ink::env::{
+        test::set_block_timestamp,
+        DefaultEnvironment,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        transfer_lock: transfer_lock::Data,
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _id: &Id,
+    ) -> Result<(), PSP34Error> {
+        transfer_lock::Internal::_check_transfer_lock(self, from, to)
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(unlock_time: u64) -> Self {
+            let mut instance = Self::default();
+            transfer_lock::Internal::_set_unlock_time(&mut instance, unlock_time);
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let nft = PSP34Struct::new(1000);
+
+        assert_eq!(PSP34TransferLock::unlock_time(&nft), 1000);
+    }
+
+    #[ink::test]
+    fn transfer_before_unlock_fails() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new(1000);
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        assert_eq!(
+            PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]),
+            Err(PSP34Error::Custom(String::from("TransfersLocked")))
+        );
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn mint_before_unlock_works() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new(1000);
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_after_unlock_works() {
+        let accounts = accounts();
+        set_block_timestamp::<DefaultEnvironment>(500);
+        let mut nft = PSP34Struct::new(1000);
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(1u8)).is_ok());
+
+        set_block_timestamp::<DefaultEnvironment>(1000);
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1u8), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1u8)), Some(accounts.bob));
+    }
+}
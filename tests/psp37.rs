@@ -58,6 +58,24 @@ mod psp37 {
         value: Balance,
     }
 
+    #[ink(event)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        approved: bool,
+    }
+
+    #[ink(event)]
+    pub struct TransferBatch {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        ids_amounts: Vec<(Id, Balance)>,
+    }
+
     #[derive(Default, Storage)]
     #[ink(storage)]
     pub struct PSP37Struct {
@@ -89,6 +107,25 @@ mod psp37 {
         });
     }
 
+    #[overrider(psp37::Internal)]
+    fn _emit_transfer_batch_event(
+        &self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        ids_amounts: Vec<(Id, Balance)>,
+    ) {
+        self.env().emit_event(TransferBatch { from, to, ids_amounts });
+    }
+
+    #[overrider(psp37::Internal)]
+    fn _emit_approval_for_all_event(&self, owner: AccountId, operator: AccountId, approved: bool) {
+        self.env().emit_event(ApprovalForAll {
+            owner,
+            operator,
+            approved,
+        });
+    }
+
     #[overrider(psp37::Internal)]
     fn _before_token_transfer(
         &mut self,
@@ -211,6 +248,52 @@ mod psp37 {
         );
     }
 
+    #[ink::test]
+    fn balance_of_batch_works() {
+        let token_id1 = Id::U128(1);
+        let token_id2 = Id::U128(2);
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id1.clone(), 1).is_ok());
+        assert!(nft.mint(accounts.alice, token_id2.clone(), 20).is_ok());
+        assert!(nft.mint(accounts.bob, token_id2.clone(), 5).is_ok());
+
+        // The result is aligned with the input, missing balances are zero.
+        assert_eq!(
+            PSP37::balance_of_batch(
+                &nft,
+                vec![
+                    (accounts.bob, token_id2.clone()),
+                    (accounts.alice, token_id1.clone()),
+                    (accounts.bob, token_id1.clone()),
+                    (accounts.charlie, Id::U128(3)),
+                    (accounts.alice, token_id2.clone()),
+                ]
+            ),
+            Ok(vec![5, 1, 0, 0, 20])
+        );
+        assert_eq!(PSP37::balance_of_batch(&nft, vec![]), Ok(vec![]));
+    }
+
+    #[ink::test]
+    fn balance_of_batch_rejects_too_many_entries() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id.clone(), 1).is_ok());
+
+        assert_eq!(
+            PSP37::balance_of_batch(&nft, vec![(accounts.alice, token_id.clone()); MAX_BALANCE_OF_BATCH_LEN]),
+            Ok(vec![1; MAX_BALANCE_OF_BATCH_LEN])
+        );
+        assert_eq!(
+            PSP37::balance_of_batch(&nft, vec![(accounts.alice, token_id); MAX_BALANCE_OF_BATCH_LEN + 1]),
+            Err(PSP37Error::Custom(String::from("BatchTooLarge")))
+        );
+    }
+
     #[ink::test]
     fn balance_of() {
         let token_id1 = Id::U128(1);
@@ -337,7 +420,117 @@ mod psp37 {
         let emmited_event = events_iter.next().unwrap();
         assert_approval_event(emmited_event, accounts.alice, accounts.bob, None, Balance::MAX);
 
-        assert_eq!(ink::env::test::recorded_events().count(), 3);
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_for_all_event(emmited_event, accounts.alice, accounts.bob, true);
+
+        assert_eq!(ink::env::test::recorded_events().count(), 4);
+    }
+
+    #[ink::test]
+    fn approve_for_all_emits_approval_for_all_event() {
+        let accounts = accounts();
+
+        let mut nft = PSP37Struct::new();
+        assert!(PSP37::approve(&mut nft, accounts.bob, None, Balance::MAX).is_ok());
+        assert!(PSP37::approve(&mut nft, accounts.bob, None, 0).is_ok());
+
+        let mut events_iter = ink::env::test::recorded_events();
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_event(emmited_event, accounts.alice, accounts.bob, None, Balance::MAX);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_for_all_event(emmited_event, accounts.alice, accounts.bob, true);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_event(emmited_event, accounts.alice, accounts.bob, None, 0);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_for_all_event(emmited_event, accounts.alice, accounts.bob, false);
+
+        assert_eq!(ink::env::test::recorded_events().count(), 4);
+    }
+
+    #[ink::test]
+    fn revoke_all_operators_works() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id.clone(), 2).is_ok());
+        assert!(PSP37::approve(&mut nft, accounts.bob, None, Balance::MAX).is_ok());
+        assert!(PSP37::approve(&mut nft, accounts.charlie, None, Balance::MAX).is_ok());
+
+        assert!(PSP37::revoke_all_operators(&mut nft).is_ok());
+
+        assert_eq!(PSP37::allowance(&nft, accounts.alice, accounts.bob, None), 0);
+        assert_eq!(PSP37::allowance(&nft, accounts.alice, accounts.charlie, None), 0);
+
+        // Mint event, two approvals and a pair of events for each revoked operator
+        let mut events_iter = ink::env::test::recorded_events().skip(5);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_event(emmited_event, accounts.alice, accounts.bob, None, 0);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_for_all_event(emmited_event, accounts.alice, accounts.bob, false);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_event(emmited_event, accounts.alice, accounts.charlie, None, 0);
+
+        let emmited_event = events_iter.next().unwrap();
+        assert_approval_for_all_event(emmited_event, accounts.alice, accounts.charlie, false);
+
+        assert!(events_iter.next().is_none());
+
+        for operator in [accounts.bob, accounts.charlie] {
+            change_caller(operator);
+            assert_eq!(
+                PSP37::transfer_from(&mut nft, accounts.alice, operator, token_id.clone(), 1, vec![]),
+                Err(PSP37Error::NotAllowed),
+            );
+        }
+        assert_eq!(PSP37::balance_of(&nft, accounts.alice, Some(token_id)), 2);
+    }
+
+    #[ink::test]
+    fn revoke_all_operators_without_operators_does_nothing() {
+        let mut nft = PSP37Struct::new();
+
+        assert!(PSP37::revoke_all_operators(&mut nft).is_ok());
+        assert_eq!(ink::env::test::recorded_events().count(), 0);
+    }
+
+    #[ink::test]
+    fn single_and_batch_mint_emit_different_events() {
+        let accounts = accounts();
+        let token_id_1 = Id::U128(1);
+        let token_id_2 = Id::U128(2);
+
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id_1.clone(), 1).is_ok());
+        assert!(psp37::Internal::_mint_to(
+            &mut nft,
+            accounts.bob,
+            vec![(token_id_1.clone(), 2), (token_id_2.clone(), 3)]
+        )
+        .is_ok());
+
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        // the signature topic and two topics for `from` and `to`
+        assert_eq!(events[0].topics.len(), 3);
+        assert_eq!(events[1].topics.len(), 3);
+
+        let decoded = openbrush::test_utils::decode_events::<Event>();
+        assert!(
+            matches!(&decoded[0], Event::Transfer(Transfer { from: None, to, id, value: 1 })
+            if *to == Some(accounts.alice) && *id == token_id_1)
+        );
+        assert!(
+            matches!(&decoded[1], Event::TransferBatch(TransferBatch { from: None, to, ids_amounts })
+            if *to == Some(accounts.bob) && *ids_amounts == vec![(token_id_1.clone(), 2), (token_id_2.clone(), 3)])
+        );
     }
 
     #[ink::test]
@@ -676,4 +869,34 @@ mod psp37 {
             panic!("encountered unexpected event kind: expected a Approval event")
         }
     }
+
+    fn assert_approval_for_all_event(
+        event: ink::env::test::EmittedEvent,
+        expected_owner: AccountId,
+        expected_operator: AccountId,
+        expected_approved: bool,
+    ) {
+        // the signature topic and two topics for `owner` and `operator`
+        assert_eq!(event.topics.len(), 3, "encountered invalid ApprovalForAll topics");
+        let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        if let Event::ApprovalForAll(ApprovalForAll {
+            owner,
+            operator,
+            approved,
+        }) = decoded_event
+        {
+            assert_eq!(owner, expected_owner, "encountered invalid ApprovalForAll.owner");
+            assert_eq!(
+                operator, expected_operator,
+                "encountered invalid ApprovalForAll.operator"
+            );
+            assert_eq!(
+                approved, expected_approved,
+                "encountered invalid ApprovalForAll.approved"
+            );
+        } else {
+            panic!("encountered unexpected event kind: expected a ApprovalForAll event")
+        }
+    }
 }
@@ -24,7 +24,10 @@
 #[openbrush::contract]
 mod psp37_burnable {
     use openbrush::{
-        test_utils::accounts,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
         traits::{
             Storage,
             String,
@@ -111,12 +114,14 @@ mod psp37_burnable {
         assert_eq!(PSP37::balance_of(&mut nft, accounts.alice, None), 2);
         assert_eq!(PSP37::balance_of(&mut nft, accounts.bob, None), 1);
 
+        change_caller(accounts.bob);
         assert!(PSP37Burnable::burn(
             &mut nft,
             accounts.bob,
             vec![(token_id2.clone(), token_amount2), (token_id1.clone(), 0)]
         )
         .is_ok());
+        change_caller(accounts.alice);
 
         assert_eq!(PSP37::total_supply(&mut nft, None), 2);
         assert_eq!(PSP37::total_supply(&mut nft, Some(token_id2.clone())), 10);
@@ -145,6 +150,78 @@ mod psp37_burnable {
         );
     }
 
+    #[ink::test]
+    fn burn_by_operator_works() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.bob, token_id.clone(), 10).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP37::approve(&mut nft, accounts.alice, Some(token_id.clone()), 6).is_ok());
+
+        change_caller(accounts.alice);
+        assert!(PSP37Burnable::burn(&mut nft, accounts.bob, vec![(token_id.clone(), 4)]).is_ok());
+
+        assert_eq!(PSP37::balance_of(&nft, accounts.bob, Some(token_id.clone())), 6);
+        assert_eq!(PSP37::total_supply(&nft, Some(token_id.clone())), 6);
+        assert_eq!(
+            PSP37::allowance(&nft, accounts.bob, accounts.alice, Some(token_id.clone())),
+            2
+        );
+    }
+
+    #[ink::test]
+    fn burn_by_unauthorized_caller_fails() {
+        let token_id = Id::U128(1);
+        let accounts = accounts();
+
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.bob, token_id.clone(), 10).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(PSP37::approve(&mut nft, accounts.alice, Some(token_id.clone()), 2).is_ok());
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            PSP37Burnable::burn(&mut nft, accounts.bob, vec![(token_id.clone(), 3)]),
+            Err(PSP37Error::NotAllowed),
+        );
+
+        change_caller(accounts.charlie);
+        assert_eq!(
+            PSP37Burnable::burn(&mut nft, accounts.bob, vec![(token_id.clone(), 1)]),
+            Err(PSP37Error::NotAllowed),
+        );
+
+        assert_eq!(PSP37::balance_of(&nft, accounts.bob, Some(token_id.clone())), 10);
+    }
+
+    #[ink::test]
+    fn burn_batch_with_insufficient_balance_burns_nothing() {
+        let token_id1 = Id::U128(1);
+        let token_id2 = Id::U128(2);
+        let accounts = accounts();
+
+        let mut nft = PSP37Struct::new();
+        assert!(nft.mint(accounts.alice, token_id1.clone(), 5).is_ok());
+        assert!(nft.mint(accounts.alice, token_id2.clone(), 1).is_ok());
+
+        assert_eq!(
+            PSP37Burnable::burn(
+                &mut nft,
+                accounts.alice,
+                vec![(token_id1.clone(), 5), (token_id2.clone(), 2)]
+            ),
+            Err(PSP37Error::InsufficientBalance),
+        );
+
+        assert_eq!(PSP37::balance_of(&nft, accounts.alice, Some(token_id1.clone())), 5);
+        assert_eq!(PSP37::balance_of(&nft, accounts.alice, Some(token_id2.clone())), 1);
+        assert_eq!(PSP37::total_supply(&nft, None), 2);
+    }
+
     #[ink::test]
     fn before_token_transfer_should_fail_burn() {
         let accounts = accounts();
@@ -278,9 +278,9 @@ mod psp37_enumerable {
         assert!(PSP37::transfer(&mut nft, accounts.bob, token_id3.clone(), token_amount3, vec![]).is_ok());
         change_caller(accounts.bob);
         assert!(PSP37::transfer(&mut nft, accounts.alice, token_id1.clone(), token_amount1, vec![]).is_ok());
-        assert!(PSP37Burnable::burn(&mut nft, accounts.alice, vec![(token_id2, token_amount2)]).is_ok());
         assert!(PSP37::transfer(&mut nft, accounts.alice, token_id3.clone(), token_amount3, vec![]).is_ok());
         change_caller(accounts.alice);
+        assert!(PSP37Burnable::burn(&mut nft, accounts.alice, vec![(token_id2, token_amount2)]).is_ok());
         assert!(PSP37::transfer(&mut nft, accounts.bob, token_id3.clone(), token_amount3, vec![]).is_ok());
         // alice does not own token
         assert_eq!(PSP37Enumerable::token_by_index(&mut nft, 0u128), Some(token_id1));
@@ -460,12 +460,14 @@ mod psp37_enumerable {
         assert_eq!(PSP37::balance_of(&mut nft, accounts.alice, None), 2);
         assert_eq!(PSP37::balance_of(&mut nft, accounts.bob, None), 1);
 
+        change_caller(accounts.bob);
         assert!(PSP37Burnable::burn(
             &mut nft,
             accounts.bob,
             vec![(token_id2.clone(), token_amount2), (token_id1.clone(), 0)]
         )
         .is_ok());
+        change_caller(accounts.alice);
 
         assert_eq!(PSP37::total_supply(&mut nft, None), 2);
         assert_eq!(PSP37::total_supply(&mut nft, Some(token_id2.clone())), 10);
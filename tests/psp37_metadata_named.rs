@@ -0,0 +1,110 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp37")]
+#[openbrush::implementation(PSP37, PSP37MetadataNamed)]
+#[openbrush::contract]
+mod psp37_metadata_named {
+    use openbrush::traits::{
+        Storage,
+        String,
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+        #[storage_field]
+        metadata_named: metadata_named::Data,
+    }
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn set_token_metadata(&mut self, id: Id, name: String, symbol: String) {
+            metadata_named::Internal::_set_token_name(self, &id, &name);
+            metadata_named::Internal::_set_token_symbol(self, &id, &symbol);
+        }
+    }
+
+    #[ink::test]
+    fn metadata_is_set_per_id() {
+        let mut nft = PSP37Struct::new();
+
+        nft.set_token_metadata(Id::U8(1), String::from("Gold"), String::from("GLD"));
+        nft.set_token_metadata(Id::U8(2), String::from("Silver"), String::from("SLV"));
+
+        assert_eq!(
+            PSP37MetadataNamed::token_name(&nft, Id::U8(1)),
+            Some(String::from("Gold"))
+        );
+        assert_eq!(
+            PSP37MetadataNamed::token_symbol(&nft, Id::U8(1)),
+            Some(String::from("GLD"))
+        );
+        assert_eq!(
+            PSP37MetadataNamed::token_name(&nft, Id::U8(2)),
+            Some(String::from("Silver"))
+        );
+        assert_eq!(
+            PSP37MetadataNamed::token_symbol(&nft, Id::U8(2)),
+            Some(String::from("SLV"))
+        );
+    }
+
+    #[ink::test]
+    fn updating_one_id_does_not_affect_another() {
+        let mut nft = PSP37Struct::new();
+
+        nft.set_token_metadata(Id::U8(1), String::from("Gold"), String::from("GLD"));
+        nft.set_token_metadata(Id::U8(2), String::from("Silver"), String::from("SLV"));
+        nft.set_token_metadata(Id::U8(1), String::from("Platinum"), String::from("PLT"));
+
+        assert_eq!(
+            PSP37MetadataNamed::token_name(&nft, Id::U8(1)),
+            Some(String::from("Platinum"))
+        );
+        assert_eq!(
+            PSP37MetadataNamed::token_name(&nft, Id::U8(2)),
+            Some(String::from("Silver"))
+        );
+        assert_eq!(
+            PSP37MetadataNamed::token_symbol(&nft, Id::U8(2)),
+            Some(String::from("SLV"))
+        );
+    }
+
+    #[ink::test]
+    fn unset_id_returns_none() {
+        let mut nft = PSP37Struct::new();
+
+        nft.set_token_metadata(Id::U8(1), String::from("Gold"), String::from("GLD"));
+
+        assert_eq!(PSP37MetadataNamed::token_name(&nft, Id::U8(3)), None);
+        assert_eq!(PSP37MetadataNamed::token_symbol(&nft, Id::U8(3)), None);
+    }
+}
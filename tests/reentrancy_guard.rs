@@ -55,6 +55,36 @@ mod reentrancy_guard {
         pub fn call_flip_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
             self.flip()
         }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_read)]
+        pub fn is_flipped(&self) -> Result<bool, ReentrancyGuardError> {
+            Ok(self.flipped)
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_read)]
+        pub fn call_is_flipped_after_read_lock(&self) -> Result<bool, ReentrancyGuardError> {
+            self.is_flipped()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_read_lock)]
+        pub fn call_is_flipped_after_read_write_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.is_flipped()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_read_lock)]
+        pub fn call_flip_after_read_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.flip()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant)]
+        pub fn call_is_flipped_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.is_flipped()
+        }
     }
 
     #[ink::test]
@@ -75,4 +105,60 @@ mod reentrancy_guard {
             instance.call_flip_after_lock()
         );
     }
+
+    #[ink::test]
+    fn call_is_flipped_after_read_lock_works() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(Ok(false), instance.call_is_flipped_after_read_lock());
+        assert_eq!(Ok(false), instance.call_is_flipped_after_read_lock());
+        assert_eq!(Ok(false), instance.flip());
+        assert_eq!(Ok(true), instance.is_flipped());
+    }
+
+    #[ink::test]
+    fn call_is_flipped_after_read_write_lock_works() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(Ok(false), instance.call_is_flipped_after_read_write_lock());
+        // The read lock is released
+        assert_eq!(Ok(false), instance.flip());
+        assert_eq!(Ok(true), instance.call_is_flipped_after_read_write_lock());
+    }
+
+    #[ink::test]
+    fn call_flip_after_read_lock_fails() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(
+            Err(ReentrancyGuardError::ReentrantCall),
+            instance.call_flip_after_read_lock()
+        );
+        assert_eq!(Ok(false), instance.is_flipped());
+        // The read lock is released after the failed call
+        assert_eq!(Ok(false), instance.flip());
+    }
+
+    #[ink::test]
+    fn read_check_does_not_write_storage() {
+        let instance = MyFlipper::new();
+        let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+
+        let (_, writes) = ink::env::test::get_contract_storage_rw::<ink::env::DefaultEnvironment>(&contract);
+        assert_eq!(Ok(false), instance.call_is_flipped_after_read_lock());
+        assert_eq!(
+            ink::env::test::get_contract_storage_rw::<ink::env::DefaultEnvironment>(&contract).1,
+            writes
+        );
+    }
+
+    #[ink::test]
+    fn call_is_flipped_after_lock_fails() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(
+            Err(ReentrancyGuardError::ReentrantCall),
+            instance.call_is_flipped_after_lock()
+        );
+    }
 }
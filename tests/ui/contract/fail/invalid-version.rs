@@ -0,0 +1,23 @@
+#[openbrush::contract(version = "1.2")]
+mod base_psp22 {
+    #[ink(storage)]
+    pub struct PSP22Struct {
+        pub value: bool
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(value: bool) -> Self {
+            Self {
+                value
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_value(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+#[openbrush::contract]
+mod base_psp22 {
+    use openbrush::traits::Storage;
+
+    pub struct NotClone(u8);
+
+    #[ink(storage)]
+    #[derive(Storage)]
+    pub struct Contract {
+        pub value: u8,
+    }
+
+    #[openbrush::modifier_definition]
+    fn with_value<BodyFn: FnOnce(&mut Contract)>(instance: &mut Contract, body: BodyFn, value: NotClone) {
+        instance.value = value.0;
+        body(instance);
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(with_value(NotClone(1)))]
+        pub fn set_value(&mut self) {}
+
+        #[ink(message)]
+        pub fn get_value(&self) -> u8 {
+            self.value
+        }
+    }
+}
+
+fn main() {}
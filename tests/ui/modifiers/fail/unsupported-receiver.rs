@@ -0,0 +1,35 @@
+#[openbrush::contract]
+mod base_psp22 {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Storage)]
+    pub struct Contract {
+        pub value: u8,
+    }
+
+    #[openbrush::modifier_definition]
+    fn with_value<BodyFn: FnOnce(&mut Contract)>(instance: &mut Contract, body: BodyFn, value: u8) {
+        instance.value = value;
+        body(instance);
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self { value: 0 }
+        }
+
+        #[ink(message)]
+        pub fn get_value(&self) -> u8 {
+            self.value
+        }
+
+        #[openbrush::modifiers(with_value(1))]
+        fn shared_value(self: std::rc::Rc<Self>) -> u8 {
+            self.value
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#[openbrush::contract]
+mod base_psp22 {
+    use openbrush::{
+        contracts::psp22,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        psp22: psp22::Data,
+        pub value: bool
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get_value(&self) -> bool {
+            self.value
+        }
+    }
+}
+
+fn main() {}
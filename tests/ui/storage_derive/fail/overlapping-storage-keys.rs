@@ -0,0 +1,56 @@
+use openbrush::{
+    storage::Mapping,
+    traits::AccountId,
+};
+
+mod v1 {
+    use super::*;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item(key = "0x0000abcd")]
+    pub struct Data {
+        #[lazy]
+        pub owner: AccountId,
+    }
+}
+
+mod v2 {
+    use super::*;
+
+    // Pins the same key as `v1::Data`, so the keys of `owner` are the same
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item(key = "0x0000abcd")]
+    pub struct Data {
+        #[lazy]
+        pub owner: AccountId,
+        pub balances: Mapping<AccountId, u128>,
+    }
+}
+
+#[openbrush::contract]
+mod overlapping_storage_keys {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        v1: crate::v1::Data,
+        #[storage_field]
+        v2: crate::v2::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.v2.owner.get()
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+pub mod config {
+    #[derive(Debug, Default, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Data {
+        pub value: u32,
+    }
+}
+
+#[openbrush::contract]
+mod non_storage_data {
+    use openbrush::{
+        contracts::psp22,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        settings: crate::config::Data,
+        config: crate::config::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get_value(&self) -> u32 {
+            self.config.value + self.data::<crate::config::Data>().value
+        }
+    }
+}
+
+fn main() {}
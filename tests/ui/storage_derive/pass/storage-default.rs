@@ -0,0 +1,52 @@
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub flag: bool,
+}
+
+#[openbrush::contract]
+mod storage_default {
+    use openbrush::traits::{
+        Storage,
+        StorageDefault,
+    };
+
+    #[ink(storage)]
+    #[derive(Storage, StorageDefault)]
+    pub struct Contract {
+        #[storage_field]
+        data: crate::Data,
+        counter: u32,
+        owner: Option<AccountId>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn flag(&self) -> bool {
+            self.data.flag
+        }
+
+        #[ink(message)]
+        pub fn counter(&self) -> u32 {
+            self.counter
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.owner
+        }
+    }
+}
+
+fn main() {
+    let contract = storage_default::Contract::new();
+
+    assert!(!contract.flag());
+    assert_eq!(contract.counter(), 0);
+    assert_eq!(contract.owner(), None);
+}
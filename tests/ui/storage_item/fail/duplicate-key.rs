@@ -0,0 +1,54 @@
+use openbrush::{
+    storage::Mapping,
+    traits::AccountId,
+};
+
+mod ownable {
+    use super::*;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item(key = "0x0000abcd")]
+    pub struct Data {
+        #[lazy]
+        pub owner: AccountId,
+    }
+}
+
+mod balances {
+    use super::*;
+
+    // Pins the same key as `ownable::Data`, the fields have different keys
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item(key = "0x0000abcd")]
+    pub struct Data {
+        pub balances: Mapping<AccountId, u128>,
+    }
+}
+
+#[openbrush::contract]
+mod duplicate_key {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        ownable: crate::ownable::Data,
+        #[storage_field]
+        balances: crate::balances::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.ownable.owner.get()
+        }
+    }
+}
+
+fn main() {}
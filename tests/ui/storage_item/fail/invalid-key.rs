@@ -0,0 +1,9 @@
+use openbrush::traits::AccountId;
+
+#[openbrush::storage_item(key = "abcd")]
+pub struct OwnableData {
+    #[lazy]
+    pub owner: AccountId,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use openbrush::{
+    storage::Mapping,
+    traits::AccountId,
+};
+
+mod v1 {
+    use super::*;
+
+    #[derive(Debug)]
+    #[openbrush::storage_item(key = "0x0000abcd")]
+    pub struct Data {
+        #[lazy]
+        pub owner: AccountId,
+        pub balances: Mapping<AccountId, u128>,
+    }
+}
+
+mod v2 {
+    use super::*;
+
+    // The same layout in another module, declared by the upgraded contract
+    pub mod moved {
+        use super::*;
+
+        #[derive(Debug)]
+        #[openbrush::storage_item(key = "0x0000abcd")]
+        pub struct Data {
+            #[lazy]
+            pub owner: AccountId,
+            pub balances: Mapping<AccountId, u128>,
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(v1::STORAGE_KEY_DATA_OWNER, v2::moved::STORAGE_KEY_DATA_OWNER);
+    assert_eq!(v1::STORAGE_KEY_DATA_BALANCES, v2::moved::STORAGE_KEY_DATA_BALANCES);
+    assert_eq!(
+        v1::STORAGE_KEY_DATA_OWNER,
+        openbrush::traits::ConstHasher::hash("0x0000abcd::owner")
+    );
+    assert_ne!(v1::STORAGE_KEY_DATA_OWNER, v1::STORAGE_KEY_DATA_BALANCES);
+}
@@ -11,6 +11,9 @@ fn ui_tests() {
     t.pass("tests/ui/modifier_definition/pass/*.rs");
     t.compile_fail("tests/ui/modifier_definition/fail/*.rs");
 
+    t.pass("tests/ui/modifiers/pass/*.rs");
+    t.compile_fail("tests/ui/modifiers/fail/*.rs");
+
     t.pass("tests/ui/storage_derive/pass/*.rs");
     t.compile_fail("tests/ui/storage_derive/fail/*.rs");
 
@@ -18,6 +21,7 @@ fn ui_tests() {
     t.compile_fail("tests/ui/trait_definition/fail/*.rs");
 
     t.pass("tests/ui/storage_item/pass/*.rs");
+    t.compile_fail("tests/ui/storage_item/fail/*.rs");
 
     t.pass("tests/ui/wrapper/pass/*.rs");
     t.compile_fail("tests/ui/wrapper/fail/*.rs");